@@ -0,0 +1,110 @@
+use std::{error::Error, fmt};
+use super::client::{Client, ExecuteError};
+use crate::types::{ChatId, CopyMessages, DeleteMessages, ForwardMessages, Integer, MessageId};
+
+impl Client {
+    /// Copies any number of messages.
+    ///
+    /// The method is split into chunks using [`CopyMessages::into_chunks`]
+    /// which are executed sequentially.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method to execute.
+    ///
+    /// Messages copied before an error are not rolled back.
+    pub async fn copy_messages(
+        &self,
+        method: CopyMessages,
+    ) -> Result<Vec<MessageId>, ExecuteError> {
+        let mut result = Vec::new();
+        for chunk in method.into_chunks() {
+            result.extend(self.execute(chunk).await?);
+        }
+        Ok(result)
+    }
+
+    /// Deletes any number of messages.
+    ///
+    /// Messages are split into chunks using [`DeleteMessages::into_chunks`]
+    /// which are executed sequentially.
+    /// A failed chunk doesn't stop deletion of the remaining chunks.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the target chat.
+    /// * `message_ids` - Identifiers of messages to delete.
+    pub async fn delete_many<A, B>(&self, chat_id: A, message_ids: B) -> Result<(), DeleteManyError>
+    where
+        A: Into<ChatId>,
+        B: IntoIterator<Item = Integer>,
+    {
+        let mut failures = Vec::new();
+        for chunk in DeleteMessages::new(chat_id, message_ids).into_chunks() {
+            let message_ids = chunk.message_ids.clone();
+            if let Err(err) = self.execute(chunk).await {
+                failures.push((message_ids, err));
+            }
+        }
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(DeleteManyError { failures })
+        }
+    }
+
+    /// Forwards any number of messages.
+    ///
+    /// The method is split into chunks using [`ForwardMessages::into_chunks`]
+    /// which are executed sequentially.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method to execute.
+    ///
+    /// Messages forwarded before an error are not rolled back.
+    pub async fn forward_messages(
+        &self,
+        method: ForwardMessages,
+    ) -> Result<Vec<MessageId>, ExecuteError> {
+        let mut result = Vec::new();
+        for chunk in method.into_chunks() {
+            result.extend(self.execute(chunk).await?);
+        }
+        Ok(result)
+    }
+}
+
+/// Represents chunks of messages which could not be deleted by [`Client::delete_many`].
+#[derive(Debug)]
+pub struct DeleteManyError {
+    failures: Vec<(Vec<Integer>, ExecuteError)>,
+}
+
+impl DeleteManyError {
+    /// Returns failed chunks of message IDs along with errors.
+    pub fn failures(&self) -> &[(Vec<Integer>, ExecuteError)] {
+        &self.failures
+    }
+
+    /// Returns IDs of messages from all failed chunks.
+    pub fn failed_message_ids(&self) -> impl Iterator<Item = Integer> + '_ {
+        self.failures.iter().flat_map(|(ids, _)| ids.iter().copied())
+    }
+}
+
+impl Error for DeleteManyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.failures.first().map(|(_, err)| err as _)
+    }
+}
+
+impl fmt::Display for DeleteManyError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        write!(out, "failed to delete messages: {} chunk(s) failed", self.failures.len())?;
+        if let Some((_, err)) = self.failures.first() {
+            write!(out, "; first error: {}", err)?;
+        }
+        Ok(())
+    }
+}
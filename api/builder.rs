@@ -0,0 +1,326 @@
+use std::{collections::HashMap, fmt, time::Duration};
+use reqwest::{ClientBuilder as HttpClientBuilder, Proxy};
+use super::{
+    client::{Client, ClientError, DEFAULT_HOST, DEFAULT_POLLING_TIMEOUT_MARGIN},
+    method_name::MethodName,
+};
+use crate::types::Limits;
+
+/// A builder for a [`Client`] with a tuned HTTP client.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use tg::{ClientBuilder, MethodName};
+///
+/// let client = ClientBuilder::new("token")
+///     .with_pool_max_idle_per_host(4)
+///     .with_tcp_keepalive(Duration::from_secs(60))
+///     .with_http2(true)
+///     .with_connect_timeout(Duration::from_secs(5))
+///     .with_request_timeout(Duration::from_secs(15))
+///     .with_method_timeout(MethodName::SendVideo, Duration::from_secs(120))
+///     .build()
+///     .unwrap();
+/// ```
+pub struct ClientBuilder {
+    compression_threshold: Option<usize>,
+    connect_timeout: Option<Duration>,
+    host: String,
+    http2: bool,
+    limits: Limits,
+    method_timeouts: HashMap<MethodName, Duration>,
+    polling_timeout_margin: Duration,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    proxy: Option<String>,
+    proxy_auth: Option<ProxyAuth>,
+    request_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    token: String,
+}
+
+impl ClientBuilder {
+    /// Creates a new `ClientBuilder`.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - A token associated with your bot.
+    pub fn new<T>(token: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            compression_threshold: None,
+            connect_timeout: None,
+            host: String::from(DEFAULT_HOST),
+            http2: false,
+            limits: Limits::CLOUD,
+            method_timeouts: HashMap::new(),
+            polling_timeout_margin: DEFAULT_POLLING_TIMEOUT_MARGIN,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            proxy: None,
+            proxy_auth: None,
+            request_timeout: None,
+            tcp_keepalive: None,
+            token: token.into(),
+        }
+    }
+
+    /// Enables gzip compression of JSON request bodies.
+    ///
+    /// See [`Client::with_request_compression`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Minimum size of a body in bytes to compress.
+    pub fn with_request_compression(mut self, threshold: usize) -> Self {
+        self.compression_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets a new timeout for establishing connections.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Timeout of the connect phase; default - no timeout.
+    ///
+    /// A short connect timeout detects an unreachable server faster
+    /// than the request timeout, which also covers reading the response.
+    pub fn with_connect_timeout(mut self, value: Duration) -> Self {
+        self.connect_timeout = Some(value);
+        self
+    }
+
+    /// Overrides the default API host with a custom one.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The new API host to use.
+    pub fn with_host<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.host = value.into();
+        self
+    }
+
+    /// Configures the client for a local Bot API server.
+    ///
+    /// Sets the API host and [`Limits::LOCAL`].
+    /// Before the first request to the local server, execute [`crate::types::LogOut`]
+    /// against the default host to move the bot from the cloud server.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Base URL of the server, e.g. `http://localhost:8081`.
+    pub fn with_api_url<T>(self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.with_host(value).with_limits(Limits::LOCAL)
+    }
+
+    /// Sets a new value for the `http2` flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Indicates whether to prefer HTTP/2 when the server supports it;
+    ///   HTTP/1.1 is used otherwise; default - `false`.
+    pub fn with_http2(mut self, value: bool) -> Self {
+        self.http2 = value;
+        self
+    }
+
+    /// Sets new limits of the Bot API server.
+    ///
+    /// See [`Client::with_limits`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Limits of the server.
+    pub fn with_limits(mut self, value: Limits) -> Self {
+        self.limits = value;
+        self
+    }
+
+    /// Sets a new timeout for requests of a method.
+    ///
+    /// See [`Client::with_method_timeout`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method to override the timeout for.
+    /// * `value` - Timeout from the start of a request until the response body is read.
+    pub fn with_method_timeout(mut self, method: MethodName, value: Duration) -> Self {
+        self.method_timeouts.insert(method, value);
+        self
+    }
+
+    /// Sets a new margin for long polling requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Time added to the `timeout` of [`crate::types::GetUpdates`]
+    ///   to get the timeout of the HTTP request; default - 10 seconds.
+    pub fn with_polling_timeout_margin(mut self, value: Duration) -> Self {
+        self.polling_timeout_margin = value;
+        self
+    }
+
+    /// Sets a new timeout for idle sockets in the connection pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Idle timeout; default - 90 seconds.
+    pub fn with_pool_idle_timeout(mut self, value: Duration) -> Self {
+        self.pool_idle_timeout = Some(value);
+        self
+    }
+
+    /// Sets a new maximum of idle connections per host in the connection pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Maximum number of idle connections; default - unlimited.
+    pub fn with_pool_max_idle_per_host(mut self, value: usize) -> Self {
+        self.pool_max_idle_per_host = Some(value);
+        self
+    }
+
+    /// Sets a new proxy for all requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - URL of the proxy, e.g. `http://proxy:3128`;
+    ///   `socks5://` and `socks5h://` schemes require the `socks` feature.
+    ///
+    /// Credentials may be included in the URL or set with [`Self::with_proxy_auth`].
+    /// By default, proxies from environment variables such as `HTTPS_PROXY` are used.
+    pub fn with_proxy<T>(mut self, url: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Sets new credentials for the proxy.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Name of the proxy user.
+    /// * `password` - Password of the proxy user.
+    pub fn with_proxy_auth<A, B>(mut self, username: A, password: B) -> Self
+    where
+        A: Into<String>,
+        B: Into<String>,
+    {
+        self.proxy_auth = Some(ProxyAuth {
+            username: username.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /// Sets a new timeout for regular requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Timeout from the start of a request until the response body is read;
+    ///   long polling requests use their own timeout.
+    ///
+    /// See [`Client::with_request_timeout`] for the default timeout of uploads.
+    pub fn with_request_timeout(mut self, value: Duration) -> Self {
+        self.request_timeout = Some(value);
+        self
+    }
+
+    /// Sets a new TCP keep-alive interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Interval between TCP keep-alive probes.
+    pub fn with_tcp_keepalive(mut self, value: Duration) -> Self {
+        self.tcp_keepalive = Some(value);
+        self
+    }
+
+    /// Builds a new [`Client`].
+    pub fn build(self) -> Result<Client, ClientError> {
+        let mut builder = HttpClientBuilder::new()
+            .use_rustls_tls()
+            .tcp_keepalive(self.tcp_keepalive);
+        if let Some(value) = self.connect_timeout {
+            builder = builder.connect_timeout(value);
+        }
+        if !self.http2 {
+            builder = builder.http1_only();
+        }
+        if let Some(value) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(value);
+        }
+        if let Some(value) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(value);
+        }
+        if let Some(url) = self.proxy {
+            let mut proxy = Proxy::all(url).map_err(ClientError::BuildClient)?;
+            if let Some(auth) = self.proxy_auth {
+                proxy = proxy.basic_auth(&auth.username, &auth.password);
+            }
+            builder = builder.proxy(proxy);
+        }
+        let http_client = builder.build().map_err(ClientError::BuildClient)?;
+        let mut client = Client::with_http_client(http_client, self.token)
+            .with_host(self.host)
+            .with_limits(self.limits)
+            .with_polling_timeout_margin(self.polling_timeout_margin);
+        if let Some(value) = self.request_timeout {
+            client = client.with_request_timeout(value);
+        }
+        if let Some(value) = self.compression_threshold {
+            client = client.with_request_compression(value);
+        }
+        for (method, value) in self.method_timeouts {
+            client = client.with_method_timeout(method, value);
+        }
+        Ok(client)
+    }
+}
+
+impl fmt::Debug for ClientBuilder {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        out.debug_struct("ClientBuilder")
+            .field("compression_threshold", &self.compression_threshold)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("host", &self.host)
+            .field("http2", &self.http2)
+            .field("limits", &self.limits)
+            .field("method_timeouts", &self.method_timeouts)
+            .field("polling_timeout_margin", &self.polling_timeout_margin)
+            .field("pool_idle_timeout", &self.pool_idle_timeout)
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("proxy", &self.proxy.is_some())
+            .field("proxy_auth", &self.proxy_auth)
+            .field("request_timeout", &self.request_timeout)
+            .field("tcp_keepalive", &self.tcp_keepalive)
+            .field("token", &format_args!("..."))
+            .finish()
+    }
+}
+
+struct ProxyAuth {
+    username: String,
+    password: String,
+}
+
+impl fmt::Debug for ProxyAuth {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        out.debug_struct("ProxyAuth")
+            .field("username", &self.username)
+            .field("password", &format_args!("..."))
+            .finish()
+    }
+}
@@ -0,0 +1,290 @@
+use super::{
+    clock::{Clock, SystemClock},
+    method_name::MethodName,
+};
+use crate::types::{ChatId, Integer, MessageData, Update, UpdateType};
+use serde_json::Value as JsonValue;
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+/// Represents an idempotent method which responses can be stored in a [`ResponseCache`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CachedMethod {
+    /// [`crate::types::GetChat`]
+    GetChat,
+    /// [`crate::types::GetChatMember`]
+    GetChatMember,
+    /// [`crate::types::GetBot`]
+    GetMe,
+    /// [`crate::types::GetStickerSet`]
+    GetStickerSet,
+}
+
+impl CachedMethod {
+    /// Returns the name of the method as used in the Bot API.
+    pub fn as_str(&self) -> &'static str {
+        MethodName::from(*self).as_str()
+    }
+
+    fn from_method(value: MethodName) -> Option<Self> {
+        Some(match value {
+            MethodName::GetChat => Self::GetChat,
+            MethodName::GetChatMember => Self::GetChatMember,
+            MethodName::GetMe => Self::GetMe,
+            MethodName::GetStickerSet => Self::GetStickerSet,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for CachedMethod {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        out.write_str(self.as_str())
+    }
+}
+
+/// A read-through cache for responses of idempotent methods.
+///
+/// Only methods with a configured TTL are cached,
+/// so an empty cache created with [`ResponseCache::default`] stores nothing.
+///
+/// Use [`crate::api::Client::with_cache`] to enable the cache
+/// and [`ResponseCache::invalidate_update`] to drop entries
+/// affected by incoming updates.
+#[derive(Debug)]
+pub struct ResponseCache {
+    clock: Arc<dyn Clock>,
+    max_entries: usize,
+    ttls: HashMap<CachedMethod, Duration>,
+    entries: Mutex<HashMap<CacheKey, CacheEntry>>,
+}
+
+impl ResponseCache {
+    /// Sets a new clock used to expire entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The clock; default - [`SystemClock`].
+    pub fn with_clock<T>(mut self, value: T) -> Self
+    where
+        T: Clock + 'static,
+    {
+        self.clock = Arc::new(value);
+        self
+    }
+
+    /// Sets a new maximum number of entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Maximum number of cached responses; default - 10000.
+    ///
+    /// Expired entries are dropped when a response is stored;
+    /// if the cache is still full, entries which expire first are evicted.
+    pub fn with_max_entries(mut self, value: usize) -> Self {
+        self.max_entries = value;
+        self
+    }
+
+    /// Sets a new TTL for a method.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method to cache.
+    /// * `ttl` - How long a response stays valid.
+    pub fn with_ttl(mut self, method: CachedMethod, ttl: Duration) -> Self {
+        self.ttls.insert(method, ttl);
+        self
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Removes all entries of the given method.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method to invalidate.
+    pub fn invalidate_method(&self, method: CachedMethod) {
+        self.lock().retain(|key, _| key.method != method);
+    }
+
+    /// Removes all `getChat` and `getChatMember` entries of a chat.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the chat.
+    pub fn invalidate_chat<T>(&self, chat_id: T)
+    where
+        T: Into<ChatId>,
+    {
+        let chat_id = chat_id.into();
+        self.lock().retain(|_, entry| !entry.has_chat(&chat_id));
+    }
+
+    /// Removes a `getChatMember` entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the chat.
+    /// * `user_id` - Unique identifier of the user.
+    pub fn invalidate_chat_member<T>(&self, chat_id: T, user_id: Integer)
+    where
+        T: Into<ChatId>,
+    {
+        let chat_id = chat_id.into();
+        self.lock().retain(|key, entry| {
+            !(key.method == CachedMethod::GetChatMember
+                && entry.has_chat(&chat_id)
+                && entry.user_id == Some(user_id))
+        });
+    }
+
+    /// Removes a `getStickerSet` entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the sticker set.
+    pub fn invalidate_sticker_set(&self, name: &str) {
+        self.lock().retain(|key, entry| {
+            !(key.method == CachedMethod::GetStickerSet && entry.name.as_deref() == Some(name))
+        });
+    }
+
+    /// Removes entries which may become stale after the given update.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - An incoming update.
+    pub fn invalidate_update(&self, update: &Update) {
+        match update.update_type {
+            UpdateType::BotStatus(ref x) => self.invalidate_chat(x.chat.get_id()),
+            UpdateType::UserStatus(ref x) => {
+                self.invalidate_chat_member(x.chat.get_id(), x.new_chat_member.get_user().id.into())
+            }
+            UpdateType::ChatBoostRemoved(ref x) => self.invalidate_chat(x.chat.get_id()),
+            UpdateType::ChatBoostUpdated(ref x) => self.invalidate_chat(x.chat.get_id()),
+            UpdateType::Message(ref x) | UpdateType::ChannelPost(ref x) => {
+                let chat_id = x.chat.get_id();
+                match x.data {
+                    MessageData::LeftChatMember(ref user) => {
+                        self.invalidate_chat_member(chat_id, user.id.into())
+                    }
+                    MessageData::NewChatMembers(ref users) => {
+                        for user in users {
+                            self.invalidate_chat_member(chat_id, user.id.into())
+                        }
+                    }
+                    MessageData::ChatBackgroundSet(_)
+                    | MessageData::DeleteChatPhoto
+                    | MessageData::MigrateToChatId(_)
+                    | MessageData::NewChatPhoto(_)
+                    | MessageData::NewChatTitle(_)
+                    | MessageData::PinnedMessage(_) => self.invalidate_chat(chat_id),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub(super) fn key(&self, method: MethodName, body: Option<&str>) -> Option<CacheKey> {
+        let method = CachedMethod::from_method(method)?;
+        if self.ttls.contains_key(&method) {
+            Some(CacheKey {
+                method,
+                body: body.map(String::from).unwrap_or_default(),
+            })
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn get(&self, key: &CacheKey) -> Option<JsonValue> {
+        let mut entries = self.lock();
+        match entries.get(key) {
+            Some(entry) if entry.expires_at > self.clock.instant() => Some(entry.value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(super) fn insert(&self, key: CacheKey, value: JsonValue) {
+        let ttl = match self.ttls.get(&key.method) {
+            Some(ttl) => *ttl,
+            None => return,
+        };
+        let params: Option<JsonValue> = serde_json::from_str(&key.body).ok();
+        let param = |name: &str| params.as_ref().and_then(|x| x.get(name)).cloned();
+        let entry = CacheEntry {
+            value,
+            expires_at: self.clock.instant() + ttl,
+            chat_id: param("chat_id").and_then(|x| serde_json::from_value(x).ok()),
+            user_id: param("user_id").and_then(|x| x.as_i64()),
+            name: param("name").and_then(|x| x.as_str().map(String::from)),
+        };
+        let now = self.clock.instant();
+        let mut entries = self.lock();
+        entries.retain(|_, entry| entry.expires_at > now);
+        while entries.len() >= self.max_entries {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.expires_at)
+                .map(|(key, _)| key.clone());
+            match oldest {
+                Some(oldest) => entries.remove(&oldest),
+                None => break,
+            };
+        }
+        if self.max_entries > 0 {
+            entries.insert(key, entry);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<CacheKey, CacheEntry>> {
+        self.entries.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self {
+            clock: Arc::new(SystemClock),
+            max_entries: DEFAULT_MAX_ENTRIES,
+            ttls: HashMap::new(),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(super) struct CacheKey {
+    method: CachedMethod,
+    body: String,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    value: JsonValue,
+    expires_at: Instant,
+    chat_id: Option<ChatId>,
+    user_id: Option<Integer>,
+    name: Option<String>,
+}
+
+impl CacheEntry {
+    fn has_chat(&self, chat_id: &ChatId) -> bool {
+        self.chat_id.as_ref() == Some(chat_id)
+    }
+}
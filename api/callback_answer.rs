@@ -0,0 +1,51 @@
+use std::future::Future;
+use super::client::{Client, ExecuteError};
+use crate::types::{AnswerCallbackQuery, CallbackQuery};
+
+impl Client {
+    /// Runs a handler of a callback query and makes sure the query is answered.
+    ///
+    /// Telegram apps show a loading indicator on the button until the query is answered,
+    /// so a query left unanswered by the handler is answered without a notification.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query to answer.
+    /// * `handler` - A future returning an answer;
+    ///   [`None`] if the handler has nothing to show.
+    ///
+    /// The handler must not answer the query itself, since a query can be answered only once.
+    /// The answer is always sent for `query`, the identifier of the returned answer is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn run(client: tg::Client, query: tg::CallbackQuery) -> Result<(), tg::ExecuteError> {
+    /// use tg::AnswerCallbackQuery;
+    ///
+    /// let id = query.id.clone();
+    /// client
+    ///     .handle_callback_query(&query, async move {
+    ///         // process the query
+    ///         Some(AnswerCallbackQuery::new(id).with_alert("Done"))
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn handle_callback_query<F>(
+        &self,
+        query: &CallbackQuery,
+        handler: F,
+    ) -> Result<(), ExecuteError>
+    where
+        F: Future<Output = Option<AnswerCallbackQuery>>,
+    {
+        let answer = match handler.await {
+            Some(answer) => answer.with_callback_query_id(query.id.as_str()),
+            None => AnswerCallbackQuery::new(query.id.as_str()),
+        };
+        self.execute(answer).await?;
+        Ok(())
+    }
+}
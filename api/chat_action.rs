@@ -0,0 +1,68 @@
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use super::client::Client;
+use crate::types::SendChatAction;
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(4);
+
+/// Keeps a chat action visible while a long task runs.
+///
+/// A chat action is shown for 5 seconds or less,
+/// so the guard sends it again every 4 seconds until it is dropped.
+/// Errors are ignored, since the action is only a hint for the user.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run(client: tg::Client) {
+/// use tg::{ChatAction, ChatActionGuard, SendChatAction};
+///
+/// let guard = ChatActionGuard::new(&client, SendChatAction::new(1, ChatAction::UploadPhoto));
+/// // render and upload the photo
+/// drop(guard);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ChatActionGuard {
+    worker: JoinHandle<()>,
+}
+
+impl ChatActionGuard {
+    /// Sends the action and starts refreshing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client to execute requests.
+    /// * `method` - The action to send.
+    ///
+    /// Must be called within a Tokio runtime.
+    pub fn new(client: &Client, method: SendChatAction) -> Self {
+        Self::with_delay(client, method, Duration::ZERO)
+    }
+
+    pub(super) fn with_delay(client: &Client, method: SendChatAction, delay: Duration) -> Self {
+        let client = client.clone();
+        let worker = tokio::spawn(async move {
+            if !delay.is_zero() {
+                client.clock().sleep(delay).await;
+            }
+            loop {
+                let _ = client.execute(method.clone()).await;
+                client.clock().sleep(REFRESH_INTERVAL).await;
+            }
+        });
+        Self { worker }
+    }
+
+    /// Stops refreshing the action.
+    ///
+    /// Same as dropping the guard;
+    /// the action disappears when it expires or when the bot sends a message.
+    pub fn stop(self) {}
+}
+
+impl Drop for ChatActionGuard {
+    fn drop(&mut self) {
+        self.worker.abort();
+    }
+}
@@ -0,0 +1,109 @@
+use std::{future::Future, time::Duration};
+use super::client::{Client, ExecuteError};
+use crate::types::{AnswerPreCheckoutQuery, PreCheckoutQuery};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(9);
+
+/// Ensures that pre-checkout queries are answered in time.
+///
+/// The Bot API cancels a payment if a pre-checkout query is not answered within 10 seconds.
+/// The watchdog runs a handler with a timeout, and when the timeout expires,
+/// rejects the query with an error message if one is configured.
+#[derive(Clone, Debug)]
+pub struct PreCheckoutWatchdog {
+    error_message: Option<String>,
+    timeout: Duration,
+}
+
+impl PreCheckoutWatchdog {
+    /// Creates a new `PreCheckoutWatchdog`.
+    pub fn new() -> Self {
+        Self {
+            error_message: None,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Sets a new error message used to reject queries which were not answered in time.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Error message in human readable form;
+    ///   by default such queries are not answered.
+    pub fn with_error_message<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.error_message = Some(value.into());
+        self
+    }
+
+    /// Sets a new timeout of a handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Maximum time to wait for an answer; default - 9 seconds,
+    ///   which leaves time to send the answer.
+    pub fn with_timeout(mut self, value: Duration) -> Self {
+        self.timeout = value;
+        self
+    }
+
+    /// Answers the query with the result of the handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client to execute requests.
+    /// * `query` - The query to answer.
+    /// * `handler` - A future returning the answer.
+    pub async fn answer<F>(
+        &self,
+        client: &Client,
+        query: &PreCheckoutQuery,
+        handler: F,
+    ) -> Result<PreCheckoutOutcome, ExecuteError>
+    where
+        F: Future<Output = AnswerPreCheckoutQuery>,
+    {
+        let answer = tokio::select! {
+            answer = handler => Some(answer),
+            _ = client.clock().sleep(self.timeout) => None,
+        };
+        match answer {
+            Some(answer) => {
+                client.execute(answer).await?;
+                Ok(PreCheckoutOutcome::Answered)
+            }
+            None => match self.error_message {
+                Some(ref message) => {
+                    client
+                        .execute(AnswerPreCheckoutQuery::error(
+                            query.id.as_str(),
+                            message.as_str(),
+                        ))
+                        .await?;
+                    Ok(PreCheckoutOutcome::Rejected)
+                }
+                None => Ok(PreCheckoutOutcome::Unanswered),
+            },
+        }
+    }
+}
+
+impl Default for PreCheckoutWatchdog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Represents a result of [`PreCheckoutWatchdog::answer`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PreCheckoutOutcome {
+    /// The handler answered the query in time.
+    Answered,
+    /// The handler timed out and the query was rejected with the error message.
+    Rejected,
+    /// The handler timed out and the query was left unanswered;
+    /// the payment will be canceled.
+    Unanswered,
+}
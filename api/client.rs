@@ -1,25 +1,56 @@
-use std::{error::Error, fmt, future::Future, time::Duration};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt,
+    future::Future,
+    sync::Arc,
+    time::Duration,
+};
 use bytes::Bytes;
 use futures_util::stream::Stream;
-use reqwest::{
-    Client as HttpClient,
-    ClientBuilder as HttpClientBuilder,
-    Error as HttpError,
-    RequestBuilder as HttpRequestBuilder,
-};
+use reqwest::{Client as HttpClient, Error as HttpError, RequestBuilder as HttpRequestBuilder};
 use serde::de::DeserializeOwned;
-use tokio::time::sleep;
-use super::payload::{Payload, PayloadError};
-use crate::types::{Response, ResponseError};
+use serde_json::{Error as JsonError, Value as JsonValue};
+use super::{
+    builder::ClientBuilder,
+    cache::ResponseCache,
+    chat_action::ChatActionGuard,
+    clock::{Clock, Rng, SystemClock, SystemRng},
+    method_name::MethodName,
+    observer::{RequestEvent, RequestObserver},
+    payload::{Payload, PayloadError},
+};
+use crate::types::{
+    is_local_file_path, ChatAction, ChatId, Integer, Limits, Response, ResponseError, SendChatAction,
+};
+
+pub(super) const DEFAULT_HOST: &str = "https://api.telegram.org";
+pub(super) const DEFAULT_POLLING_TIMEOUT_MARGIN: Duration = Duration::from_secs(10);
+const DEFAULT_UPLOAD_TIMEOUT: Duration = Duration::from_secs(300);
+
+const QUERY_ANSWER_METHODS: [MethodName; 2] = [MethodName::AnswerCallbackQuery, MethodName::AnswerInlineQuery];
 
-const DEFAULT_HOST: &str = "https://api.telegram.org";
+type ChatMigrationHandler = dyn Fn(ChatMigrated) + Send + Sync;
+type ExpiredQueryHandler = dyn Fn(ExpiredQuery) + Send + Sync;
 
 /// A client for interacting with the Telegram Bot API.
 #[derive(Clone)]
 pub struct Client {
+    cache: Option<Arc<ResponseCache>>,
+    chat_migration_handler: Option<Arc<ChatMigrationHandler>>,
+    clock: Arc<dyn Clock>,
+    compression_threshold: Option<usize>,
+    expired_query_handler: Option<Arc<ExpiredQueryHandler>>,
     host: String,
     http_client: HttpClient,
+    limits: Limits,
+    method_timeouts: Arc<HashMap<MethodName, Duration>>,
+    observer: Option<Arc<dyn RequestObserver>>,
+    polling_timeout_margin: Duration,
+    request_timeout: Option<Duration>,
+    rng: Arc<dyn Rng>,
     token: String,
+    upload_chat_action_delay: Option<Duration>,
 }
 
 impl Client {
@@ -32,11 +63,7 @@ impl Client {
     where
         T: Into<String>,
     {
-        let client = HttpClientBuilder::new()
-            .use_rustls_tls()
-            .build()
-            .map_err(ClientError::BuildClient)?;
-        Ok(Self::with_http_client(client, token))
+        ClientBuilder::new(token).build()
     }
 
     /// Creates a new Telegram Bot API client with a custom HTTP client and bot token.
@@ -51,9 +78,21 @@ impl Client {
         T: Into<String>,
     {
         Self {
+            cache: None,
+            chat_migration_handler: None,
+            clock: Arc::new(SystemClock),
+            compression_threshold: None,
+            expired_query_handler: None,
             http_client,
             host: String::from(DEFAULT_HOST),
+            limits: Limits::CLOUD,
+            method_timeouts: Arc::new(HashMap::new()),
+            observer: None,
+            polling_timeout_margin: DEFAULT_POLLING_TIMEOUT_MARGIN,
+            request_timeout: None,
+            rng: Arc::new(SystemRng),
             token: token.into(),
+            upload_chat_action_delay: None,
         }
     }
 
@@ -70,6 +109,201 @@ impl Client {
         self
     }
 
+    /// Sets new limits of the Bot API server.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Limits of the server; default - [`Limits::CLOUD`].
+    ///
+    /// Use [`Limits::LOCAL`] along with [`Self::with_host`] for a local Bot API server.
+    pub fn with_limits(mut self, value: Limits) -> Self {
+        self.limits = value;
+        self
+    }
+
+    /// Returns limits of the Bot API server.
+    pub fn limits(&self) -> &Limits {
+        &self.limits
+    }
+
+    /// Enables gzip compression of JSON request bodies.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Minimum size of a body in bytes to compress.
+    ///
+    /// The official Bot API server does not accept compressed bodies,
+    /// use this option only with a local Bot API server which is known to accept them.
+    /// Multipart bodies are never compressed.
+    pub fn with_request_compression(mut self, threshold: usize) -> Self {
+        self.compression_threshold = Some(threshold);
+        self
+    }
+
+    /// Suppresses errors of answers to expired callback and inline queries.
+    ///
+    /// When Telegram rejects [`crate::types::AnswerCallbackQuery`]
+    /// or [`crate::types::AnswerInlineQuery`] because the query is too old,
+    /// the handler is called with the rejected answer and the method returns `false`
+    /// instead of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function called for every suppressed error;
+    ///   use it to log a warning or to count such errors.
+    pub fn with_expired_query_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(ExpiredQuery) + Send + Sync + 'static,
+    {
+        self.expired_query_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Retries requests to groups which have been migrated to supergroups.
+    ///
+    /// When Telegram rejects a request because the group has been migrated,
+    /// the handler is called and the request is repeated with the identifier of the supergroup.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function called for every migrated group;
+    ///   use it to update stored chat identifiers.
+    ///
+    /// Only JSON requests with a numeric `chat_id` are repeated;
+    /// requests with files are not, since their bodies can't be rebuilt.
+    pub fn with_chat_migration_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(ChatMigrated) + Send + Sync + 'static,
+    {
+        self.chat_migration_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Sets a new observer of requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - An observer called when a request is completed;
+    ///   it is shared between clones of the client.
+    ///
+    /// Enable the `tracing` feature to get a span for every request as well.
+    pub fn with_request_observer<T>(mut self, value: T) -> Self
+    where
+        T: RequestObserver + 'static,
+    {
+        self.observer = Some(Arc::new(value));
+        self
+    }
+
+    /// Sets a new margin for long polling requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Time added to the `timeout` of [`crate::types::GetUpdates`]
+    ///   to get the timeout of the HTTP request.
+    ///
+    /// The timeout of a long polling request must be greater than the polling interval,
+    /// so [`Self::with_request_timeout`] is not applied to such requests.
+    pub fn with_polling_timeout_margin(mut self, value: Duration) -> Self {
+        self.polling_timeout_margin = value;
+        self
+    }
+
+    /// Sets a new timeout for regular requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Timeout from the start of a request until the response body is read.
+    ///
+    /// Overrides a timeout configured in the HTTP client.
+    /// Methods accepting file uploads, such as `sendVideo` or `sendDocument`,
+    /// get at least 5 minutes unless [`Self::with_method_timeout`] is used.
+    pub fn with_request_timeout(mut self, value: Duration) -> Self {
+        self.request_timeout = Some(value);
+        self
+    }
+
+    /// Sets a new timeout for requests of a method.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method to override the timeout for.
+    /// * `value` - Timeout from the start of a request until the response body is read.
+    ///
+    /// Overrides [`Self::with_request_timeout`] and the default upload timeout for the method.
+    /// Long polling requests always use their own timeout.
+    pub fn with_method_timeout(mut self, method: MethodName, value: Duration) -> Self {
+        Arc::make_mut(&mut self.method_timeouts).insert(method, value);
+        self
+    }
+
+    /// Enables sending a chat action while a file is uploaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - Time to wait before sending the action;
+    ///   uploads completed earlier don't send it.
+    ///
+    /// The action is chosen with [`crate::types::ChatAction::for_method`]
+    /// and refreshed until the request is completed, see [`crate::api::ChatActionGuard`].
+    /// Applied only to requests with files, since other requests are fast enough.
+    pub fn with_upload_chat_action(mut self, delay: Duration) -> Self {
+        self.upload_chat_action_delay = Some(delay);
+        self
+    }
+
+    /// Sets a new clock.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The clock; default - [`SystemClock`].
+    ///
+    /// The clock measures durations of requests, waits before retries
+    /// and is used by helpers which wait between requests,
+    /// e.g. [`crate::api::ChatActionGuard`] and [`crate::api::StarBalanceMonitor`].
+    pub fn with_clock<T>(mut self, value: T) -> Self
+    where
+        T: Clock + 'static,
+    {
+        self.clock = Arc::new(value);
+        self
+    }
+
+    /// Sets a new random number generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The generator; default - [`SystemRng`].
+    ///
+    /// Used to add jitter to delays before retries,
+    /// so requests limited at the same time are not retried at once.
+    pub fn with_rng<T>(mut self, value: T) -> Self
+    where
+        T: Rng + 'static,
+    {
+        self.rng = Arc::new(value);
+        self
+    }
+
+    /// Enables a read-through cache for idempotent methods.
+    ///
+    /// The cache is shared between clones of the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The cache to use.
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Returns the response cache if it is enabled.
+    ///
+    /// Use it to invalidate entries which became stale.
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_deref()
+    }
+
     /// Downloads a file.
     ///
     /// Use [`crate::types::GetFile`] method to get a value for the `file_path` argument.
@@ -78,11 +312,14 @@ impl Client {
     ///
     /// * `file_path` - The path to the file to be downloaded.
     ///
+    /// Absolute paths returned by a local Bot API server started with `--local`
+    /// are rejected, see [`crate::types::File::local_path`].
+    ///
     /// # Example
     ///
     /// ```
     /// # async fn download_file() {
-    /// use tgbot::api::Client;
+    /// use tg::Client;
     /// use futures_util::stream::StreamExt;
     /// let api = Client::new("token").unwrap();
     /// let mut stream = api.download_file("path").await.unwrap();
@@ -99,8 +336,10 @@ impl Client {
     where
         P: AsRef<str>,
     {
-        let payload = Payload::empty(file_path.as_ref());
-        let url = payload.build_url(&format!("{}/file", &self.host), &self.token);
+        if is_local_file_path(file_path.as_ref()) {
+            return Err(DownloadFileError::LocalFile(String::from(file_path.as_ref())));
+        }
+        let url = format!("{}/file/bot{}/{}", &self.host, &self.token, file_path.as_ref());
         let rep = self.http_client.get(&url).send().await?;
         let status = rep.status();
         if !status.is_success() {
@@ -123,22 +362,185 @@ impl Client {
     ///
     /// The client will not retry a request on a timeout error if the request is not cloneable
     /// (e.g. contains a stream).
+    ///
+    /// Responses of methods configured in the [`ResponseCache`] are served from the cache
+    /// until they expire or get invalidated.
     pub async fn execute<M>(&self, method: M) -> Result<M::Response, ExecuteError>
     where
         M: Method,
         M::Response: DeserializeOwned + Send + 'static,
     {
-        let builder = method
-            .into_payload()
-            .into_http_request_builder(&self.http_client, &self.host, &self.token)?;
+        let payload = method.into_payload();
+        if let Some(ref handler) = self.expired_query_handler {
+            if QUERY_ANSWER_METHODS.contains(&payload.method()) {
+                let method = String::from(payload.method().as_str());
+                return match self.execute_payload(payload).await {
+                    Err(ExecuteError::Response(error)) if error.is_query_expired() => {
+                        handler(ExpiredQuery { method, error });
+                        Ok(serde_json::from_value(JsonValue::Bool(false))?)
+                    }
+                    result => result,
+                };
+            }
+        }
+        if payload.not_modified_as_success() {
+            return match self.execute_payload(payload).await {
+                Err(ExecuteError::Response(error)) if error.is_message_not_modified() => {
+                    Ok(serde_json::from_value(JsonValue::Null)?)
+                }
+                result => result,
+            };
+        }
+        if let Some(ref cache) = self.cache {
+            if let Some(key) = cache.key(payload.method(), payload.json_body()) {
+                let value = match cache.get(&key) {
+                    Some(value) => value,
+                    None => {
+                        let value: JsonValue = self.execute_payload(payload).await?;
+                        cache.insert(key, value.clone());
+                        value
+                    }
+                };
+                return Ok(serde_json::from_value(value)?);
+            }
+        }
+        self.execute_payload(payload).await
+    }
+
+    async fn execute_payload<T>(&self, payload: Payload) -> Result<T, ExecuteError>
+    where
+        T: DeserializeOwned,
+    {
+        let migration = match (&self.chat_migration_handler, payload.json_body()) {
+            (Some(handler), Some(body)) => Some((handler, payload.method(), String::from(body))),
+            _ => None,
+        };
+        let chat_action = self.start_upload_chat_action(&payload);
+        let result = self.execute_observed(payload).await;
+        drop(chat_action);
+        if let (Err(ExecuteError::Response(ref error)), Some((handler, method, body))) =
+            (&result, migration)
+        {
+            if let Some(to) = error.migrate_to_chat_id() {
+                if let Some((from, payload)) = replace_chat_id(method, &body, to) {
+                    handler(ChatMigrated { from, to });
+                    return self.execute_observed(payload).await;
+                }
+            }
+        }
+        result
+    }
+
+    fn start_upload_chat_action(&self, payload: &Payload) -> Option<ChatActionGuard> {
+        let delay = self.upload_chat_action_delay?;
+        if !payload.has_files() {
+            return None;
+        }
+        let action = ChatAction::for_method(payload.method())?;
+        let chat_id = payload.form_field("chat_id")?;
+        let chat_id = match chat_id.parse::<Integer>() {
+            Ok(value) => ChatId::from(value),
+            Err(_) => ChatId::from(chat_id),
+        };
+        let mut method = SendChatAction::new(chat_id, action);
+        if let Some(value) = payload.form_field("business_connection_id") {
+            method = method.with_business_connection_id(value);
+        }
+        if let Some(value) = payload
+            .form_field("message_thread_id")
+            .and_then(|x| x.parse().ok())
+        {
+            method = method.with_message_thread_id(value);
+        }
+        Some(ChatActionGuard::with_delay(self, method, delay))
+    }
+
+    async fn execute_observed<T>(&self, payload: Payload) -> Result<T, ExecuteError>
+    where
+        T: DeserializeOwned,
+    {
+        let method = payload.method();
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "request",
+            method = method.as_str(),
+            payload_size = tracing::field::Empty,
+            error = tracing::field::Empty,
+        );
+        let is_observed = self.observer.is_some() || cfg!(feature = "tracing");
+        let started_at = self.clock.instant();
+        let (payload_size, result) = match self.build_request(payload) {
+            Ok(builder) => {
+                let payload_size = if is_observed {
+                    get_body_size(&builder)
+                } else {
+                    None
+                };
+                let result = self.send_payload(builder);
+                #[cfg(feature = "tracing")]
+                let result = tracing::Instrument::instrument(result, span.clone());
+                (payload_size, result.await)
+            }
+            Err(err) => (None, Err(err)),
+        };
+        let duration = self.clock.instant().saturating_duration_since(started_at);
+        #[cfg(feature = "tracing")]
+        {
+            if let Some(value) = payload_size {
+                span.record("payload_size", value);
+            }
+            if let Err(ref err) = result {
+                span.record("error", tracing::field::display(err));
+            }
+            tracing::debug!(parent: &span, duration = ?duration, "request completed");
+        }
+        if let Some(ref observer) = self.observer {
+            observer.observe(&RequestEvent {
+                duration,
+                method,
+                outcome: result.as_ref().map(|_| ()),
+                payload_size,
+            });
+        }
+        result
+    }
+
+    fn build_request(&self, payload: Payload) -> Result<HttpRequestBuilder, ExecuteError> {
+        let timeout = match payload.polling_timeout() {
+            Some(value) => Some(value + self.polling_timeout_margin),
+            None => {
+                let method = payload.method();
+                match self.method_timeouts.get(&method) {
+                    Some(value) => Some(*value),
+                    None => self
+                        .request_timeout
+                        .map(|value| if method.accepts_uploads() { value.max(DEFAULT_UPLOAD_TIMEOUT) } else { value }),
+                }
+            }
+        };
+        let mut builder = payload.into_http_request_builder(
+            &self.http_client,
+            &self.host,
+            &self.token,
+            self.compression_threshold,
+            &self.limits,
+        )?;
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+        Ok(builder)
+    }
+
+    async fn send_payload<T>(&self, builder: HttpRequestBuilder) -> Result<T, ExecuteError>
+    where
+        T: DeserializeOwned,
+    {
         for i in 0..2 {
             match builder.try_clone() {
                 Some(builder) => {
                     let response = self.send_request(builder).await?;
                     match response.retry_after() {
-                        Some(retry_after) => {
-                            sleep(Duration::from_secs(retry_after)).await
-                        }
+                        Some(retry_after) => self.clock.sleep(self.retry_delay(retry_after)).await,
                         None => return Ok(response.into_result()?),
                     }
                 }
@@ -157,6 +559,17 @@ impl Client {
         let response = http_request.send().await?;
         Ok(response.json::<Response<T>>().await?)
     }
+
+    /// Returns `retry_after` seconds with a random jitter of up to 10%.
+    fn retry_delay(&self, retry_after: u64) -> Duration {
+        let max_jitter = retry_after.saturating_mul(100);
+        let jitter = self.rng.next_u64() % max_jitter.saturating_add(1);
+        Duration::from_secs(retry_after) + Duration::from_millis(jitter)
+    }
+
+    pub(super) fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
 }
 
 pub trait MethodExt: Method + Sized {
@@ -175,13 +588,73 @@ where
 impl fmt::Debug for Client {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Client")
+            .field("cache", &self.cache)
+            .field("chat_migration_handler", &self.chat_migration_handler.is_some())
+            .field("clock", &self.clock)
+            .field("compression_threshold", &self.compression_threshold)
+            .field("expired_query_handler", &self.expired_query_handler.is_some())
             .field("http_client", &self.http_client)
             .field("host", &self.host)
+            .field("limits", &self.limits)
+            .field("method_timeouts", &self.method_timeouts)
+            .field("observer", &self.observer.is_some())
+            .field("polling_timeout_margin", &self.polling_timeout_margin)
+            .field("request_timeout", &self.request_timeout)
+            .field("rng", &self.rng)
             .field("token", &format_args!("..."))
+            .field("upload_chat_action_delay", &self.upload_chat_action_delay)
             .finish()
     }
 }
 
+fn replace_chat_id(method: MethodName, body: &str, chat_id: Integer) -> Option<(Integer, Payload)> {
+    let mut body: JsonValue = serde_json::from_str(body).ok()?;
+    let value = body.get_mut("chat_id")?;
+    let from = value.as_i64()?;
+    *value = JsonValue::from(chat_id);
+    Some((from, Payload::json(method, body)))
+}
+
+fn get_body_size(builder: &HttpRequestBuilder) -> Option<usize> {
+    let request = builder.try_clone()?.build().ok()?;
+    match request.body() {
+        Some(body) => body.as_bytes().map(|x| x.len()),
+        None => Some(0),
+    }
+}
+
+/// Represents a group which has been migrated to a supergroup.
+///
+/// See [`Client::with_chat_migration_handler`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ChatMigrated {
+    /// Identifier of the group.
+    pub from: Integer,
+    /// Identifier of the supergroup.
+    pub to: Integer,
+}
+
+/// Represents an answer to a callback or inline query rejected because the query has expired.
+///
+/// See [`Client::with_expired_query_handler`].
+#[derive(Clone, Debug)]
+pub struct ExpiredQuery {
+    method: String,
+    error: ResponseError,
+}
+
+impl ExpiredQuery {
+    /// Returns the name of the rejected method.
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Returns the error received from the server.
+    pub fn error(&self) -> &ResponseError {
+        &self.error
+    }
+}
+
 /// Represents an API method that can be executed by the Telegram Bot API client.
 pub trait Method {
     /// The type representing a successful result in an API response.
@@ -220,6 +693,9 @@ impl fmt::Display for ClientError {
 pub enum DownloadFileError {
     /// An error indicating a failure to send an HTTP request.
     Http(HttpError),
+    /// An error indicating that the file is stored on the file system of a local Bot API server
+    /// and should be read directly; contains the path.
+    LocalFile(String),
     /// An error received from the server in response to the download request.
     Response {
         /// The HTTP status code received in the response.
@@ -248,6 +724,9 @@ impl fmt::Display for DownloadFileError {
     fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
         match self {
             DownloadFileError::Http(err) => write!(out, "failed to download file: {}", err),
+            DownloadFileError::LocalFile(path) => {
+                write!(out, "failed to download file: {} is a local file", path)
+            }
             DownloadFileError::Response { status, text } => {
                 write!(out, "failed to download file: status={} text={}", status, text)
             }
@@ -261,6 +740,8 @@ impl fmt::Display for DownloadFileError {
 pub enum ExecuteError {
     /// An error indicating a failure to send an HTTP request.
     Http(HttpError),
+    /// An error indicating a failure to deserialize a cached response.
+    Json(JsonError),
     /// An error indicating a failure to build an HTTP request payload.
     Payload(PayloadError),
     /// An error received from the Telegram server in response to the execution request.
@@ -274,6 +755,7 @@ impl Error for ExecuteError {
         use self::ExecuteError::*;
         Some(match self {
             Http(err) => err,
+            Json(err) => err,
             Payload(err) => err,
             Response(err) => err,
             TooManyRequests => return None,
@@ -289,6 +771,7 @@ impl fmt::Display for ExecuteError {
             "failed to execute method: {}",
             match self {
                 Http(err) => err.to_string(),
+                Json(err) => err.to_string(),
                 Payload(err) => err.to_string(),
                 Response(err) => err.to_string(),
                 TooManyRequests => "too many requests".to_string(),
@@ -0,0 +1,71 @@
+use std::{
+    collections::hash_map::RandomState,
+    fmt,
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    pin::Pin,
+    time::{Duration, Instant, SystemTime},
+};
+
+/// Provides the current time to time-dependent components.
+///
+/// Implement the trait to control time in tests;
+/// use [`SystemClock`] otherwise.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Returns the current monotonic time.
+    ///
+    /// Used to measure intervals, e.g. TTLs.
+    fn instant(&self) -> Instant;
+
+    /// Returns the current wall-clock time.
+    ///
+    /// Used to compare with dates received from the Bot API.
+    fn now(&self) -> SystemTime;
+
+    /// Waits for the given duration.
+    ///
+    /// Used for delays and timeouts, e.g. refresh intervals;
+    /// the default implementation uses the Tokio timer.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - Time to wait.
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// A clock using the system time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Provides random numbers to components which need them, e.g. for retry jitter.
+///
+/// Implement the trait to get deterministic values in tests;
+/// use [`SystemRng`] otherwise.
+pub trait Rng: fmt::Debug + Send + Sync {
+    /// Returns a random number.
+    fn next_u64(&self) -> u64;
+}
+
+/// A random number generator using randomly seeded keys of the standard library.
+///
+/// The numbers are not suitable for cryptography.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemRng;
+
+impl Rng for SystemRng {
+    fn next_u64(&self) -> u64 {
+        RandomState::new().build_hasher().finish()
+    }
+}
@@ -0,0 +1,142 @@
+use super::client::{Client, ExecuteError};
+use crate::types::{
+    BotCommand, BotCommandScope, DeleteBotCommands, GetBotCommands, SetBotCommands,
+};
+
+/// Synchronizes commands of the bot with a desired state.
+///
+/// Commands are set per scope and language;
+/// the current commands are requested for every configured pair
+/// and updated only when they differ from the desired ones.
+/// An empty list of desired commands deletes the commands of the pair.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run(client: tg::Client) -> Result<(), Box<dyn std::error::Error>> {
+/// use tg::{BotCommand, BotCommandScope, CommandScopeSync};
+///
+/// let updated = CommandScopeSync::default()
+///     .with_commands(BotCommandScope::Default, [BotCommand::new("help", "Show help")?])
+///     .with_localized_commands(
+///         BotCommandScope::Default,
+///         "de",
+///         [BotCommand::new("help", "Hilfe anzeigen")?],
+///     )
+///     .sync(&client)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CommandScopeSync {
+    items: Vec<CommandScopeItem>,
+}
+
+#[derive(Clone, Debug)]
+struct CommandScopeItem {
+    commands: Vec<BotCommand>,
+    language_code: Option<String>,
+    scope: BotCommandScope,
+}
+
+impl CommandScopeSync {
+    /// Adds desired commands for all users of the scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Scope of users.
+    /// * `commands` - Commands to set; an empty list deletes commands of the scope.
+    pub fn with_commands<T>(mut self, scope: BotCommandScope, commands: T) -> Self
+    where
+        T: IntoIterator<Item = BotCommand>,
+    {
+        self.push(scope, None, commands);
+        self
+    }
+
+    /// Adds desired commands for users of the scope with the given language.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Scope of users.
+    /// * `language_code` - Two-letter ISO 639-1 language code.
+    /// * `commands` - Commands to set; an empty list deletes commands of the scope.
+    pub fn with_localized_commands<A, B>(
+        mut self,
+        scope: BotCommandScope,
+        language_code: A,
+        commands: B,
+    ) -> Self
+    where
+        A: Into<String>,
+        B: IntoIterator<Item = BotCommand>,
+    {
+        self.push(scope, Some(language_code.into()), commands);
+        self
+    }
+
+    /// Applies the desired commands.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client to execute requests.
+    ///
+    /// Returns the number of updated scope and language pairs.
+    pub async fn sync(&self, client: &Client) -> Result<usize, ExecuteError> {
+        let mut updated = 0;
+        for item in &self.items {
+            let mut method = GetBotCommands::default().with_scope(item.scope.clone());
+            if let Some(ref language_code) = item.language_code {
+                method = method.with_language_code(language_code.as_str());
+            }
+            let current = client.execute(method).await?;
+            if is_equal(&item.commands, &current) {
+                continue;
+            }
+            if item.commands.is_empty() {
+                let mut method = DeleteBotCommands::default().with_scope(item.scope.clone());
+                if let Some(ref language_code) = item.language_code {
+                    method = method.with_language_code(language_code.as_str());
+                }
+                client.execute(method).await?;
+            } else {
+                let mut method =
+                    SetBotCommands::new(item.commands.clone()).with_scope(item.scope.clone());
+                if let Some(ref language_code) = item.language_code {
+                    method = method.with_language_code(language_code.as_str());
+                }
+                client.execute(method).await?;
+            }
+            updated += 1;
+        }
+        Ok(updated)
+    }
+
+    fn push<T>(&mut self, scope: BotCommandScope, language_code: Option<String>, commands: T)
+    where
+        T: IntoIterator<Item = BotCommand>,
+    {
+        let commands = commands.into_iter().collect();
+        match self
+            .items
+            .iter_mut()
+            .find(|x| x.scope == scope && x.language_code == language_code)
+        {
+            Some(item) => item.commands = commands,
+            None => self.items.push(CommandScopeItem {
+                commands,
+                language_code,
+                scope,
+            }),
+        }
+    }
+}
+
+fn is_equal(desired: &[BotCommand], current: &[BotCommand]) -> bool {
+    desired.len() == current.len()
+        && desired
+            .iter()
+            .zip(current)
+            .all(|(a, b)| a.name() == b.name() && a.description() == b.description())
+}
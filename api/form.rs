@@ -1,19 +1,25 @@
-use std::{collections::HashMap, error::Error, fmt, fmt::Formatter};
+use std::{collections::HashMap, error::Error, fmt, fmt::Formatter, sync::Arc};
+use futures_util::stream::TryStreamExt;
 use mime::Mime;
 use reqwest::{
     multipart::{Form as MultipartForm, Part},
     Body,
     Error as ReqwestError,
 };
+use serde_json::Error as JsonError;
 use tokio::io::AsyncRead;
 use tokio_util::codec::{BytesCodec, FramedRead};
+use crate::types::{Integer, Limits, UploadProgress, UploadProgressHandler};
 
 pub(crate) enum FormValue {
     Text(String),
     File {
+        is_photo: bool,
         name: Option<String>,
         mime_type: Option<Mime>,
+        progress_handler: Option<Arc<UploadProgressHandler>>,
         reader: FramedRead<Box<dyn AsyncRead + Send + Sync + Unpin>, BytesCodec>,
+        size: Option<Integer>,
     },
 }
 
@@ -21,10 +27,18 @@ impl fmt::Debug for FormValue {
     fn fmt(&self, out: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::Text(value) => out.debug_tuple("FormValue::Text").field(value).finish(),
-            Self::File { name, mime_type, .. } => out
+            Self::File {
+                is_photo,
+                name,
+                mime_type,
+                size,
+                ..
+            } => out
                 .debug_struct("FormValue::File")
+                .field("is_photo", is_photo)
                 .field("name", name)
                 .field("mime_type", mime_type)
+                .field("size", size)
                 .finish(),
         }
     }
@@ -51,6 +65,19 @@ impl PartialEq for FormValue {
     }
 }
 
+impl FormValue {
+    /// Marks a file as a photo, so the photo upload limit is applied.
+    pub(crate) fn into_photo(mut self) -> Self {
+        if let Self::File {
+            ref mut is_photo, ..
+        } = self
+        {
+            *is_photo = true;
+        }
+        self
+    }
+}
+
 impl<T> From<T> for FormValue
 where
     T: ToString,
@@ -70,8 +97,23 @@ impl TryFrom<FormValue> for Part {
                 reader,
                 name,
                 mime_type,
+                progress_handler,
+                size,
+                ..
             } => {
-                let body = Body::wrap_stream(reader);
+                let body = match progress_handler {
+                    Some(handler) => {
+                        let mut bytes_sent = 0;
+                        Body::wrap_stream(reader.inspect_ok(move |chunk| {
+                            bytes_sent += chunk.len() as Integer;
+                            handler(UploadProgress {
+                                bytes_sent,
+                                total: size,
+                            })
+                        }))
+                    }
+                    None => Body::wrap_stream(reader),
+                };
                 let part = Part::stream(body);
                 match (name, mime_type) {
                     (Some(name), mime_type) => match mime_type {
@@ -102,12 +144,58 @@ impl Form {
         self.fields.insert(name.into(), value.into());
     }
 
+    pub(crate) fn get_text_field(&self, name: &str) -> Option<&str> {
+        match self.fields.get(name) {
+            Some(FormValue::Text(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn has_files(&self) -> bool {
+        self.fields.values().any(|x| matches!(x, FormValue::File { .. }))
+    }
+
+    pub(crate) fn into_json(self) -> Option<Result<String, JsonError>> {
+        let mut fields = HashMap::new();
+        for (name, value) in self.fields {
+            match value {
+                FormValue::Text(value) => fields.insert(name, value),
+                FormValue::File { .. } => return None,
+            };
+        }
+        Some(serde_json::to_string(&fields))
+    }
+
     pub(crate) fn remove_field<N>(&mut self, name: N)
     where
         N: Into<String>,
     {
         self.fields.remove(&name.into());
     }
+
+    pub(crate) fn check_file_sizes(&self, limits: &Limits) -> Result<(), FormError> {
+        for value in self.fields.values() {
+            if let FormValue::File {
+                is_photo,
+                size: Some(actual),
+                ..
+            } = value
+            {
+                let limit = if *is_photo {
+                    limits.max_photo_upload_size
+                } else {
+                    limits.max_upload_size
+                };
+                if *actual > limit {
+                    return Err(FormError::FileTooLarge {
+                        limit,
+                        actual: *actual,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<I, K> From<I> for Form
@@ -140,21 +228,34 @@ impl TryFrom<Form> for MultipartForm {
 /// An error occurred when building multipart form
 #[derive(Debug)]
 pub enum FormError {
+    /// A file exceeds the upload limit of the server
+    FileTooLarge {
+        /// Maximum size in bytes
+        limit: Integer,
+        /// Size of the file in bytes
+        actual: Integer,
+    },
     /// Failed to set MIME type
     Mime(ReqwestError),
 }
 
 impl Error for FormError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        Some(match self {
-            FormError::Mime(err) => err,
-        })
+        match self {
+            FormError::FileTooLarge { .. } => None,
+            FormError::Mime(err) => Some(err),
+        }
     }
 }
 
 impl fmt::Display for FormError {
     fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            FormError::FileTooLarge { limit, actual } => write!(
+                out,
+                "file is too large: {} bytes, limit is {} bytes",
+                actual, limit
+            ),
             FormError::Mime(err) => write!(out, "can not set MIME type: {}", err),
         }
     }
@@ -0,0 +1,138 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use futures_util::stream::{Stream, StreamExt};
+use super::client::{Client, ExecuteError};
+use crate::types::{
+    ChatPeerId, EditMessageLiveLocation, Integer, Location, Message, MessageData,
+    StopMessageLiveLocation,
+};
+
+const LIVE_PERIOD_FOREVER: Integer = 0x7FFF_FFFF;
+
+/// Pushes coordinates to a sent live location message.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run(client: tg::Client, message: tg::Message) -> Result<(), tg::ExecuteError> {
+/// use futures_util::stream;
+/// use tg::{LiveLocationSession, Location};
+///
+/// let points = stream::iter(vec![Location::new(51.5, -0.12), Location::new(51.6, -0.13)]);
+/// if let Some(session) = LiveLocationSession::new(&message) {
+///     session.run(&client, points).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LiveLocationSession {
+    chat_id: ChatPeerId,
+    expires_at: Option<SystemTime>,
+    message_id: Integer,
+}
+
+impl LiveLocationSession {
+    /// Creates a new `LiveLocationSession`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - A message sent with [`crate::types::SendLocation`]
+    ///   with a live period.
+    ///
+    /// Returns [`None`] if the message doesn't contain a live location.
+    pub fn new(message: &Message) -> Option<Self> {
+        let live_period = match message.data {
+            MessageData::Location(Location {
+                live_period: Some(live_period),
+                ..
+            }) => live_period,
+            _ => return None,
+        };
+        let expires_at = if live_period == LIVE_PERIOD_FOREVER {
+            None
+        } else {
+            let expires_at = message.date.saturating_add(live_period).max(0) as u64;
+            Some(UNIX_EPOCH + Duration::from_secs(expires_at))
+        };
+        Some(Self {
+            chat_id: message.chat.get_id(),
+            expires_at,
+            message_id: message.id,
+        })
+    }
+
+    /// Returns the time when the live period expires.
+    ///
+    /// Returns [`None`] if the location can be updated forever.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.expires_at
+    }
+
+    /// Edits the message with every location received from the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client to execute requests.
+    /// * `locations` - New locations; heading, horizontal accuracy
+    ///   and proximity alert radius are passed when set.
+    ///
+    /// When the stream ends, the live location is stopped
+    /// with [`StopMessageLiveLocation`].
+    /// When the live period expires, the session ends without requests,
+    /// since the message can't be edited anymore.
+    pub async fn run<S>(self, client: &Client, locations: S) -> Result<(), ExecuteError>
+    where
+        S: Stream<Item = Location>,
+    {
+        let clock = client.clock();
+        let delay = self
+            .expires_at
+            .map(|x| x.duration_since(clock.now()).unwrap_or_default());
+        let expired = async {
+            match delay {
+                Some(delay) => clock.sleep(delay).await,
+                None => std::future::pending().await,
+            }
+        };
+        tokio::pin!(expired);
+        tokio::pin!(locations);
+        loop {
+            let location = tokio::select! {
+                _ = &mut expired => return Ok(()),
+                location = locations.next() => location,
+            };
+            match location {
+                Some(location) => {
+                    client.execute(self.edit(location)).await?;
+                }
+                None => break,
+            }
+        }
+        client
+            .execute(StopMessageLiveLocation::for_chat_message(
+                self.chat_id,
+                self.message_id,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    fn edit(&self, location: Location) -> EditMessageLiveLocation {
+        let mut method = EditMessageLiveLocation::for_chat_message(
+            self.chat_id,
+            self.message_id,
+            location.latitude,
+            location.longitude,
+        );
+        if let Some(value) = location.heading {
+            method = method.with_heading(value);
+        }
+        if let Some(value) = location.horizontal_accuracy {
+            method = method.with_horizontal_accuracy(value);
+        }
+        if let Some(value) = location.proximity_alert_radius {
+            method = method.with_proximity_alert_radius(value);
+        }
+        method
+    }
+}
@@ -0,0 +1,586 @@
+use std::fmt;
+use super::cache::CachedMethod;
+
+/// Represents a name of a Bot API method supported by the crate.
+///
+/// Use it to key metrics, logs and other statistics by method
+/// instead of raw strings.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum MethodName {
+    /// [`crate::types::AddStickerToSet`]
+    AddStickerToSet,
+    /// [`crate::types::AnswerCallbackQuery`]
+    AnswerCallbackQuery,
+    /// [`crate::types::AnswerInlineQuery`]
+    AnswerInlineQuery,
+    /// [`crate::types::AnswerPreCheckoutQuery`]
+    AnswerPreCheckoutQuery,
+    /// [`crate::types::AnswerShippingQuery`]
+    AnswerShippingQuery,
+    /// [`crate::types::AnswerWebAppQuery`]
+    AnswerWebAppQuery,
+    /// [`crate::types::ApproveChatJoinRequest`]
+    ApproveChatJoinRequest,
+    /// [`crate::types::BanChatMember`]
+    BanChatMember,
+    /// [`crate::types::BanChatSenderChat`]
+    BanChatSenderChat,
+    /// [`crate::types::Close`]
+    Close,
+    /// [`crate::types::CloseForumTopic`]
+    CloseForumTopic,
+    /// [`crate::types::CloseGeneralForumTopic`]
+    CloseGeneralForumTopic,
+    /// [`crate::types::CopyMessage`]
+    CopyMessage,
+    /// [`crate::types::CopyMessages`]
+    CopyMessages,
+    /// [`crate::types::CreateChatInviteLink`]
+    CreateChatInviteLink,
+    /// [`crate::types::CreateChatSubscriptionInviteLink`]
+    CreateChatSubscriptionInviteLink,
+    /// [`crate::types::CreateForumTopic`]
+    CreateForumTopic,
+    /// [`crate::types::CreateInvoiceLink`]
+    CreateInvoiceLink,
+    /// [`crate::types::CreateNewStickerSet`]
+    CreateNewStickerSet,
+    /// [`crate::types::DeclineChatJoinRequest`]
+    DeclineChatJoinRequest,
+    /// [`crate::types::DeleteChatPhoto`]
+    DeleteChatPhoto,
+    /// [`crate::types::DeleteChatStickerSet`]
+    DeleteChatStickerSet,
+    /// [`crate::types::DeleteForumTopic`]
+    DeleteForumTopic,
+    /// [`crate::types::DeleteMessage`]
+    DeleteMessage,
+    /// [`crate::types::DeleteMessages`]
+    DeleteMessages,
+    /// [`crate::types::DeleteBotCommands`]
+    DeleteMyCommands,
+    /// [`crate::types::DeleteStickerFromSet`]
+    DeleteStickerFromSet,
+    /// [`crate::types::DeleteStickerSet`]
+    DeleteStickerSet,
+    /// [`crate::types::DeleteWebhook`]
+    DeleteWebhook,
+    /// [`crate::types::EditChatInviteLink`]
+    EditChatInviteLink,
+    /// [`crate::types::EditChatSubscriptionInviteLink`]
+    EditChatSubscriptionInviteLink,
+    /// [`crate::types::EditForumTopic`]
+    EditForumTopic,
+    /// [`crate::types::EditGeneralForumTopic`]
+    EditGeneralForumTopic,
+    /// [`crate::types::EditMessageCaption`]
+    EditMessageCaption,
+    /// [`crate::types::EditMessageLiveLocation`]
+    EditMessageLiveLocation,
+    /// [`crate::types::EditMessageMedia`]
+    EditMessageMedia,
+    /// [`crate::types::EditMessageReplyMarkup`]
+    EditMessageReplyMarkup,
+    /// [`crate::types::EditMessageText`]
+    EditMessageText,
+    /// [`crate::types::ExportChatInviteLink`]
+    ExportChatInviteLink,
+    /// [`crate::types::ForwardMessage`]
+    ForwardMessage,
+    /// [`crate::types::ForwardMessages`]
+    ForwardMessages,
+    /// [`crate::types::GetBusinessAccountStarBalance`]
+    GetBusinessAccountStarBalance,
+    /// [`crate::types::GetBusinessConnection`]
+    GetBusinessConnection,
+    /// [`crate::types::GetChat`]
+    GetChat,
+    /// [`crate::types::GetChatAdministrators`]
+    GetChatAdministrators,
+    /// [`crate::types::GetChatMember`]
+    GetChatMember,
+    /// [`crate::types::GetChatMemberCount`]
+    GetChatMemberCount,
+    /// [`crate::types::GetChatMenuButton`]
+    GetChatMenuButton,
+    /// [`crate::types::GetCustomEmojiStickers`]
+    GetCustomEmojiStickers,
+    /// [`crate::types::GetFile`]
+    GetFile,
+    /// [`crate::types::GetForumTopicIconStickers`]
+    GetForumTopicIconStickers,
+    /// [`crate::types::GetGameHighScores`]
+    GetGameHighScores,
+    /// [`crate::types::GetBot`]
+    GetMe,
+    /// [`crate::types::GetBotCommands`]
+    GetMyCommands,
+    /// [`crate::types::GetBotDefaultAdministratorRights`]
+    GetMyDefaultAdministratorRights,
+    /// [`crate::types::GetBotDescription`]
+    GetMyDescription,
+    /// [`crate::types::GetBotName`]
+    GetMyName,
+    /// [`crate::types::GetBotShortDescription`]
+    GetMyShortDescription,
+    /// [`crate::types::GetBotStarBalance`]
+    GetMyStarBalance,
+    /// [`crate::types::GetStarTransactions`]
+    GetStarTransactions,
+    /// [`crate::types::GetStickerSet`]
+    GetStickerSet,
+    /// [`crate::types::GetUpdates`]
+    GetUpdates,
+    /// [`crate::types::GetUserChatBoosts`]
+    GetUserChatBoosts,
+    /// [`crate::types::GetUserProfilePhotos`]
+    GetUserProfilePhotos,
+    /// [`crate::types::GetWebhookInfo`]
+    GetWebhookInfo,
+    /// [`crate::types::HideGeneralForumTopic`]
+    HideGeneralForumTopic,
+    /// [`crate::types::LeaveChat`]
+    LeaveChat,
+    /// [`crate::types::LogOut`]
+    LogOut,
+    /// [`crate::types::PinChatMessage`]
+    PinChatMessage,
+    /// [`crate::types::PromoteChatMember`]
+    PromoteChatMember,
+    /// [`crate::types::RefundStarPayment`]
+    RefundStarPayment,
+    /// [`crate::types::ReopenForumTopic`]
+    ReopenForumTopic,
+    /// [`crate::types::ReopenGeneralForumTopic`]
+    ReopenGeneralForumTopic,
+    /// [`crate::types::ReplaceStickerInSet`]
+    ReplaceStickerInSet,
+    /// [`crate::types::RestrictChatMember`]
+    RestrictChatMember,
+    /// [`crate::types::RevokeChatInviteLink`]
+    RevokeChatInviteLink,
+    /// [`crate::types::SendAnimation`]
+    SendAnimation,
+    /// [`crate::types::SendAudio`]
+    SendAudio,
+    /// [`crate::types::SendChatAction`]
+    SendChatAction,
+    /// [`crate::types::SendContact`]
+    SendContact,
+    /// [`crate::types::SendDice`]
+    SendDice,
+    /// [`crate::types::SendDocument`]
+    SendDocument,
+    /// [`crate::types::SendGame`]
+    SendGame,
+    /// [`crate::types::SendInvoice`]
+    SendInvoice,
+    /// [`crate::types::SendLocation`]
+    SendLocation,
+    /// [`crate::types::SendMediaGroup`]
+    SendMediaGroup,
+    /// [`crate::types::SendMessage`]
+    SendMessage,
+    /// [`crate::types::SendPaidMedia`]
+    SendPaidMedia,
+    /// [`crate::types::SendPhoto`]
+    SendPhoto,
+    /// [`crate::types::SendPoll`], [`crate::types::SendQuiz`]
+    SendPoll,
+    /// [`crate::types::SendSticker`]
+    SendSticker,
+    /// [`crate::types::SendVenue`]
+    SendVenue,
+    /// [`crate::types::SendVideo`]
+    SendVideo,
+    /// [`crate::types::SendVideoNote`]
+    SendVideoNote,
+    /// [`crate::types::SendVoice`]
+    SendVoice,
+    /// [`crate::types::SetChatAdministratorCustomTitle`]
+    SetChatAdministratorCustomTitle,
+    /// [`crate::types::SetChatDescription`]
+    SetChatDescription,
+    /// [`crate::types::SetChatMenuButton`]
+    SetChatMenuButton,
+    /// [`crate::types::SetChatPermissions`]
+    SetChatPermissions,
+    /// [`crate::types::SetChatPhoto`]
+    SetChatPhoto,
+    /// [`crate::types::SetChatStickerSet`]
+    SetChatStickerSet,
+    /// [`crate::types::SetChatTitle`]
+    SetChatTitle,
+    /// [`crate::types::SetCustomEmojiStickerSetThumbnail`]
+    SetCustomEmojiStickerSetThumbnail,
+    /// [`crate::types::SetGameScore`]
+    SetGameScore,
+    /// [`crate::types::SetMessageReaction`]
+    SetMessageReaction,
+    /// [`crate::types::SetBotCommands`]
+    SetMyCommands,
+    /// [`crate::types::SetBotDefaultAdministratorRights`]
+    SetMyDefaultAdministratorRights,
+    /// [`crate::types::SetBotDescription`]
+    SetMyDescription,
+    /// [`crate::types::SetBotName`]
+    SetMyName,
+    /// [`crate::types::SetBotShortDescription`]
+    SetMyShortDescription,
+    /// [`crate::types::SetPassportDataErrors`]
+    SetPassportDataErrors,
+    /// [`crate::types::SetStickerEmojiList`]
+    SetStickerEmojiList,
+    /// [`crate::types::SetStickerKeywords`]
+    SetStickerKeywords,
+    /// [`crate::types::SetStickerMaskPosition`]
+    SetStickerMaskPosition,
+    /// [`crate::types::SetStickerPositionInSet`]
+    SetStickerPositionInSet,
+    /// [`crate::types::SetStickerSetThumbnail`]
+    SetStickerSetThumbnail,
+    /// [`crate::types::SetStickerSetTitle`]
+    SetStickerSetTitle,
+    /// [`crate::types::SetWebhook`]
+    SetWebhook,
+    /// [`crate::types::StopMessageLiveLocation`]
+    StopMessageLiveLocation,
+    /// [`crate::types::StopPoll`]
+    StopPoll,
+    /// [`crate::types::UnbanChatMember`]
+    UnbanChatMember,
+    /// [`crate::types::UnbanChatSenderChat`]
+    UnbanChatSenderChat,
+    /// [`crate::types::UnhideGeneralForumTopic`]
+    UnhideGeneralForumTopic,
+    /// [`crate::types::UnpinAllChatMessages`]
+    UnpinAllChatMessages,
+    /// [`crate::types::UnpinAllForumTopicMessages`]
+    UnpinAllForumTopicMessages,
+    /// [`crate::types::UnpinAllGeneralForumTopicMessages`]
+    UnpinAllGeneralForumTopicMessages,
+    /// [`crate::types::UnpinChatMessage`]
+    UnpinChatMessage,
+    /// [`crate::types::UploadStickerFile`]
+    UploadStickerFile,
+}
+
+impl MethodName {
+    /// Returns the name of the method as used in the Bot API.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::AddStickerToSet => "addStickerToSet",
+            Self::AnswerCallbackQuery => "answerCallbackQuery",
+            Self::AnswerInlineQuery => "answerInlineQuery",
+            Self::AnswerPreCheckoutQuery => "answerPreCheckoutQuery",
+            Self::AnswerShippingQuery => "answerShippingQuery",
+            Self::AnswerWebAppQuery => "answerWebAppQuery",
+            Self::ApproveChatJoinRequest => "approveChatJoinRequest",
+            Self::BanChatMember => "banChatMember",
+            Self::BanChatSenderChat => "banChatSenderChat",
+            Self::Close => "close",
+            Self::CloseForumTopic => "closeForumTopic",
+            Self::CloseGeneralForumTopic => "closeGeneralForumTopic",
+            Self::CopyMessage => "copyMessage",
+            Self::CopyMessages => "copyMessages",
+            Self::CreateChatInviteLink => "createChatInviteLink",
+            Self::CreateChatSubscriptionInviteLink => "createChatSubscriptionInviteLink",
+            Self::CreateForumTopic => "createForumTopic",
+            Self::CreateInvoiceLink => "createInvoiceLink",
+            Self::CreateNewStickerSet => "createNewStickerSet",
+            Self::DeclineChatJoinRequest => "declineChatJoinRequest",
+            Self::DeleteChatPhoto => "deleteChatPhoto",
+            Self::DeleteChatStickerSet => "deleteChatStickerSet",
+            Self::DeleteForumTopic => "deleteForumTopic",
+            Self::DeleteMessage => "deleteMessage",
+            Self::DeleteMessages => "deleteMessages",
+            Self::DeleteMyCommands => "deleteMyCommands",
+            Self::DeleteStickerFromSet => "deleteStickerFromSet",
+            Self::DeleteStickerSet => "deleteStickerSet",
+            Self::DeleteWebhook => "deleteWebhook",
+            Self::EditChatInviteLink => "editChatInviteLink",
+            Self::EditChatSubscriptionInviteLink => "editChatSubscriptionInviteLink",
+            Self::EditForumTopic => "editForumTopic",
+            Self::EditGeneralForumTopic => "editGeneralForumTopic",
+            Self::EditMessageCaption => "editMessageCaption",
+            Self::EditMessageLiveLocation => "editMessageLiveLocation",
+            Self::EditMessageMedia => "editMessageMedia",
+            Self::EditMessageReplyMarkup => "editMessageReplyMarkup",
+            Self::EditMessageText => "editMessageText",
+            Self::ExportChatInviteLink => "exportChatInviteLink",
+            Self::ForwardMessage => "forwardMessage",
+            Self::ForwardMessages => "forwardMessages",
+            Self::GetBusinessAccountStarBalance => "getBusinessAccountStarBalance",
+            Self::GetBusinessConnection => "getBusinessConnection",
+            Self::GetChat => "getChat",
+            Self::GetChatAdministrators => "getChatAdministrators",
+            Self::GetChatMember => "getChatMember",
+            Self::GetChatMemberCount => "getChatMemberCount",
+            Self::GetChatMenuButton => "getChatMenuButton",
+            Self::GetCustomEmojiStickers => "getCustomEmojiStickers",
+            Self::GetFile => "getFile",
+            Self::GetForumTopicIconStickers => "getForumTopicIconStickers",
+            Self::GetGameHighScores => "getGameHighScores",
+            Self::GetMe => "getMe",
+            Self::GetMyCommands => "getMyCommands",
+            Self::GetMyDefaultAdministratorRights => "getMyDefaultAdministratorRights",
+            Self::GetMyDescription => "getMyDescription",
+            Self::GetMyName => "getMyName",
+            Self::GetMyShortDescription => "getMyShortDescription",
+            Self::GetMyStarBalance => "getMyStarBalance",
+            Self::GetStarTransactions => "getStarTransactions",
+            Self::GetStickerSet => "getStickerSet",
+            Self::GetUpdates => "getUpdates",
+            Self::GetUserChatBoosts => "getUserChatBoosts",
+            Self::GetUserProfilePhotos => "getUserProfilePhotos",
+            Self::GetWebhookInfo => "getWebhookInfo",
+            Self::HideGeneralForumTopic => "hideGeneralForumTopic",
+            Self::LeaveChat => "leaveChat",
+            Self::LogOut => "logOut",
+            Self::PinChatMessage => "pinChatMessage",
+            Self::PromoteChatMember => "promoteChatMember",
+            Self::RefundStarPayment => "refundStarPayment",
+            Self::ReopenForumTopic => "reopenForumTopic",
+            Self::ReopenGeneralForumTopic => "reopenGeneralForumTopic",
+            Self::ReplaceStickerInSet => "replaceStickerInSet",
+            Self::RestrictChatMember => "restrictChatMember",
+            Self::RevokeChatInviteLink => "revokeChatInviteLink",
+            Self::SendAnimation => "sendAnimation",
+            Self::SendAudio => "sendAudio",
+            Self::SendChatAction => "sendChatAction",
+            Self::SendContact => "sendContact",
+            Self::SendDice => "sendDice",
+            Self::SendDocument => "sendDocument",
+            Self::SendGame => "sendGame",
+            Self::SendInvoice => "sendInvoice",
+            Self::SendLocation => "sendLocation",
+            Self::SendMediaGroup => "sendMediaGroup",
+            Self::SendMessage => "sendMessage",
+            Self::SendPaidMedia => "sendPaidMedia",
+            Self::SendPhoto => "sendPhoto",
+            Self::SendPoll => "sendPoll",
+            Self::SendSticker => "sendSticker",
+            Self::SendVenue => "sendVenue",
+            Self::SendVideo => "sendVideo",
+            Self::SendVideoNote => "sendVideoNote",
+            Self::SendVoice => "sendVoice",
+            Self::SetChatAdministratorCustomTitle => "setChatAdministratorCustomTitle",
+            Self::SetChatDescription => "setChatDescription",
+            Self::SetChatMenuButton => "setChatMenuButton",
+            Self::SetChatPermissions => "setChatPermissions",
+            Self::SetChatPhoto => "setChatPhoto",
+            Self::SetChatStickerSet => "setChatStickerSet",
+            Self::SetChatTitle => "setChatTitle",
+            Self::SetCustomEmojiStickerSetThumbnail => "setCustomEmojiStickerSetThumbnail",
+            Self::SetGameScore => "setGameScore",
+            Self::SetMessageReaction => "setMessageReaction",
+            Self::SetMyCommands => "setMyCommands",
+            Self::SetMyDefaultAdministratorRights => "setMyDefaultAdministratorRights",
+            Self::SetMyDescription => "setMyDescription",
+            Self::SetMyName => "setMyName",
+            Self::SetMyShortDescription => "setMyShortDescription",
+            Self::SetPassportDataErrors => "setPassportDataErrors",
+            Self::SetStickerEmojiList => "setStickerEmojiList",
+            Self::SetStickerKeywords => "setStickerKeywords",
+            Self::SetStickerMaskPosition => "setStickerMaskPosition",
+            Self::SetStickerPositionInSet => "setStickerPositionInSet",
+            Self::SetStickerSetThumbnail => "setStickerSetThumbnail",
+            Self::SetStickerSetTitle => "setStickerSetTitle",
+            Self::SetWebhook => "setWebhook",
+            Self::StopMessageLiveLocation => "stopMessageLiveLocation",
+            Self::StopPoll => "stopPoll",
+            Self::UnbanChatMember => "unbanChatMember",
+            Self::UnbanChatSenderChat => "unbanChatSenderChat",
+            Self::UnhideGeneralForumTopic => "unhideGeneralForumTopic",
+            Self::UnpinAllChatMessages => "unpinAllChatMessages",
+            Self::UnpinAllForumTopicMessages => "unpinAllForumTopicMessages",
+            Self::UnpinAllGeneralForumTopicMessages => "unpinAllGeneralForumTopicMessages",
+            Self::UnpinChatMessage => "unpinChatMessage",
+            Self::UploadStickerFile => "uploadStickerFile",
+        }
+    }
+
+    /// Returns a method by its name as used in the Bot API.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Name of the method, e.g. `sendMessage`.
+    ///
+    /// Returns [`None`] if the method is not supported.
+    pub fn from_name(value: &str) -> Option<Self> {
+        Some(match value {
+            "addStickerToSet" => Self::AddStickerToSet,
+            "answerCallbackQuery" => Self::AnswerCallbackQuery,
+            "answerInlineQuery" => Self::AnswerInlineQuery,
+            "answerPreCheckoutQuery" => Self::AnswerPreCheckoutQuery,
+            "answerShippingQuery" => Self::AnswerShippingQuery,
+            "answerWebAppQuery" => Self::AnswerWebAppQuery,
+            "approveChatJoinRequest" => Self::ApproveChatJoinRequest,
+            "banChatMember" => Self::BanChatMember,
+            "banChatSenderChat" => Self::BanChatSenderChat,
+            "close" => Self::Close,
+            "closeForumTopic" => Self::CloseForumTopic,
+            "closeGeneralForumTopic" => Self::CloseGeneralForumTopic,
+            "copyMessage" => Self::CopyMessage,
+            "copyMessages" => Self::CopyMessages,
+            "createChatInviteLink" => Self::CreateChatInviteLink,
+            "createChatSubscriptionInviteLink" => Self::CreateChatSubscriptionInviteLink,
+            "createForumTopic" => Self::CreateForumTopic,
+            "createInvoiceLink" => Self::CreateInvoiceLink,
+            "createNewStickerSet" => Self::CreateNewStickerSet,
+            "declineChatJoinRequest" => Self::DeclineChatJoinRequest,
+            "deleteChatPhoto" => Self::DeleteChatPhoto,
+            "deleteChatStickerSet" => Self::DeleteChatStickerSet,
+            "deleteForumTopic" => Self::DeleteForumTopic,
+            "deleteMessage" => Self::DeleteMessage,
+            "deleteMessages" => Self::DeleteMessages,
+            "deleteMyCommands" => Self::DeleteMyCommands,
+            "deleteStickerFromSet" => Self::DeleteStickerFromSet,
+            "deleteStickerSet" => Self::DeleteStickerSet,
+            "deleteWebhook" => Self::DeleteWebhook,
+            "editChatInviteLink" => Self::EditChatInviteLink,
+            "editChatSubscriptionInviteLink" => Self::EditChatSubscriptionInviteLink,
+            "editForumTopic" => Self::EditForumTopic,
+            "editGeneralForumTopic" => Self::EditGeneralForumTopic,
+            "editMessageCaption" => Self::EditMessageCaption,
+            "editMessageLiveLocation" => Self::EditMessageLiveLocation,
+            "editMessageMedia" => Self::EditMessageMedia,
+            "editMessageReplyMarkup" => Self::EditMessageReplyMarkup,
+            "editMessageText" => Self::EditMessageText,
+            "exportChatInviteLink" => Self::ExportChatInviteLink,
+            "forwardMessage" => Self::ForwardMessage,
+            "forwardMessages" => Self::ForwardMessages,
+            "getBusinessAccountStarBalance" => Self::GetBusinessAccountStarBalance,
+            "getBusinessConnection" => Self::GetBusinessConnection,
+            "getChat" => Self::GetChat,
+            "getChatAdministrators" => Self::GetChatAdministrators,
+            "getChatMember" => Self::GetChatMember,
+            "getChatMemberCount" => Self::GetChatMemberCount,
+            "getChatMenuButton" => Self::GetChatMenuButton,
+            "getCustomEmojiStickers" => Self::GetCustomEmojiStickers,
+            "getFile" => Self::GetFile,
+            "getForumTopicIconStickers" => Self::GetForumTopicIconStickers,
+            "getGameHighScores" => Self::GetGameHighScores,
+            "getMe" => Self::GetMe,
+            "getMyCommands" => Self::GetMyCommands,
+            "getMyDefaultAdministratorRights" => Self::GetMyDefaultAdministratorRights,
+            "getMyDescription" => Self::GetMyDescription,
+            "getMyName" => Self::GetMyName,
+            "getMyShortDescription" => Self::GetMyShortDescription,
+            "getMyStarBalance" => Self::GetMyStarBalance,
+            "getStarTransactions" => Self::GetStarTransactions,
+            "getStickerSet" => Self::GetStickerSet,
+            "getUpdates" => Self::GetUpdates,
+            "getUserChatBoosts" => Self::GetUserChatBoosts,
+            "getUserProfilePhotos" => Self::GetUserProfilePhotos,
+            "getWebhookInfo" => Self::GetWebhookInfo,
+            "hideGeneralForumTopic" => Self::HideGeneralForumTopic,
+            "leaveChat" => Self::LeaveChat,
+            "logOut" => Self::LogOut,
+            "pinChatMessage" => Self::PinChatMessage,
+            "promoteChatMember" => Self::PromoteChatMember,
+            "refundStarPayment" => Self::RefundStarPayment,
+            "reopenForumTopic" => Self::ReopenForumTopic,
+            "reopenGeneralForumTopic" => Self::ReopenGeneralForumTopic,
+            "replaceStickerInSet" => Self::ReplaceStickerInSet,
+            "restrictChatMember" => Self::RestrictChatMember,
+            "revokeChatInviteLink" => Self::RevokeChatInviteLink,
+            "sendAnimation" => Self::SendAnimation,
+            "sendAudio" => Self::SendAudio,
+            "sendChatAction" => Self::SendChatAction,
+            "sendContact" => Self::SendContact,
+            "sendDice" => Self::SendDice,
+            "sendDocument" => Self::SendDocument,
+            "sendGame" => Self::SendGame,
+            "sendInvoice" => Self::SendInvoice,
+            "sendLocation" => Self::SendLocation,
+            "sendMediaGroup" => Self::SendMediaGroup,
+            "sendMessage" => Self::SendMessage,
+            "sendPaidMedia" => Self::SendPaidMedia,
+            "sendPhoto" => Self::SendPhoto,
+            "sendPoll" => Self::SendPoll,
+            "sendSticker" => Self::SendSticker,
+            "sendVenue" => Self::SendVenue,
+            "sendVideo" => Self::SendVideo,
+            "sendVideoNote" => Self::SendVideoNote,
+            "sendVoice" => Self::SendVoice,
+            "setChatAdministratorCustomTitle" => Self::SetChatAdministratorCustomTitle,
+            "setChatDescription" => Self::SetChatDescription,
+            "setChatMenuButton" => Self::SetChatMenuButton,
+            "setChatPermissions" => Self::SetChatPermissions,
+            "setChatPhoto" => Self::SetChatPhoto,
+            "setChatStickerSet" => Self::SetChatStickerSet,
+            "setChatTitle" => Self::SetChatTitle,
+            "setCustomEmojiStickerSetThumbnail" => Self::SetCustomEmojiStickerSetThumbnail,
+            "setGameScore" => Self::SetGameScore,
+            "setMessageReaction" => Self::SetMessageReaction,
+            "setMyCommands" => Self::SetMyCommands,
+            "setMyDefaultAdministratorRights" => Self::SetMyDefaultAdministratorRights,
+            "setMyDescription" => Self::SetMyDescription,
+            "setMyName" => Self::SetMyName,
+            "setMyShortDescription" => Self::SetMyShortDescription,
+            "setPassportDataErrors" => Self::SetPassportDataErrors,
+            "setStickerEmojiList" => Self::SetStickerEmojiList,
+            "setStickerKeywords" => Self::SetStickerKeywords,
+            "setStickerMaskPosition" => Self::SetStickerMaskPosition,
+            "setStickerPositionInSet" => Self::SetStickerPositionInSet,
+            "setStickerSetThumbnail" => Self::SetStickerSetThumbnail,
+            "setStickerSetTitle" => Self::SetStickerSetTitle,
+            "setWebhook" => Self::SetWebhook,
+            "stopMessageLiveLocation" => Self::StopMessageLiveLocation,
+            "stopPoll" => Self::StopPoll,
+            "unbanChatMember" => Self::UnbanChatMember,
+            "unbanChatSenderChat" => Self::UnbanChatSenderChat,
+            "unhideGeneralForumTopic" => Self::UnhideGeneralForumTopic,
+            "unpinAllChatMessages" => Self::UnpinAllChatMessages,
+            "unpinAllForumTopicMessages" => Self::UnpinAllForumTopicMessages,
+            "unpinAllGeneralForumTopicMessages" => Self::UnpinAllGeneralForumTopicMessages,
+            "unpinChatMessage" => Self::UnpinChatMessage,
+            "uploadStickerFile" => Self::UploadStickerFile,
+            _ => return None,
+        })
+    }
+
+    /// Returns whether the method accepts file uploads.
+    pub const fn accepts_uploads(&self) -> bool {
+        matches!(
+            self,
+            Self::AddStickerToSet
+                | Self::CreateNewStickerSet
+                | Self::EditMessageMedia
+                | Self::ReplaceStickerInSet
+                | Self::SendAnimation
+                | Self::SendAudio
+                | Self::SendDocument
+                | Self::SendMediaGroup
+                | Self::SendPaidMedia
+                | Self::SendPhoto
+                | Self::SendSticker
+                | Self::SendVideo
+                | Self::SendVideoNote
+                | Self::SendVoice
+                | Self::SetChatPhoto
+                | Self::SetStickerSetThumbnail
+                | Self::SetWebhook
+                | Self::UploadStickerFile
+        )
+    }
+}
+
+impl fmt::Display for MethodName {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        out.write_str(self.as_str())
+    }
+}
+
+impl From<CachedMethod> for MethodName {
+    fn from(value: CachedMethod) -> Self {
+        match value {
+            CachedMethod::GetChat => Self::GetChat,
+            CachedMethod::GetChatMember => Self::GetChatMember,
+            CachedMethod::GetMe => Self::GetMe,
+            CachedMethod::GetStickerSet => Self::GetStickerSet,
+        }
+    }
+}
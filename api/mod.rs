@@ -1,5 +1,41 @@
-pub use self::{client::*, form::*, payload::*};
+pub use self::{
+    batch::*,
+    builder::*,
+    cache::*,
+    chat_action::*,
+    checkout::*,
+    client::*,
+    clock::*,
+    command_sync::*,
+    form::*,
+    live_location::*,
+    method_name::*,
+    observer::*,
+    payload::*,
+    polling::*,
+    quiet::*,
+    sequenced::*,
+    star_balance::*,
+    upsert::*,
+};
 
+mod batch;
+mod builder;
+mod cache;
+mod callback_answer;
+mod chat_action;
+mod checkout;
 mod client;
+mod clock;
+mod command_sync;
 mod form;
+mod live_location;
+mod method_name;
+mod observer;
 mod payload;
+mod polling;
+mod quiet;
+mod sequenced;
+mod star_balance;
+mod star_ledger;
+mod upsert;
@@ -0,0 +1,92 @@
+use std::time::Duration;
+use super::{client::ExecuteError, method_name::MethodName};
+use crate::types::Update;
+
+/// Receives events about requests executed by [`crate::api::Client`].
+///
+/// Use it to export metrics, e.g. to Prometheus or OpenTelemetry;
+/// see [`crate::api::Client::with_request_observer`].
+///
+/// Any `Fn(&RequestEvent)` closure is an observer.
+pub trait RequestObserver: Send + Sync {
+    /// Called when a request is completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - Information about the request.
+    ///
+    /// The method is called on the task executing the request,
+    /// so it should not block.
+    fn observe(&self, event: &RequestEvent);
+}
+
+impl<F> RequestObserver for F
+where
+    F: Fn(&RequestEvent) + Send + Sync,
+{
+    fn observe(&self, event: &RequestEvent) {
+        self(event)
+    }
+}
+
+/// Receives lags of updates received by [`crate::api::UpdatePoller`].
+///
+/// Use it to export a lag metric and detect a backlog of undelivered updates;
+/// see [`crate::api::UpdatePoller::with_lag_observer`].
+///
+/// Any `Fn(&Update, Duration)` closure is an observer.
+pub trait UpdateLagObserver: Send + Sync {
+    /// Called for every received update with a date.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - The received update.
+    /// * `lag` - Time passed between the event and receipt of the update, see [`Update::lag`].
+    fn observe(&self, update: &Update, lag: Duration);
+}
+
+impl<F> UpdateLagObserver for F
+where
+    F: Fn(&Update, Duration) + Send + Sync,
+{
+    fn observe(&self, update: &Update, lag: Duration) {
+        self(update, lag)
+    }
+}
+
+/// Represents a completed request.
+///
+/// Responses served from the [`crate::api::ResponseCache`] are not requests
+/// and are not observed.
+#[derive(Debug)]
+pub struct RequestEvent<'a> {
+    pub(super) duration: Duration,
+    pub(super) method: MethodName,
+    pub(super) outcome: Result<(), &'a ExecuteError>,
+    pub(super) payload_size: Option<usize>,
+}
+
+impl RequestEvent<'_> {
+    /// Returns the name of the method, e.g. [`MethodName::SendMessage`].
+    pub fn method(&self) -> MethodName {
+        self.method
+    }
+
+    /// Returns the time spent on the request including retries.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the size of the request body in bytes.
+    ///
+    /// The size is [`None`] for multipart bodies, since they are streamed,
+    /// and when the request could not be built.
+    pub fn payload_size(&self) -> Option<usize> {
+        self.payload_size
+    }
+
+    /// Returns the outcome of the request.
+    pub fn outcome(&self) -> Result<(), &ExecuteError> {
+        self.outcome
+    }
+}
@@ -1,8 +1,18 @@
-use super::form::{Form, FormError};
+use super::{
+    form::{Form, FormError},
+    method_name::MethodName,
+};
+use crate::types::Limits;
+use flate2::{write::GzEncoder, Compression};
 use reqwest::{Client as HttpClient, Method as HttpMethod, RequestBuilder as HttpRequestBuilder};
 use serde::ser::Serialize;
 use serde_json::Error as JsonError;
-use std::{error::Error, fmt};
+use std::{
+    error::Error,
+    fmt,
+    io::{Error as IoError, Write},
+    time::Duration,
+};
 
 #[derive(Debug)]
 enum PayloadData {
@@ -15,37 +25,107 @@ enum PayloadData {
 #[derive(Debug)]
 pub struct Payload {
     http_method: HttpMethod,
+    method: MethodName,
+    not_modified_as_success: bool,
     payload_data: PayloadData,
-    url_path: String,
+    polling_timeout: Option<Duration>,
 }
 
 impl Payload {
-    pub(crate) fn form<P: Into<String>>(path: P, form: Form) -> Self {
+    pub(crate) fn form(method: MethodName, form: Form) -> Self {
         Self {
             http_method: HttpMethod::POST,
+            method,
+            not_modified_as_success: false,
             payload_data: PayloadData::Form(form),
-            url_path: path.into(),
+            polling_timeout: None,
         }
     }
 
-    pub(crate) fn json<P: Into<String>>(path: P, data: impl Serialize) -> Self {
+    pub(crate) fn json(method: MethodName, data: impl Serialize) -> Self {
         Self {
             http_method: HttpMethod::POST,
+            method,
+            not_modified_as_success: false,
             payload_data: PayloadData::Json(serde_json::to_string(&data)),
-            url_path: path.into(),
+            polling_timeout: None,
         }
     }
 
-    pub(crate) fn empty<P: Into<String>>(path: P) -> Self {
+    /// Creates a payload which fails with a serialization error when executed.
+    pub(crate) fn json_error(method: MethodName, err: JsonError) -> Self {
+        Self {
+            http_method: HttpMethod::POST,
+            method,
+            not_modified_as_success: false,
+            payload_data: PayloadData::Json(Err(err)),
+            polling_timeout: None,
+        }
+    }
+
+    pub(crate) fn empty(method: MethodName) -> Self {
         Self {
             http_method: HttpMethod::GET,
+            method,
+            not_modified_as_success: false,
             payload_data: PayloadData::Empty,
-            url_path: path.into(),
+            polling_timeout: None,
+        }
+    }
+
+    pub(crate) fn with_polling_timeout(mut self, value: Duration) -> Self {
+        self.polling_timeout = Some(value);
+        self
+    }
+
+    pub(crate) fn with_not_modified_as_success(mut self, value: bool) -> Self {
+        self.not_modified_as_success = value;
+        self
+    }
+
+    pub(super) fn not_modified_as_success(&self) -> bool {
+        self.not_modified_as_success
+    }
+
+    pub(super) fn polling_timeout(&self) -> Option<Duration> {
+        self.polling_timeout
+    }
+
+    pub(crate) fn method(&self) -> MethodName {
+        self.method
+    }
+
+    pub(super) fn form_field(&self, name: &str) -> Option<&str> {
+        match self.payload_data {
+            PayloadData::Form(ref form) => form.get_text_field(name),
+            _ => None,
+        }
+    }
+
+    pub(super) fn has_files(&self) -> bool {
+        match self.payload_data {
+            PayloadData::Form(ref form) => form.has_files(),
+            _ => false,
+        }
+    }
+
+    pub(super) fn json_body(&self) -> Option<&str> {
+        match self.payload_data {
+            PayloadData::Json(Ok(ref data)) => Some(data),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn into_json_body(self) -> Option<Result<String, JsonError>> {
+        match self.payload_data {
+            PayloadData::Form(form) => form.into_json(),
+            PayloadData::Json(data) => Some(data),
+            PayloadData::Empty => Some(Ok(String::from("{}"))),
         }
     }
 
-    pub(super) fn build_url(&self, base_url: &str, token: &str) -> String {
-        format!("{}/bot{}/{}", base_url, token, self.url_path)
+    fn build_url(&self, base_url: &str, token: &str) -> String {
+        format!("{}/bot{}/{}", base_url, token, self.method)
     }
 
     pub(super) fn into_http_request_builder(
@@ -53,19 +133,30 @@ impl Payload {
         http_client: &HttpClient,
         base_url: &str,
         token: &str,
+        compression_threshold: Option<usize>,
+        limits: &Limits,
     ) -> Result<HttpRequestBuilder, PayloadError> {
         let url = self.build_url(base_url, token);
         let builder = http_client.request(self.http_method, url);
         Ok(match self.payload_data {
             PayloadData::Form(form) => {
+                form.check_file_sizes(limits)?;
                 let form = form.try_into()?;
                 builder.multipart(form)
             }
             PayloadData::Json(data) => {
                 let data = data?;
-                builder
-                    .header("Content-Type", "application/json")
-                    .body(data)
+                let builder = builder.header("Content-Type", "application/json");
+                match compression_threshold {
+                    Some(threshold) if data.len() >= threshold => {
+                        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                        encoder.write_all(data.as_bytes())?;
+                        builder
+                            .header("Content-Encoding", "gzip")
+                            .body(encoder.finish()?)
+                    }
+                    _ => builder.body(data),
+                }
             }
             PayloadData::Empty => builder,
         })
@@ -75,6 +166,8 @@ impl Payload {
 /// An error when building an HTTP request
 #[derive(Debug, derive_more::From)]
 pub enum PayloadError {
+    /// Failed to compress a body
+    Compress(IoError),
     /// Failed to build a form body
     Form(FormError),
     /// Failed to build a JSON body
@@ -85,6 +178,7 @@ impl Error for PayloadError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         use self::PayloadError::*;
         Some(match self {
+            Compress(err) => err,
             Form(err) => err,
             Json(err) => err,
         })
@@ -98,6 +192,7 @@ impl fmt::Display for PayloadError {
             out,
             "could not build an HTTP request: {}",
             match self {
+                Compress(err) => err.to_string(),
                 Form(err) => err.to_string(),
                 Json(err) => err.to_string(),
             }
@@ -0,0 +1,352 @@
+use std::{
+    error::Error,
+    fmt, fs,
+    io::{Error as IoError, ErrorKind as IoErrorKind},
+    path::PathBuf,
+    sync::Mutex,
+    time::SystemTime,
+    vec::IntoIter,
+};
+use super::{
+    client::{Client, ExecuteError},
+    observer::UpdateLagObserver,
+};
+use crate::types::{GetUpdates, Integer, Update};
+
+/// Persists the offset of [`GetUpdates`] across restarts.
+pub trait OffsetStorage: Send + Sync {
+    /// Returns the identifier of the last confirmed update.
+    fn load(&self) -> Result<Option<Integer>, IoError>;
+
+    /// Saves the identifier of the last confirmed update.
+    ///
+    /// # Arguments
+    ///
+    /// * `update_id` - Identifier of the last processed update.
+    fn save(&self, update_id: Integer) -> Result<(), IoError>;
+}
+
+/// Keeps the offset in memory.
+#[derive(Debug, Default)]
+pub struct MemoryOffsetStorage {
+    value: Mutex<Option<Integer>>,
+}
+
+impl MemoryOffsetStorage {
+    /// Creates a new `MemoryOffsetStorage`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl OffsetStorage for MemoryOffsetStorage {
+    fn load(&self) -> Result<Option<Integer>, IoError> {
+        Ok(*self.value.lock().unwrap())
+    }
+
+    fn save(&self, update_id: Integer) -> Result<(), IoError> {
+        *self.value.lock().unwrap() = Some(update_id);
+        Ok(())
+    }
+}
+
+/// Keeps the offset in a file.
+///
+/// The file is replaced atomically, so it is never left half-written.
+#[derive(Clone, Debug)]
+pub struct FileOffsetStorage {
+    path: PathBuf,
+}
+
+impl FileOffsetStorage {
+    /// Creates a new `FileOffsetStorage`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the file; created on the first save.
+    pub fn new<T>(path: T) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        Self { path: path.into() }
+    }
+}
+
+impl OffsetStorage for FileOffsetStorage {
+    fn load(&self) -> Result<Option<Integer>, IoError> {
+        match fs::read_to_string(&self.path) {
+            Ok(data) => data
+                .trim()
+                .parse()
+                .map(Some)
+                .map_err(|err| IoError::new(IoErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == IoErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn save(&self, update_id: Integer) -> Result<(), IoError> {
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        fs::write(&tmp_path, update_id.to_string())?;
+        fs::rename(&tmp_path, &self.path)
+    }
+}
+
+type PoisonHandler = dyn FnMut(Update) + Send;
+
+/// Receives updates using long polling and persists the offset.
+///
+/// Updates of a batch are confirmed when the next batch is requested,
+/// so a batch interrupted by a restart is received again.
+///
+/// Use [`Self::with_manual_acknowledgment`] for bots that must not drop updates.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run(client: tg::Client) -> Result<(), tg::PollingError> {
+/// use std::time::Duration;
+/// use tg::{FileOffsetStorage, GetUpdates, UpdatePoller};
+///
+/// let method = GetUpdates::default().with_timeout(Duration::from_secs(60));
+/// let mut poller = UpdatePoller::new(client, FileOffsetStorage::new("offset"), method);
+/// loop {
+///     let batch = poller.next_batch().await?;
+///     let received_at = batch.received_at();
+///     for update in batch {
+///         println!("{:?} lag: {:?}", update, update.lag(received_at));
+///     }
+/// }
+/// # }
+/// ```
+pub struct UpdatePoller<S> {
+    client: Client,
+    confirmed_update_id: Option<Integer>,
+    delivery: Option<(Integer, u32)>,
+    is_loaded: bool,
+    lag_observer: Option<Box<dyn UpdateLagObserver>>,
+    max_attempts: Option<u32>,
+    method: GetUpdates,
+    poison_handler: Option<Box<PoisonHandler>>,
+    received_update_id: Option<Integer>,
+    storage: S,
+}
+
+impl<S> UpdatePoller<S>
+where
+    S: OffsetStorage,
+{
+    /// Creates a new `UpdatePoller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client to execute requests.
+    /// * `storage` - Storage of the offset.
+    /// * `method` - Parameters of requests; the offset is overridden.
+    pub fn new(client: Client, storage: S, method: GetUpdates) -> Self {
+        Self {
+            client,
+            confirmed_update_id: None,
+            delivery: None,
+            is_loaded: false,
+            lag_observer: None,
+            max_attempts: None,
+            method,
+            poison_handler: None,
+            received_update_id: None,
+            storage,
+        }
+    }
+
+    /// Sets a new observer of update lags.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - An observer called for every received update with a date;
+    ///   the lag is measured from the receipt time of the batch, see [`UpdateBatch::received_at`].
+    pub fn with_lag_observer<T>(mut self, value: T) -> Self
+    where
+        T: UpdateLagObserver + 'static,
+    {
+        self.lag_observer = Some(Box::new(value));
+        self
+    }
+
+    /// Enables the manual acknowledgment mode.
+    ///
+    /// In this mode the offset advances only when [`Self::acknowledge`] is called,
+    /// so updates which were not processed successfully are received again.
+    /// Acknowledge an update after its handler or an outbox commit succeeds.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts` - Number of times the first unacknowledged update is received
+    ///   before it is considered poisoned and skipped; see [`Self::with_poison_handler`].
+    pub fn with_manual_acknowledgment(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Sets a new handler of poisoned updates.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function called with every skipped update;
+    ///   use it to log the update or to move it to a dead letter queue.
+    pub fn with_poison_handler<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(Update) + Send + 'static,
+    {
+        self.poison_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Confirms an update and all previous ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `update_id` - Identifier of the update.
+    ///
+    /// Updates must be acknowledged in the order they were received;
+    /// the call is ignored if a later update is already confirmed.
+    pub fn acknowledge(&mut self, update_id: Integer) -> Result<(), PollingError> {
+        if self.confirmed_update_id.is_some_and(|x| x >= update_id) {
+            return Ok(());
+        }
+        self.storage.save(update_id)?;
+        self.confirmed_update_id = Some(update_id);
+        if self.delivery.is_some_and(|(x, _)| x <= update_id) {
+            self.delivery = None;
+        }
+        Ok(())
+    }
+
+    /// Returns the next batch of updates.
+    ///
+    /// The offset is loaded from the storage on the first call.
+    /// Unless the manual acknowledgment mode is enabled,
+    /// the previous batch is confirmed before the request.
+    ///
+    /// The receipt time of the batch is taken from the clock of the client.
+    pub async fn next_batch(&mut self) -> Result<UpdateBatch, PollingError> {
+        if !self.is_loaded {
+            self.confirmed_update_id = self.storage.load()?;
+            self.is_loaded = true;
+        }
+        if let Some(update_id) = self.received_update_id.take() {
+            self.acknowledge(update_id)?;
+        }
+        let mut method = self.method.clone();
+        if let Some(update_id) = self.confirmed_update_id {
+            method = method.with_offset(update_id + 1);
+        }
+        let mut updates = self.client.execute(method).await?;
+        let received_at = self.client.clock().now();
+        match self.max_attempts {
+            Some(max_attempts) => {
+                if let Some(update) = updates.first() {
+                    let attempts = match self.delivery {
+                        Some((update_id, attempts)) if update_id == update.id => attempts + 1,
+                        _ => 1,
+                    };
+                    if attempts > max_attempts {
+                        let update = updates.remove(0);
+                        self.acknowledge(update.id)?;
+                        if let Some(ref mut handler) = self.poison_handler {
+                            handler(update);
+                        }
+                    } else {
+                        self.delivery = Some((update.id, attempts));
+                    }
+                }
+            }
+            None => self.received_update_id = updates.last().map(|x| x.id),
+        }
+        if let Some(ref observer) = self.lag_observer {
+            for update in &updates {
+                if let Some(lag) = update.lag(received_at) {
+                    observer.observe(update, lag);
+                }
+            }
+        }
+        Ok(UpdateBatch { received_at, updates })
+    }
+}
+
+impl<S> fmt::Debug for UpdatePoller<S> {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        out.debug_struct("UpdatePoller")
+            .field("client", &self.client)
+            .field("confirmed_update_id", &self.confirmed_update_id)
+            .field("delivery", &self.delivery)
+            .field("lag_observer", &self.lag_observer.is_some())
+            .field("max_attempts", &self.max_attempts)
+            .field("method", &self.method)
+            .field("poison_handler", &self.poison_handler.is_some())
+            .field("received_update_id", &self.received_update_id)
+            .finish()
+    }
+}
+
+/// Represents a batch of updates received by [`UpdatePoller`].
+#[derive(Clone, Debug)]
+pub struct UpdateBatch {
+    received_at: SystemTime,
+    updates: Vec<Update>,
+}
+
+impl UpdateBatch {
+    /// Returns the time when the batch was received.
+    ///
+    /// Pass it to [`Update::lag`] to measure the lag of an update.
+    pub fn received_at(&self) -> SystemTime {
+        self.received_at
+    }
+
+    /// Returns the updates.
+    pub fn updates(&self) -> &[Update] {
+        &self.updates
+    }
+
+    /// Returns the updates, consuming the batch.
+    pub fn into_updates(self) -> Vec<Update> {
+        self.updates
+    }
+}
+
+impl IntoIterator for UpdateBatch {
+    type Item = Update;
+    type IntoIter = IntoIter<Update>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.updates.into_iter()
+    }
+}
+
+/// Represents an error occurred when polling updates.
+#[derive(Debug, derive_more::From)]
+pub enum PollingError {
+    /// Failed to execute [`GetUpdates`].
+    Execute(ExecuteError),
+    /// Failed to load or save the offset.
+    Storage(IoError),
+}
+
+impl Error for PollingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(match self {
+            Self::Execute(err) => err,
+            Self::Storage(err) => err,
+        })
+    }
+}
+
+impl fmt::Display for PollingError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Execute(err) => write!(out, "failed to get updates: {}", err),
+            Self::Storage(err) => write!(out, "failed to store updates offset: {}", err),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::types::Integer;
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Represents a daily interval when non-urgent messages should not be sent.
+///
+/// The interval is defined in local time of a chat with a fixed UTC offset;
+/// it may cross midnight, e.g. from 22:00 to 08:00.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct QuietHours {
+    end: i64,
+    start: i64,
+    utc_offset: i64,
+}
+
+impl QuietHours {
+    /// Creates a new `QuietHours`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start of the interval since local midnight.
+    /// * `end` - End of the interval since local midnight;
+    ///   an interval with equal start and end is empty.
+    pub fn new(start: Duration, end: Duration) -> Self {
+        Self {
+            end: end.as_secs() as i64 % SECONDS_PER_DAY,
+            start: start.as_secs() as i64 % SECONDS_PER_DAY,
+            utc_offset: 0,
+        }
+    }
+
+    /// Sets a new UTC offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Offset of the local time from UTC in seconds; default - 0.
+    pub fn with_utc_offset(mut self, value: i32) -> Self {
+        self.utc_offset = value.into();
+        self
+    }
+
+    /// Returns a time left until the end of the interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - Current time.
+    ///
+    /// Returns [`None`] if the time is outside of the interval.
+    pub fn get_delay(&self, now: SystemTime) -> Option<Duration> {
+        let unix_time = match now.duration_since(UNIX_EPOCH) {
+            Ok(x) => x.as_secs() as i64,
+            Err(err) => -(err.duration().as_secs() as i64),
+        };
+        let time = (unix_time + self.utc_offset).rem_euclid(SECONDS_PER_DAY);
+        let is_quiet = if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        };
+        if is_quiet {
+            Some(Duration::from_secs(
+                (self.end - time).rem_euclid(SECONDS_PER_DAY) as u64,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Decides whether sending to a chat should be deferred because of quiet hours.
+///
+/// Chats without their own quiet hours use the default ones, if any.
+/// The policy doesn't send anything itself:
+/// wait for the returned delay before executing a non-urgent method.
+#[derive(Clone, Debug, Default)]
+pub struct SendWindowPolicy {
+    chats: HashMap<Integer, QuietHours>,
+    default: Option<QuietHours>,
+}
+
+impl SendWindowPolicy {
+    /// Sets quiet hours for a chat.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the chat.
+    /// * `value` - Quiet hours in local time of the chat.
+    pub fn with_chat(mut self, chat_id: Integer, value: QuietHours) -> Self {
+        self.chats.insert(chat_id, value);
+        self
+    }
+
+    /// Sets default quiet hours.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Quiet hours for chats without their own ones.
+    pub fn with_default(mut self, value: QuietHours) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    /// Returns a time to wait before sending to a chat.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the chat.
+    /// * `is_urgent` - Urgent methods are never deferred.
+    /// * `now` - Current time.
+    ///
+    /// Returns [`None`] if the method can be sent immediately.
+    pub fn get_delay(
+        &self,
+        chat_id: Integer,
+        is_urgent: bool,
+        now: SystemTime,
+    ) -> Option<Duration> {
+        if is_urgent {
+            return None;
+        }
+        self.chats
+            .get(&chat_id)
+            .or(self.default.as_ref())
+            .and_then(|x| x.get_delay(now))
+    }
+}
@@ -0,0 +1,217 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    mem,
+    sync::{Arc, Mutex},
+};
+use tokio::{
+    sync::mpsc::{self, error::TrySendError, Receiver, Sender},
+    task::{JoinHandle, JoinSet},
+};
+use crate::types::{Integer, Update};
+
+const DEFAULT_BUFFER_SIZE: usize = 64;
+
+/// Processes updates of each chat in order while updates of different chats run concurrently.
+///
+/// Updates are keyed by the chat, or by the user when an update has no chat;
+/// updates without both are processed concurrently.
+/// Each key has a queue of a limited size;
+/// [`Self::push`] waits when the queue is full, which slows down the source of updates.
+/// A queue and its task are removed once the queue is empty.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run(updates: Vec<tg::Update>) {
+/// use tg::{SequencedExecutor, Update};
+///
+/// let executor = SequencedExecutor::new(|update: Update| async move {
+///     println!("{:?}", update);
+/// });
+/// for update in updates {
+///     executor.push(update).await;
+/// }
+/// executor.shutdown().await;
+/// # }
+/// ```
+pub struct SequencedExecutor<H> {
+    buffer_size: usize,
+    handler: Arc<H>,
+    state: Arc<Mutex<State>>,
+}
+
+impl<H, F> SequencedExecutor<H>
+where
+    H: Fn(Update) -> F + Send + Sync + 'static,
+    F: Future<Output = ()> + Send + 'static,
+{
+    /// Creates a new `SequencedExecutor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function processing an update.
+    pub fn new(handler: H) -> Self {
+        Self {
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            handler: Arc::new(handler),
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+
+    /// Sets a new size of a queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Maximum number of updates waiting for processing in a chat; default - 64.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is 0.
+    pub fn with_buffer_size(mut self, value: usize) -> Self {
+        assert!(value > 0, "buffer size must be greater than 0");
+        self.buffer_size = value;
+        self
+    }
+
+    /// Adds an update to the queue of its chat.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - The update to process.
+    ///
+    /// Returns once the update is queued; waits while the queue is full.
+    pub async fn push(&self, mut update: Update) {
+        let key = match update
+            .get_chat_id()
+            .map(Integer::from)
+            .or_else(|| update.get_user_id().map(Integer::from))
+        {
+            Some(key) => key,
+            None => {
+                let mut state = self.state.lock().unwrap();
+                while state.unkeyed.try_join_next().is_some() {}
+                state.unkeyed.spawn((self.handler)(update));
+                return;
+            }
+        };
+        loop {
+            let (id, sender) = {
+                let mut state = self.state.lock().unwrap();
+                let queue = match state.queues.get(&key) {
+                    Some(queue) => queue,
+                    None => {
+                        let queue = self.spawn_queue(&mut state, key);
+                        state.queues.entry(key).or_insert(queue)
+                    }
+                };
+                match queue.sender.try_send(update) {
+                    Ok(()) => return,
+                    Err(TrySendError::Full(value)) => update = value,
+                    Err(TrySendError::Closed(value)) => {
+                        update = value;
+                        state.queues.remove(&key);
+                        continue;
+                    }
+                }
+                let queue = state.queues.get_mut(&key).expect("queue exists");
+                queue.pending += 1;
+                (queue.id, queue.sender.clone())
+            };
+            let result = sender.send(update).await;
+            let mut state = self.state.lock().unwrap();
+            if let Some(queue) = state.queues.get_mut(&key).filter(|x| x.id == id) {
+                queue.pending -= 1;
+            }
+            match result {
+                Ok(()) => return,
+                Err(err) => update = err.0,
+            }
+        }
+    }
+
+    /// Waits until all queued updates are processed.
+    ///
+    /// Updates pushed during the shutdown are processed as usual,
+    /// but the method doesn't wait for them.
+    pub async fn shutdown(&self) {
+        let (workers, mut unkeyed) = {
+            let mut state = self.state.lock().unwrap();
+            let workers: Vec<JoinHandle<()>> =
+                state.queues.drain().map(|(_, queue)| queue.worker).collect();
+            (workers, mem::take(&mut state.unkeyed))
+        };
+        for worker in workers {
+            let _ = worker.await;
+        }
+        while unkeyed.join_next().await.is_some() {}
+    }
+
+    fn spawn_queue(&self, state: &mut State, key: Integer) -> Queue {
+        let id = state.next_id;
+        state.next_id += 1;
+        let (sender, receiver) = mpsc::channel(self.buffer_size);
+        let worker = tokio::spawn(run_queue(
+            self.handler.clone(),
+            self.state.clone(),
+            key,
+            id,
+            receiver,
+        ));
+        Queue {
+            id,
+            pending: 0,
+            sender,
+            worker,
+        }
+    }
+}
+
+impl<H> fmt::Debug for SequencedExecutor<H> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SequencedExecutor")
+            .field("buffer_size", &self.buffer_size)
+            .finish()
+    }
+}
+
+#[derive(Default)]
+struct State {
+    next_id: u64,
+    queues: HashMap<Integer, Queue>,
+    unkeyed: JoinSet<()>,
+}
+
+struct Queue {
+    id: u64,
+    pending: usize,
+    sender: Sender<Update>,
+    worker: JoinHandle<()>,
+}
+
+async fn run_queue<H, F>(
+    handler: Arc<H>,
+    state: Arc<Mutex<State>>,
+    key: Integer,
+    id: u64,
+    mut receiver: Receiver<Update>,
+) where
+    H: Fn(Update) -> F + Send + Sync + 'static,
+    F: Future<Output = ()> + Send + 'static,
+{
+    while let Some(update) = receiver.recv().await {
+        // a panic in the handler must not stop the queue
+        let _ = tokio::spawn(handler(update)).await;
+        let mut state = state.lock().unwrap();
+        let is_idle = receiver.is_empty()
+            && state
+                .queues
+                .get(&key)
+                .is_some_and(|x| x.id == id && x.pending == 0);
+        if is_idle {
+            state.queues.remove(&key);
+            return;
+        }
+    }
+}
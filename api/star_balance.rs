@@ -0,0 +1,183 @@
+use std::{ops::ControlFlow, time::Duration};
+use super::client::{Client, ExecuteError};
+use crate::types::{GetBotStarBalance, GetBusinessAccountStarBalance, Integer, StarAmount};
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Periodically checks a Telegram Stars balance and reports when it crosses thresholds.
+///
+/// Use it to alert owners before the balance is not enough for refunds.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run(client: tg::Client) -> Result<(), tg::ExecuteError> {
+/// use std::ops::ControlFlow;
+/// use tg::{StarBalanceEvent, StarBalanceMonitor};
+///
+/// StarBalanceMonitor::new()
+///     .with_threshold(1000)
+///     .with_threshold(100)
+///     .run(
+///         &client,
+///         |event| {
+///             if let StarBalanceEvent::Below { threshold, balance } = event {
+///                 println!("Balance {} is below {}", balance.amount, threshold);
+///             }
+///         },
+///         |err| {
+///             println!("Failed to check balance: {}", err);
+///             ControlFlow::Continue(())
+///         },
+///     )
+///     .await
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct StarBalanceMonitor {
+    business_connection_id: Option<String>,
+    interval: Duration,
+    last_amount: Option<Integer>,
+    thresholds: Vec<Integer>,
+}
+
+impl StarBalanceMonitor {
+    /// Creates a new `StarBalanceMonitor` for the balance of the bot.
+    pub fn new() -> Self {
+        Self {
+            business_connection_id: None,
+            interval: DEFAULT_INTERVAL,
+            last_amount: None,
+            thresholds: Vec::new(),
+        }
+    }
+
+    /// Creates a new `StarBalanceMonitor` for the balance of a managed business account.
+    ///
+    /// # Arguments
+    ///
+    /// * `business_connection_id` - Unique identifier of the business connection.
+    pub fn for_business_account<T>(business_connection_id: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            business_connection_id: Some(business_connection_id.into()),
+            ..Self::new()
+        }
+    }
+
+    /// Sets a new interval between checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Time between requests; default - 5 minutes.
+    pub fn with_interval(mut self, value: Duration) -> Self {
+        self.interval = value;
+        self
+    }
+
+    /// Adds a threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Amount of Telegram Stars.
+    pub fn with_threshold(mut self, value: Integer) -> Self {
+        if !self.thresholds.contains(&value) {
+            self.thresholds.push(value);
+            self.thresholds.sort_unstable();
+        }
+        self
+    }
+
+    /// Requests the balance and returns crossed thresholds.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client to execute requests.
+    ///
+    /// On the first check, a [`StarBalanceEvent::Below`] is returned
+    /// for every threshold above the balance.
+    pub async fn check(&mut self, client: &Client) -> Result<Vec<StarBalanceEvent>, ExecuteError> {
+        let balance = match self.business_connection_id {
+            Some(ref business_connection_id) => {
+                client
+                    .execute(GetBusinessAccountStarBalance::new(
+                        business_connection_id.as_str(),
+                    ))
+                    .await?
+            }
+            None => client.execute(GetBotStarBalance).await?,
+        };
+        let amount = balance.amount;
+        let mut result = Vec::new();
+        for &threshold in self.thresholds.iter().rev() {
+            let was_below = self.last_amount.is_some_and(|x| x < threshold);
+            if amount < threshold && !was_below {
+                result.push(StarBalanceEvent::Below { threshold, balance });
+            }
+        }
+        if let Some(last_amount) = self.last_amount {
+            for &threshold in &self.thresholds {
+                if last_amount < threshold && amount >= threshold {
+                    result.push(StarBalanceEvent::Above { threshold, balance });
+                }
+            }
+        }
+        self.last_amount = Some(amount);
+        Ok(result)
+    }
+
+    /// Checks the balance periodically and passes crossed thresholds to a handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client to execute requests.
+    /// * `handler` - A function called for every event.
+    /// * `error_handler` - A function called for every failed check;
+    ///   return [`ControlFlow::Break`] to stop and get the error.
+    ///
+    /// A failed check is retried after the interval.
+    pub async fn run<F, E>(mut self, client: &Client, mut handler: F, mut error_handler: E) -> Result<(), ExecuteError>
+    where
+        F: FnMut(StarBalanceEvent),
+        E: FnMut(&ExecuteError) -> ControlFlow<()>,
+    {
+        loop {
+            match self.check(client).await {
+                Ok(events) => events.into_iter().for_each(&mut handler),
+                Err(err) => {
+                    if error_handler(&err).is_break() {
+                        return Err(err);
+                    }
+                }
+            }
+            client.clock().sleep(self.interval).await;
+        }
+    }
+}
+
+impl Default for StarBalanceMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Represents a threshold crossed by a Telegram Stars balance.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StarBalanceEvent {
+    /// The balance has risen to the threshold or above.
+    Above {
+        /// The crossed threshold.
+        threshold: Integer,
+        /// The current balance.
+        balance: StarAmount,
+    },
+    /// The balance has dropped below the threshold.
+    Below {
+        /// The crossed threshold.
+        threshold: Integer,
+        /// The current balance.
+        balance: StarAmount,
+    },
+}
@@ -0,0 +1,34 @@
+use super::client::{Client, ExecuteError};
+use crate::types::{GetStarTransactions, Integer, StarLedger};
+
+const PAGE_SIZE: Integer = 100;
+
+impl StarLedger {
+    /// Returns all Telegram Star transactions of the bot.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client to execute requests.
+    ///
+    /// Transactions are requested with [`GetStarTransactions`] page by page
+    /// until an incomplete page is received.
+    pub async fn fetch(client: &Client) -> Result<Self, ExecuteError> {
+        let mut transactions = Vec::new();
+        loop {
+            let page = client
+                .execute(
+                    GetStarTransactions::default()
+                        .with_offset(transactions.len() as Integer)
+                        .with_limit(PAGE_SIZE),
+                )
+                .await?
+                .transactions;
+            let is_last = (page.len() as Integer) < PAGE_SIZE;
+            transactions.extend(page);
+            if is_last {
+                break;
+            }
+        }
+        Ok(Self::from(transactions))
+    }
+}
@@ -0,0 +1,74 @@
+use super::client::{Client, ExecuteError};
+use crate::types::{
+    ChatId, EditMessageResult, EditMessageText, InlineKeyboardMarkup, Integer, Message,
+    SendMessage, Text,
+};
+
+impl Client {
+    /// Edits a text message or sends a new one.
+    ///
+    /// A common pattern for menus: the menu message is edited in place,
+    /// and a new one is sent when the old message is gone.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the target chat.
+    /// * `message_id` - Identifier of the message to edit;
+    ///   a new message is sent if [`None`].
+    /// * `text` - Text of the message; entities are passed when set.
+    /// * `reply_markup` - An inline keyboard of the message.
+    ///
+    /// A new message is sent when the message to edit is not found or can't be edited.
+    /// When the message already has the same content, nothing is sent.
+    pub async fn upsert_message<A, B>(
+        &self,
+        chat_id: A,
+        message_id: Option<Integer>,
+        text: B,
+        reply_markup: Option<InlineKeyboardMarkup>,
+    ) -> Result<UpsertedMessage, ExecuteError>
+    where
+        A: Into<ChatId>,
+        B: Into<Text>,
+    {
+        let chat_id = chat_id.into();
+        let text = text.into();
+        if let Some(message_id) = message_id {
+            let mut method =
+                EditMessageText::for_chat_message(chat_id.clone(), message_id, text.data.as_str());
+            if let Some(ref entities) = text.entities {
+                method = method.with_entities(entities.into_iter().cloned());
+            }
+            if let Some(ref reply_markup) = reply_markup {
+                method = method.with_reply_markup(reply_markup.clone());
+            }
+            match self.execute(method).await {
+                Ok(result) => return Ok(UpsertedMessage::Edited(result)),
+                Err(ExecuteError::Response(err)) if err.is_message_not_modified() => {
+                    return Ok(UpsertedMessage::NotModified)
+                }
+                Err(ExecuteError::Response(err)) if err.is_message_not_editable() => {}
+                Err(err) => return Err(err),
+            }
+        }
+        let mut method = SendMessage::new(chat_id, text.data);
+        if let Some(entities) = text.entities {
+            method = method.with_entities(entities);
+        }
+        if let Some(reply_markup) = reply_markup {
+            method = method.with_reply_markup(reply_markup);
+        }
+        self.execute(method).await.map(UpsertedMessage::Sent)
+    }
+}
+
+/// Represents a result of [`Client::upsert_message`].
+#[derive(Clone, Debug)]
+pub enum UpsertedMessage {
+    /// The message was edited.
+    Edited(EditMessageResult),
+    /// The message already had the same content.
+    NotModified,
+    /// A new message was sent; store its identifier to edit it next time.
+    Sent(Message),
+}
@@ -1,7 +1,7 @@
 use std::{error::Error, fmt};
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{ChatAdministratorRights, ChatId, Integer},
 };
 
@@ -391,7 +391,7 @@ impl Method for Close {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::empty("close")
+        Payload::empty(MethodName::Close)
     }
 }
 
@@ -435,7 +435,7 @@ impl Method for DeleteBotCommands {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("deleteMyCommands", self)
+        Payload::json(MethodName::DeleteMyCommands, self)
     }
 }
 
@@ -447,7 +447,7 @@ impl Method for GetBot {
     type Response = Bot;
 
     fn into_payload(self) -> Payload {
-        Payload::empty("getMe")
+        Payload::empty(MethodName::GetMe)
     }
 }
 
@@ -489,7 +489,7 @@ impl Method for GetBotCommands {
     type Response = Vec<BotCommand>;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getMyCommands", self)
+        Payload::json(MethodName::GetMyCommands, self)
     }
 }
 
@@ -516,7 +516,7 @@ impl Method for GetBotDefaultAdministratorRights {
     type Response = ChatAdministratorRights;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getMyDefaultAdministratorRights", self)
+        Payload::json(MethodName::GetMyDefaultAdministratorRights, self)
     }
 }
 
@@ -546,7 +546,7 @@ impl Method for GetBotDescription {
     type Response = BotDescription;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getMyDescription", self)
+        Payload::json(MethodName::GetMyDescription, self)
     }
 }
 
@@ -576,7 +576,7 @@ impl Method for GetBotName {
     type Response = BotName;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getMyName", self)
+        Payload::json(MethodName::GetMyName, self)
     }
 }
 
@@ -606,7 +606,7 @@ impl Method for GetBotShortDescription {
     type Response = BotShortDescription;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getMyShortDescription", self)
+        Payload::json(MethodName::GetMyShortDescription, self)
     }
 }
 
@@ -624,7 +624,7 @@ impl Method for LogOut {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::empty("logOut")
+        Payload::empty(MethodName::LogOut)
     }
 }
 
@@ -683,7 +683,7 @@ impl Method for SetBotCommands {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setMyCommands", self)
+        Payload::json(MethodName::SetMyCommands, self)
     }
 }
 
@@ -727,7 +727,7 @@ impl Method for SetBotDefaultAdministratorRights {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setMyDefaultAdministratorRights", self)
+        Payload::json(MethodName::SetMyDefaultAdministratorRights, self)
     }
 }
 
@@ -776,7 +776,7 @@ impl Method for SetBotDescription {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setMyDescription", self)
+        Payload::json(MethodName::SetMyDescription, self)
     }
 }
 
@@ -825,7 +825,7 @@ impl Method for SetBotName {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setMyName", self)
+        Payload::json(MethodName::SetMyName, self)
     }
 }
 
@@ -875,6 +875,6 @@ impl Method for SetBotShortDescription {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setMyShortDescription", self)
+        Payload::json(MethodName::SetMyShortDescription, self)
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
-    api::{Method, Payload},
-    types::{Chat, Integer, Location, Sticker, User},
+    api::{Method, MethodName, Payload},
+    types::{Chat, Integer, Location, StarAmount, Sticker, User},
 };
 use serde::{Deserialize, Serialize};
 
@@ -276,6 +276,38 @@ impl Method for GetBusinessConnection {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getBusinessConnection", self)
+        Payload::json(MethodName::GetBusinessConnection, self)
+    }
+}
+
+/// Returns the amount of Telegram Stars owned by a managed business account.
+///
+/// Requires the `can_view_gifts_and_stars` business bot right.
+#[derive(Clone, Debug, Serialize)]
+pub struct GetBusinessAccountStarBalance {
+    business_connection_id: String,
+}
+
+impl GetBusinessAccountStarBalance {
+    /// Creates a new `GetBusinessAccountStarBalance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `business_connection_id` - Unique identifier of the business connection.
+    pub fn new<T>(business_connection_id: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            business_connection_id: business_connection_id.into(),
+        }
+    }
+}
+
+impl Method for GetBusinessAccountStarBalance {
+    type Response = StarAmount;
+
+    fn into_payload(self) -> Payload {
+        Payload::json(MethodName::GetBusinessAccountStarBalance, self)
     }
 }
@@ -2,7 +2,7 @@ use std::{error::Error, fmt};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Error as JsonError;
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{Integer, MaybeInaccessibleMessage, User},
 };
 
@@ -209,6 +209,28 @@ impl AnswerCallbackQuery {
         }
     }
 
+    pub(crate) fn with_callback_query_id<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.callback_query_id = value.into();
+        self
+    }
+
+    /// Sets a new text shown as an alert.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Text of the alert; 0-200 characters.
+    ///
+    /// A shortcut for [`Self::with_text`] and [`Self::with_show_alert`].
+    pub fn with_alert<T>(self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.with_text(value).with_show_alert(true)
+    }
+
     /// Sets a new cache time.
     ///
     /// # Arguments
@@ -274,6 +296,6 @@ impl Method for AnswerCallbackQuery {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("answerCallbackQuery", self)
+        Payload::json(MethodName::AnswerCallbackQuery, self)
     }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{ChatId, Integer},
 };
 
@@ -32,6 +32,30 @@ pub enum ChatAction {
     UploadVoice,
 }
 
+impl ChatAction {
+    /// Returns an action matching a method which sends a message.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - Name of the method.
+    ///
+    /// Returns [`None`] for methods without a matching action,
+    /// e.g. for media groups which may contain different kinds of media.
+    pub fn for_method(method: MethodName) -> Option<Self> {
+        Some(match method {
+            MethodName::SendAnimation | MethodName::SendVideo => Self::UploadVideo,
+            MethodName::SendAudio | MethodName::SendDocument => Self::UploadDocument,
+            MethodName::SendLocation | MethodName::SendVenue => Self::FindLocation,
+            MethodName::SendMessage => Self::Typing,
+            MethodName::SendPhoto => Self::UploadPhoto,
+            MethodName::SendSticker => Self::ChooseSticker,
+            MethodName::SendVideoNote => Self::UploadVideoNote,
+            MethodName::SendVoice => Self::UploadVoice,
+            _ => return None,
+        })
+    }
+}
+
 /// Tells a user that something is happening on a bot side.
 ///
 /// A status is set for 5 seconds or less
@@ -102,6 +126,6 @@ impl Method for SendChatAction {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("sendChatAction", self)
+        Payload::json(MethodName::SendChatAction, self)
     }
 }
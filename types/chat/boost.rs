@@ -1,5 +1,5 @@
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{Chat, ChatId, Integer, User},
 };
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -108,6 +108,18 @@ pub enum ChatBoostSource {
     Premium(User),
 }
 
+impl ChatBoostSource {
+    /// Returns the user associated with the boost source.
+    ///
+    /// For giveaways, the user is available only if the prize was won.
+    pub fn get_user(&self) -> Option<&User> {
+        match self {
+            Self::GiftCode(user) | Self::Premium(user) => Some(user),
+            Self::Giveaway(giveaway) => giveaway.user.as_ref(),
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize)]
 struct ChatBoostSourceUser {
     user: User,
@@ -276,6 +288,6 @@ impl Method for GetUserChatBoosts {
     type Response = UserChatBoosts;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getUserChatBoosts", self)
+        Payload::json(MethodName::GetUserChatBoosts, self)
     }
 }
@@ -1,6 +1,7 @@
 use crate::types::{
-    AccentColor, Birthdate, BusinessIntro, BusinessLocation, BusinessOpeningHours, Chat,
-    ChatLocation, ChatPermissions, ChatPhoto, Integer, Message, ProfileAccentColor, ReactionType,
+    AccentColor, AcceptedGiftTypes, Birthdate, BusinessIntro, BusinessLocation,
+    BusinessOpeningHours, Chat, ChatLocation, ChatPermissions, ChatPhoto, Integer, Message,
+    ProfileAccentColor, ReactionType,
 };
 use serde::{Deserialize, Serialize};
 
@@ -33,6 +34,10 @@ pub struct ChatFullInfo {
     /// The maximum number of reactions that can be set on a message in the chat.
     pub max_reaction_count: Integer,
 
+    /// Types of gifts that are accepted by the chat or by the corresponding user for private chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_gift_types: Option<AcceptedGiftTypes>,
+
     /// List of available reactions allowed in the chat. If omitted, then all emoji reactions are allowed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub available_reactions: Option<Vec<ReactionType>>,
@@ -53,7 +58,7 @@ pub struct ChatFullInfo {
     pub message_auto_delete_time: Option<Integer>,
     /// Chat photo.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub photo: Option<Vec<ChatPhoto>>,
+    pub photo: Option<ChatPhoto>,
     /// The most recent pinned message (by sending date).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pinned_message: Option<Message>,
@@ -173,4 +178,29 @@ pub struct ChatFullInfo {
     /// Default chat member permissions, for groups and supergroups.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub permissions: Option<ChatPermissions>,
+
+    /// The number of Telegram Stars a general user has to pay to send a message to the chat.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_message_star_count: Option<Integer>,
+}
+
+impl ChatFullInfo {
+    /// Returns `true` if the chat accepts any type of gifts.
+    ///
+    /// Returns `false` if the types of accepted gifts are unknown.
+    pub fn accepts_gifts(&self) -> bool {
+        self.accepted_gift_types.is_some_and(|x| x.accepts_any())
+    }
+
+    /// Returns `true` if the chat accepts unique gifts.
+    ///
+    /// Returns `false` if the types of accepted gifts are unknown.
+    pub fn accepts_unique_gifts(&self) -> bool {
+        self.accepted_gift_types.is_some_and(|x| x.unique_gifts)
+    }
+
+    /// Returns `true` if a general user has to pay to send a message to the chat.
+    pub fn requires_paid_messages(&self) -> bool {
+        self.paid_message_star_count.is_some_and(|x| x > 0)
+    }
 }
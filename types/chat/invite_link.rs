@@ -1,10 +1,10 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{ChatId, Integer, User},
 };
 
-
+/// Represents an invite link for a chat.
 #[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize)]
 pub struct ChatInviteLink {
     /// Indicates whether users joining the chat via the link
@@ -35,6 +35,13 @@ pub struct ChatInviteLink {
     /// The number of pending join requests created using this link.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pending_join_request_count: Option<Integer>,
+    /// The number of seconds the subscription will be active for before the next payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_period: Option<Integer>,
+    /// The amount of Telegram Stars a user must pay initially
+    /// and after each subsequent subscription period to be a member of the chat using the link.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_price: Option<Integer>,
 }
 
 impl ChatInviteLink {
@@ -58,6 +65,8 @@ impl ChatInviteLink {
             expire_date: None,
             member_limit: None,
             pending_join_request_count: None,
+            subscription_period: None,
+            subscription_price: None,
         }
     }
 
@@ -136,6 +145,33 @@ impl ChatInviteLink {
         self.pending_join_request_count = Some(value);
         self
     }
+
+    /// Sets a new subscription period.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The number of seconds the subscription will be active for
+    ///   before the next payment.
+    pub fn with_subscription_period(mut self, value: Integer) -> Self {
+        self.subscription_period = Some(value);
+        self
+    }
+
+    /// Sets a new subscription price.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The amount of Telegram Stars a user must pay initially
+    ///   and after each subsequent subscription period.
+    pub fn with_subscription_price(mut self, value: Integer) -> Self {
+        self.subscription_price = Some(value);
+        self
+    }
+
+    /// Returns `true` if the link is a subscription link.
+    pub fn is_subscription(&self) -> bool {
+        self.subscription_period.is_some()
+    }
 }
 
 /// Creates an additional invite link for a chat.
@@ -225,7 +261,7 @@ impl Method for CreateChatInviteLink {
     type Response = ChatInviteLink;
 
     fn into_payload(self) -> Payload {
-        Payload::json("createChatInviteLink", self)
+        Payload::json(MethodName::CreateChatInviteLink, self)
     }
 }
 
@@ -284,7 +320,7 @@ impl Method for CreateChatSubscriptionInviteLink {
     type Response = ChatInviteLink;
 
     fn into_payload(self) -> Payload {
-        Payload::json("createChatSubscriptionInviteLink", self)
+        Payload::json(MethodName::CreateChatSubscriptionInviteLink, self)
     }
 }
 
@@ -380,7 +416,7 @@ impl Method for EditChatInviteLink {
     type Response = ChatInviteLink;
 
     fn into_payload(self) -> Payload {
-        Payload::json("editChatInviteLink", self)
+        Payload::json(MethodName::EditChatInviteLink, self)
     }
 }
 
@@ -432,7 +468,7 @@ impl Method for EditChatSubscriptionInviteLink {
     type Response = ChatInviteLink;
 
     fn into_payload(self) -> Payload {
-        Payload::json("editChatSubscriptionInviteLink", self)
+        Payload::json(MethodName::EditChatSubscriptionInviteLink, self)
     }
 }
 
@@ -476,7 +512,7 @@ impl Method for ExportChatInviteLink {
     type Response = String;
 
     fn into_payload(self) -> Payload {
-        Payload::json("exportChatInviteLink", self)
+        Payload::json(MethodName::ExportChatInviteLink, self)
     }
 }
 
@@ -515,6 +551,6 @@ impl Method for RevokeChatInviteLink {
     type Response = ChatInviteLink;
 
     fn into_payload(self) -> Payload {
-        Payload::json("revokeChatInviteLink", self)
+        Payload::json(MethodName::RevokeChatInviteLink, self)
     }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{Chat, ChatId, ChatInviteLink, Integer, User},
 };
 
@@ -112,7 +112,7 @@ impl Method for ApproveChatJoinRequest {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("approveChatJoinRequest", self)
+        Payload::json(MethodName::ApproveChatJoinRequest, self)
     }
 }
 
@@ -148,6 +148,6 @@ impl Method for DeclineChatJoinRequest {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("declineChatJoinRequest", self)
+        Payload::json(MethodName::DeclineChatJoinRequest, self)
     }
 }
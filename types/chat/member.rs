@@ -1,6 +1,8 @@
 use crate::{
-    api::{Method, Payload},
-    types::{Chat, ChatId, ChatInviteLink, ChatPermissions, Integer, User},
+    api::{Method, MethodName, Payload},
+    types::{
+        Chat, ChatAdministratorRights, ChatId, ChatInviteLink, ChatPermissions, Integer, User,
+    },
 };
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -54,6 +56,220 @@ impl ChatMember {
             Restricted(ref restricted) => restricted.is_member,
         }
     }
+
+    /// Checks if a user is the creator or an administrator of the chat.
+    pub fn is_admin(&self) -> bool {
+        matches!(self, ChatMember::Administrator(_) | ChatMember::Creator(_))
+    }
+
+    /// Checks if a user is the creator or an administrator with the given right.
+    ///
+    /// # Arguments
+    ///
+    /// * `permission` - The right to check; send permissions are always granted to admins.
+    pub fn is_admin_with(&self, permission: ChatMemberPermission) -> bool {
+        self.is_admin() && self.has_permission(permission)
+    }
+
+    /// Checks if a user can post messages in the channel.
+    pub fn can_post(&self) -> bool {
+        self.is_admin_with(ChatMemberPermission::PostMessages)
+    }
+
+    /// Returns the date when restrictions will be lifted for a restricted user.
+    ///
+    /// Zero means that the user is restricted forever.
+    pub fn is_restricted_until(&self) -> Option<Integer> {
+        match self {
+            ChatMember::Restricted(ref restricted) => Some(restricted.until_date),
+            _ => None,
+        }
+    }
+
+    /// Checks if a user has a permission.
+    ///
+    /// # Arguments
+    ///
+    /// * `permission` - The permission to check.
+    ///
+    /// Permissions of regular members depend on default permissions of the chat,
+    /// which are not known here, so send permissions are considered granted and other ones are not;
+    /// use [`Self::has_permission_in`] when default permissions are available.
+    pub fn has_permission(&self, permission: ChatMemberPermission) -> bool {
+        use self::ChatMemberPermission::*;
+        match self {
+            ChatMember::Creator(_) => true,
+            ChatMember::Administrator(ref x) => match permission {
+                ChangeInfo => x.can_change_info,
+                DeleteMessages => x.can_delete_messages,
+                DeleteStories => x.can_delete_stories.unwrap_or(false),
+                EditMessages => x.can_edit_messages.unwrap_or(false),
+                EditStories => x.can_edit_stories.unwrap_or(false),
+                InviteUsers => x.can_invite_users,
+                ManageChat => x.can_manage_chat,
+                ManageTopics => x.can_manage_topics.unwrap_or(false),
+                ManageVideoChats => x.can_manage_video_chats,
+                PinMessages => x.can_pin_messages.unwrap_or(false),
+                PostMessages => x.can_post_messages.unwrap_or(false),
+                PostStories => x.can_post_stories.unwrap_or(false),
+                PromoteMembers => x.can_promote_members,
+                RestrictMembers => x.can_restrict_members,
+                _ => true,
+            },
+            ChatMember::Member { .. } => permission.is_send_permission(),
+            ChatMember::Restricted(ref x) => match permission {
+                AddWebPagePreviews => x.can_add_web_page_previews,
+                ChangeInfo => x.can_change_info,
+                InviteUsers => x.can_invite_users,
+                ManageTopics => x.can_manage_topics,
+                PinMessages => x.can_pin_messages.unwrap_or(false),
+                SendAudios => x.can_send_audios.unwrap_or(false),
+                SendDocuments => x.can_send_documents.unwrap_or(false),
+                SendMessages => x.can_send_messages,
+                SendOtherMessages => x.can_send_other_messages,
+                SendPhotos => x.can_send_photos.unwrap_or(false),
+                SendPolls => x.can_send_polls,
+                SendVideoNotes => x.can_send_video_notes.unwrap_or(false),
+                SendVideos => x.can_send_videos.unwrap_or(false),
+                SendVoiceNotes => x.can_send_voice_notes.unwrap_or(false),
+                _ => false,
+            },
+            ChatMember::Kicked(_) | ChatMember::Left(_) => false,
+        }
+    }
+
+    /// Checks if a user has a permission, taking default permissions of the chat into account.
+    ///
+    /// # Arguments
+    ///
+    /// * `permission` - The permission to check.
+    /// * `chat_permissions` - Default permissions of the chat,
+    ///   see [`crate::types::ChatFullInfo::permissions`].
+    ///
+    /// Regular members get permissions from the defaults,
+    /// e.g. [`ChatMemberPermission::InviteUsers`] or [`ChatMemberPermission::PinMessages`];
+    /// other members are checked as in [`Self::has_permission`].
+    pub fn has_permission_in(&self, permission: ChatMemberPermission, chat_permissions: &ChatPermissions) -> bool {
+        match self {
+            ChatMember::Member { .. } => chat_permissions
+                .get(permission)
+                .unwrap_or_else(|| permission.is_send_permission()),
+            _ => self.has_permission(permission),
+        }
+    }
+}
+
+/// Represents a permission of a chat member.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ChatMemberPermission {
+    /// Add web page previews to messages.
+    AddWebPagePreviews,
+    /// Change the chat title, photo and other settings.
+    ChangeInfo,
+    /// Delete messages of other users.
+    DeleteMessages,
+    /// Delete stories posted by other users.
+    DeleteStories,
+    /// Edit messages of other users.
+    EditMessages,
+    /// Edit stories posted by other users.
+    EditStories,
+    /// Invite new users to the chat.
+    InviteUsers,
+    /// Access the chat event log, statistics and other administrative data.
+    ManageChat,
+    /// Create, rename, close, and reopen forum topics.
+    ManageTopics,
+    /// Manage video chats.
+    ManageVideoChats,
+    /// Pin messages.
+    PinMessages,
+    /// Post messages in the channel.
+    PostMessages,
+    /// Post stories in the channel.
+    PostStories,
+    /// Add new administrators.
+    PromoteMembers,
+    /// Restrict, ban or unban chat members.
+    RestrictMembers,
+    /// Send audios.
+    SendAudios,
+    /// Send documents.
+    SendDocuments,
+    /// Send text messages, contacts, locations and venues.
+    SendMessages,
+    /// Send animations, games, stickers and use inline bots.
+    SendOtherMessages,
+    /// Send photos.
+    SendPhotos,
+    /// Send polls.
+    SendPolls,
+    /// Send video notes.
+    SendVideoNotes,
+    /// Send videos.
+    SendVideos,
+    /// Send voice notes.
+    SendVoiceNotes,
+}
+
+impl ChatMemberPermission {
+    /// All permissions.
+    pub const ALL: [ChatMemberPermission; 24] = {
+        use self::ChatMemberPermission::*;
+        [
+            AddWebPagePreviews,
+            ChangeInfo,
+            DeleteMessages,
+            DeleteStories,
+            EditMessages,
+            EditStories,
+            InviteUsers,
+            ManageChat,
+            ManageTopics,
+            ManageVideoChats,
+            PinMessages,
+            PostMessages,
+            PostStories,
+            PromoteMembers,
+            RestrictMembers,
+            SendAudios,
+            SendDocuments,
+            SendMessages,
+            SendOtherMessages,
+            SendPhotos,
+            SendPolls,
+            SendVideoNotes,
+            SendVideos,
+            SendVoiceNotes,
+        ]
+    };
+
+    /// Checks if the permission allows to send some kind of messages.
+    pub fn is_send_permission(&self) -> bool {
+        use self::ChatMemberPermission::*;
+        matches!(
+            self,
+            AddWebPagePreviews
+                | SendAudios
+                | SendDocuments
+                | SendMessages
+                | SendOtherMessages
+                | SendPhotos
+                | SendPolls
+                | SendVideoNotes
+                | SendVideos
+                | SendVoiceNotes
+        )
+    }
+}
+
+/// Represents a permission toggled in a [`ChatMemberUpdated`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ChatMemberPermissionChange {
+    /// The permission.
+    pub permission: ChatMemberPermission,
+    /// Indicates whether the permission was granted or revoked.
+    pub granted: bool,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -739,6 +955,92 @@ impl ChatMemberUpdated {
         self.via_join_request = Some(value);
         self
     }
+
+    /// Returns permissions which were granted or revoked.
+    ///
+    /// See [`ChatMember::has_permission`] for details on how permissions are determined.
+    pub fn changes(&self) -> Vec<ChatMemberPermissionChange> {
+        ChatMemberPermission::ALL
+            .into_iter()
+            .filter_map(|permission| {
+                let granted = self.new_chat_member.has_permission(permission);
+                if self.old_chat_member.has_permission(permission) != granted {
+                    Some(ChatMemberPermissionChange { permission, granted })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Returns effects of the change on the chat member.
+    ///
+    /// Useful for `my_chat_member` updates to find out how rights of the bot were changed.
+    /// Permission changes are reported only when the user remains a member of the chat,
+    /// see [`Self::changes`].
+    pub fn effects(&self) -> Vec<ChatMemberEffect> {
+        let (old, new) = (&self.old_chat_member, &self.new_chat_member);
+        let mut result = Vec::new();
+        match (old.is_member(), new.is_member()) {
+            (false, true) => result.push(ChatMemberEffect::Joined),
+            (true, false) => result.push(match new {
+                ChatMember::Kicked(_) => ChatMemberEffect::Banned,
+                _ => ChatMemberEffect::Left,
+            }),
+            (false, false) => {
+                if let (ChatMember::Kicked(_), ChatMember::Left(_)) = (old, new) {
+                    result.push(ChatMemberEffect::Unbanned);
+                }
+            }
+            (true, true) => {}
+        }
+        match (old.is_admin(), new.is_admin()) {
+            (false, true) => result.push(ChatMemberEffect::Promoted),
+            (true, false) => result.push(ChatMemberEffect::Demoted),
+            _ => {}
+        }
+        match (old, new) {
+            (ChatMember::Restricted(_), ChatMember::Restricted(_)) => {}
+            (_, ChatMember::Restricted(_)) => result.push(ChatMemberEffect::Restricted),
+            (ChatMember::Restricted(_), _) => result.push(ChatMemberEffect::Unrestricted),
+            _ => {}
+        }
+        if old.is_member() && new.is_member() {
+            result.extend(self.changes().into_iter().map(|x| {
+                if x.granted {
+                    ChatMemberEffect::PermissionGranted(x.permission)
+                } else {
+                    ChatMemberEffect::PermissionRevoked(x.permission)
+                }
+            }));
+        }
+        result
+    }
+}
+
+/// Represents an effect of a [`ChatMemberUpdated`] on the chat member.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ChatMemberEffect {
+    /// The user was banned.
+    Banned,
+    /// The user was demoted from administrators.
+    Demoted,
+    /// The user joined the chat or was added to it.
+    Joined,
+    /// The user left the chat.
+    Left,
+    /// The permission was granted.
+    PermissionGranted(ChatMemberPermission),
+    /// The permission was revoked.
+    PermissionRevoked(ChatMemberPermission),
+    /// The user was promoted to administrators.
+    Promoted,
+    /// The user was restricted.
+    Restricted,
+    /// The user was unbanned.
+    Unbanned,
+    /// Restrictions were lifted for the user.
+    Unrestricted,
 }
 
 /// Bans a user in a chat.
@@ -809,7 +1111,7 @@ impl Method for BanChatMember {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("banChatMember", self)
+        Payload::json(MethodName::BanChatMember, self)
     }
 }
 
@@ -845,7 +1147,7 @@ impl Method for GetChatAdministrators {
     type Response = Vec<ChatMember>;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getChatAdministrators", self)
+        Payload::json(MethodName::GetChatAdministrators, self)
     }
 }
 
@@ -878,7 +1180,7 @@ impl Method for GetChatMember {
     type Response = ChatMember;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getChatMember", self)
+        Payload::json(MethodName::GetChatMember, self)
     }
 }
 
@@ -908,7 +1210,7 @@ impl Method for GetChatMemberCount {
     type Response = Integer;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getChatMemberCount", self)
+        Payload::json(MethodName::GetChatMemberCount, self)
     }
 }
 
@@ -1024,6 +1326,34 @@ impl PromoteChatMember {
         self
     }
 
+    /// Sets all privileges from administrator rights.
+    ///
+    /// Optional flags which are not set in the rights are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Rights of the administrator;
+    ///   the same struct is accepted by
+    ///   [`SetBotDefaultAdministratorRights`](crate::types::SetBotDefaultAdministratorRights).
+    pub fn with_rights(mut self, value: ChatAdministratorRights) -> Self {
+        self.is_anonymous = Some(value.is_anonymous);
+        self.can_change_info = Some(value.can_change_info);
+        self.can_delete_messages = Some(value.can_delete_messages);
+        self.can_delete_stories = value.can_delete_stories.or(self.can_delete_stories);
+        self.can_edit_messages = value.can_edit_messages.or(self.can_edit_messages);
+        self.can_edit_stories = value.can_edit_stories.or(self.can_edit_stories);
+        self.can_invite_users = Some(value.can_invite_users);
+        self.can_manage_chat = Some(value.can_manage_chat);
+        self.can_manage_topics = value.can_manage_topics.or(self.can_manage_topics);
+        self.can_manage_video_chats = Some(value.can_manage_video_chats);
+        self.can_pin_messages = value.can_pin_messages.or(self.can_pin_messages);
+        self.can_post_messages = value.can_post_messages.or(self.can_post_messages);
+        self.can_post_stories = value.can_post_stories.or(self.can_post_stories);
+        self.can_promote_members = Some(value.can_promote_members);
+        self.can_restrict_members = Some(value.can_restrict_members);
+        self
+    }
+
     /// Sets a new value for a `can_change_info` flag.
     ///
     /// # Arguments
@@ -1194,7 +1524,7 @@ impl Method for PromoteChatMember {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("promoteChatMember", self)
+        Payload::json(MethodName::PromoteChatMember, self)
     }
 }
 
@@ -1289,7 +1619,7 @@ impl Method for RestrictChatMember {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("restrictChatMember", self)
+        Payload::json(MethodName::RestrictChatMember, self)
     }
 }
 
@@ -1327,7 +1657,7 @@ impl Method for SetChatAdministratorCustomTitle {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setChatAdministratorCustomTitle", self)
+        Payload::json(MethodName::SetChatAdministratorCustomTitle, self)
     }
 }
 
@@ -1378,6 +1708,6 @@ impl Method for UnbanChatMember {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("unbanChatMember", self)
+        Payload::json(MethodName::UnbanChatMember, self)
     }
 }
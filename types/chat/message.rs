@@ -1,5 +1,5 @@
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{ChatId, Integer},
 };
 use serde::Serialize;
@@ -68,7 +68,7 @@ impl Method for PinChatMessage {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("pinChatMessage", self)
+        Payload::json(MethodName::PinChatMessage, self)
     }
 }
 
@@ -134,7 +134,7 @@ impl Method for UnpinChatMessage {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("unpinChatMessage", self)
+        Payload::json(MethodName::UnpinChatMessage, self)
     }
 }
 
@@ -168,6 +168,6 @@ impl Method for UnpinAllChatMessages {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("unpinAllChatMessages", self)
+        Payload::json(MethodName::UnpinAllChatMessages, self)
     }
 }
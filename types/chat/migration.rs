@@ -0,0 +1,70 @@
+use crate::types::{Integer, Message, MessageData, Update};
+
+/// Represents a migration of a group to a supergroup.
+///
+/// Telegram sends two service messages for a migration:
+/// one to the group with the new identifier, and one to the supergroup with the old identifier.
+/// Both of them are converted to the same value, so records stored by the identifier
+/// of the group can be moved to the supergroup by either message.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ChatMigration {
+    /// Unique identifier of the group.
+    pub from_chat_id: Integer,
+    /// Unique identifier of the supergroup.
+    pub to_chat_id: Integer,
+}
+
+impl ChatMigration {
+    /// Creates a new `ChatMigration`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_chat_id` - Unique identifier of the group.
+    /// * `to_chat_id` - Unique identifier of the supergroup.
+    pub fn new(from_chat_id: Integer, to_chat_id: Integer) -> Self {
+        Self {
+            from_chat_id,
+            to_chat_id,
+        }
+    }
+
+    /// Returns an identifier of a chat after the migration.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of a chat;
+    ///   the identifier of the group is replaced with the identifier of the supergroup,
+    ///   other identifiers are returned as is.
+    pub fn rekey(&self, chat_id: Integer) -> Integer {
+        if chat_id == self.from_chat_id {
+            self.to_chat_id
+        } else {
+            chat_id
+        }
+    }
+}
+
+impl Message {
+    /// Returns a migration of a group to a supergroup for service messages.
+    pub fn get_chat_migration(&self) -> Option<ChatMigration> {
+        let chat_id = self.chat.get_id().into();
+        match self.data {
+            MessageData::MigrateToChatId(to_chat_id) => {
+                Some(ChatMigration::new(chat_id, to_chat_id))
+            }
+            MessageData::MigrateFromChatId(from_chat_id) => {
+                Some(ChatMigration::new(from_chat_id, chat_id))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Update {
+    /// Returns a migration of a group to a supergroup.
+    ///
+    /// See [`Message::get_chat_migration`] for details.
+    pub fn get_chat_migration(&self) -> Option<ChatMigration> {
+        self.get_message().and_then(Message::get_chat_migration)
+    }
+}
@@ -1,9 +1,9 @@
 pub use self::{
     action::*, boost::*, full_info::*, id::*, invite_link::*, join_request::*, location::*,
-    member::*, message::*, permissions::*, photo::*, sender_chat::*, sticker_set::*,
+    member::*, message::*, migration::*, permissions::*, photo::*, sender_chat::*, sticker_set::*,
 };
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::BackgroundType,
 };
 use serde::{Deserialize, Serialize};
@@ -17,6 +17,7 @@ mod join_request;
 mod location;
 mod member;
 mod message;
+mod migration;
 mod permissions;
 mod photo;
 mod sender_chat;
@@ -295,7 +296,7 @@ impl Method for GetChat {
     type Response = ChatFullInfo;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getChat", self)
+        Payload::json(MethodName::GetChat, self)
     }
 }
 
@@ -325,7 +326,7 @@ impl Method for LeaveChat {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("leaveChat", self)
+        Payload::json(MethodName::LeaveChat, self)
     }
 }
 
@@ -374,7 +375,7 @@ impl Method for SetChatDescription {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setChatDescription", self)
+        Payload::json(MethodName::SetChatDescription, self)
     }
 }
 
@@ -417,6 +418,6 @@ impl Method for SetChatTitle {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setChatTitle", self)
+        Payload::json(MethodName::SetChatTitle, self)
     }
 }
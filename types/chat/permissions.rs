@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
-    types::ChatId,
+    api::{Method, MethodName, Payload},
+    types::{ChatId, ChatMemberPermission},
 };
 
 /// Represents the rights of an administrator in a chat.
@@ -357,6 +357,75 @@ impl ChatPermissions {
         }
     }
 
+    /// Forbid sending anything.
+    ///
+    /// Every granular `can_send_*` permission is set explicitly,
+    /// so the preset doesn't depend on the `use_independent_chat_permissions` flag;
+    /// other permissions are not changed.
+    pub fn muted() -> Self {
+        Self {
+            can_add_web_page_previews: Some(false),
+            can_send_audios: Some(false),
+            can_send_documents: Some(false),
+            can_send_messages: Some(false),
+            can_send_other_messages: Some(false),
+            can_send_photos: Some(false),
+            can_send_polls: Some(false),
+            can_send_video_notes: Some(false),
+            can_send_videos: Some(false),
+            can_send_voice_notes: Some(false),
+            ..Self::default()
+        }
+    }
+
+    /// Allow reading the chat and inviting new users only.
+    pub fn read_only() -> Self {
+        Self {
+            can_invite_users: Some(true),
+            ..Self::restricted()
+        }
+    }
+
+    /// Allow sending everything and inviting new users,
+    /// but forbid changing the chat, pinning messages and managing topics.
+    pub fn default_group() -> Self {
+        Self {
+            can_change_info: Some(false),
+            can_manage_topics: Some(false),
+            can_pin_messages: Some(false),
+            ..Self::allowed()
+        }
+    }
+
+    /// Returns the value of a permission.
+    ///
+    /// # Arguments
+    ///
+    /// * `permission` - The permission to get.
+    ///
+    /// Returns [`None`] if the permission is not set
+    /// or can be granted only to administrators.
+    pub fn get(&self, permission: ChatMemberPermission) -> Option<bool> {
+        use self::ChatMemberPermission::*;
+        match permission {
+            AddWebPagePreviews => self.can_add_web_page_previews,
+            ChangeInfo => self.can_change_info,
+            InviteUsers => self.can_invite_users,
+            ManageTopics => self.can_manage_topics.or(self.can_pin_messages),
+            PinMessages => self.can_pin_messages,
+            SendAudios => self.can_send_audios,
+            SendDocuments => self.can_send_documents,
+            SendMessages => self.can_send_messages,
+            SendOtherMessages => self.can_send_other_messages,
+            SendPhotos => self.can_send_photos,
+            SendPolls => self.can_send_polls,
+            SendVideoNotes => self.can_send_video_notes,
+            SendVideos => self.can_send_videos,
+            SendVoiceNotes => self.can_send_voice_notes,
+            _ => None,
+        }
+    }
+
     /// Sets a new value for a `can_add_web_page_previews` flag.
     ///
     /// # Arguments
@@ -548,6 +617,6 @@ impl Method for SetChatPermissions {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setChatPermissions", self)
+        Payload::json(MethodName::SetChatPermissions, self)
     }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, FormValue, Method, MethodName, Payload},
     types::{ChatId, InputFile},
 };
 
@@ -93,7 +93,7 @@ impl Method for DeleteChatPhoto {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("deleteChatPhoto", self)
+        Payload::json(MethodName::DeleteChatPhoto, self)
     }
 }
 
@@ -128,7 +128,7 @@ impl SetChatPhoto {
         Self {
             form: Form::from([
                 ("chat_id", chat_id.into().into()),
-                ("photo", photo.into().into()),
+                ("photo", FormValue::from(photo.into()).into_photo()),
             ]),
         }
     }
@@ -138,6 +138,6 @@ impl Method for SetChatPhoto {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::form("setChatPhoto", self.form)
+        Payload::form(MethodName::SetChatPhoto, self.form)
     }
 }
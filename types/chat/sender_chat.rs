@@ -1,5 +1,5 @@
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{ChatId, Integer},
 };
 use serde::Serialize;
@@ -37,7 +37,7 @@ impl Method for BanChatSenderChat {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("banChatSenderChat", self)
+        Payload::json(MethodName::BanChatSenderChat, self)
     }
 }
 
@@ -73,6 +73,6 @@ impl Method for UnbanChatSenderChat {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("unbanChatSenderChat", self)
+        Payload::json(MethodName::UnbanChatSenderChat, self)
     }
 }
@@ -1,6 +1,6 @@
 use serde::Serialize;
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::ChatId,
 };
 
@@ -35,7 +35,7 @@ impl Method for DeleteChatStickerSet {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("deleteChatStickerSet", self)
+        Payload::json(MethodName::DeleteChatStickerSet, self)
     }
 }
 
@@ -75,6 +75,6 @@ impl Method for SetChatStickerSet {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setChatStickerSet", self)
+        Payload::json(MethodName::SetChatStickerSet, self)
     }
 }
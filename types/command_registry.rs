@@ -0,0 +1,78 @@
+/// Declares commands of a bot.
+///
+/// Generates a type with a constant for every command name,
+/// which can be compared with [`crate::types::Command::get_name`],
+/// and a list of [`crate::types::BotCommand`] for [`crate::types::SetBotCommands`],
+/// so names used by handlers and names shown to users are kept in sync.
+///
+/// Names are declared with leading slash.
+///
+/// # Example
+///
+/// ```
+/// use tg::{commands, Command};
+///
+/// commands! {
+///     /// Commands of the bot.
+///     pub BotCommands {
+///         START = "/start" => "Start the bot",
+///         HELP = "/help" => "Show help",
+///     }
+/// }
+///
+/// fn handle_command(command: &Command) {
+///     match command.get_name() {
+///         BotCommands::START => println!("START"),
+///         BotCommands::HELP => println!("HELP"),
+///         _ => {}
+///     }
+/// }
+///
+/// let method = BotCommands::set_bot_commands().unwrap();
+/// assert!(BotCommands::contains("/help"));
+/// ```
+#[macro_export]
+macro_rules! commands {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident {
+            $(
+                $(#[$item_meta:meta])*
+                $item_name:ident = $command:literal => $description:literal
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug)]
+        $vis struct $name;
+
+        impl $name {
+            $(
+                $(#[$item_meta])*
+                pub const $item_name: &'static str = $command;
+            )*
+
+            /// Names of all commands with leading slash.
+            pub const ALL: &'static [&'static str] = &[$($command),*];
+
+            /// Returns `true` if a name with leading slash belongs to one of the commands.
+            pub fn contains(name: &str) -> bool {
+                Self::ALL.contains(&name)
+            }
+
+            /// Returns the list of commands.
+            pub fn bot_commands(
+            ) -> ::std::result::Result<::std::vec::Vec<$crate::BotCommand>, $crate::BotCommandError> {
+                ::std::result::Result::Ok(::std::vec![
+                    $($crate::BotCommand::new($command.trim_start_matches('/'), $description)?),*
+                ])
+            }
+
+            /// Returns a method which sets the list of commands.
+            pub fn set_bot_commands(
+            ) -> ::std::result::Result<$crate::SetBotCommands, $crate::BotCommandError> {
+                Self::bot_commands().map($crate::SetBotCommands::new)
+            }
+        }
+    };
+}
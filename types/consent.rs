@@ -0,0 +1,119 @@
+use crate::types::{
+    AnswerCallbackQuery, CallbackQuery, ChatId, InlineKeyboardButton, InlineKeyboardMarkup,
+    Integer, SendMessage,
+};
+
+const CALLBACK_DATA_PREFIX: &str = "consent:";
+const DEFAULT_BUTTON_TEXT: &str = "Accept";
+
+/// Requires users to accept terms before other handlers run.
+///
+/// The gate doesn't store acceptances, keep the accepted version per user
+/// in a storage of your choice and pass it to [`Self::is_accepted`].
+/// When the terms change, increase the version, so users are prompted again.
+///
+/// Typical flow:
+///
+/// 1. Call [`Self::get_accepted_version`] for every callback query;
+///    store the returned version and answer the query with [`Self::confirm`].
+/// 2. Otherwise, call [`Self::is_accepted`] with the stored version;
+///    send [`Self::prompt`] and skip other handlers if the terms are not accepted.
+#[derive(Clone, Debug)]
+pub struct ConsentGate {
+    button_text: String,
+    text: String,
+    version: Integer,
+}
+
+impl ConsentGate {
+    /// Creates a new `ConsentGate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - Current version of the terms.
+    /// * `text` - Text of the terms or a link to them.
+    pub fn new<T>(version: Integer, text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            button_text: String::from(DEFAULT_BUTTON_TEXT),
+            text: text.into(),
+            version,
+        }
+    }
+
+    /// Sets a new text of the accept button.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Text of the button; default - “Accept”.
+    pub fn with_button_text<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.button_text = value.into();
+        self
+    }
+
+    /// Returns the current version of the terms.
+    pub fn version(&self) -> Integer {
+        self.version
+    }
+
+    /// Checks if the current version of the terms is accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `accepted_version` - A version accepted by the user, if any.
+    pub fn is_accepted(&self, accepted_version: Option<Integer>) -> bool {
+        accepted_version.is_some_and(|x| x >= self.version)
+    }
+
+    /// Returns a message asking to accept the terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the target chat.
+    pub fn prompt<T>(&self, chat_id: T) -> SendMessage
+    where
+        T: Into<ChatId>,
+    {
+        SendMessage::new(chat_id, self.text.as_str()).with_reply_markup(InlineKeyboardMarkup::from(
+            [[InlineKeyboardButton::for_callback_data(
+                self.button_text.as_str(),
+                format!("{}{}", CALLBACK_DATA_PREFIX, self.version),
+            )]],
+        ))
+    }
+
+    /// Returns a version accepted with the callback query.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - An incoming callback query.
+    ///
+    /// Returns [`None`] if the query is not an acceptance
+    /// or it accepts an outdated version of the terms.
+    pub fn get_accepted_version(&self, query: &CallbackQuery) -> Option<Integer> {
+        query
+            .data
+            .as_deref()
+            .and_then(|x| x.strip_prefix(CALLBACK_DATA_PREFIX))
+            .and_then(|x| x.parse().ok())
+            .filter(|x| *x == self.version)
+    }
+
+    /// Returns an answer to an acceptance callback query.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The acceptance callback query.
+    /// * `text` - A notification shown to the user.
+    pub fn confirm<T>(&self, query: &CallbackQuery, text: T) -> AnswerCallbackQuery
+    where
+        T: Into<String>,
+    {
+        AnswerCallbackQuery::new(query.id.as_str()).with_text(text)
+    }
+}
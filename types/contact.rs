@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{ChatId, Integer, Message, ReplyMarkup, ReplyParameters},
 };
 
@@ -249,6 +249,6 @@ impl Method for SendContact {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::json("sendContact", self)
+        Payload::json(MethodName::SendContact, self)
     }
 }
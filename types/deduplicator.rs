@@ -0,0 +1,161 @@
+use crate::types::{Integer, Update};
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+const DEFAULT_WINDOW_SIZE: usize = 1000;
+
+/// Persists identifiers remembered by [`UpdateDeduplicator`] across restarts.
+pub trait UpdateIdStore: Send + Sync {
+    /// Returns remembered identifiers from the oldest to the newest.
+    fn load(&self) -> Vec<Integer>;
+
+    /// Remembers an identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `update_id` - Identifier of an accepted update.
+    fn insert(&self, update_id: Integer);
+
+    /// Forgets an identifier which left the window.
+    ///
+    /// # Arguments
+    ///
+    /// * `update_id` - Identifier of an old update.
+    fn remove(&self, update_id: Integer);
+}
+
+/// Drops updates which were already received.
+///
+/// Webhook requests are retried when the bot doesn't respond in time,
+/// and [`crate::types::GetUpdates`] returns unconfirmed updates again after a restart.
+/// The deduplicator remembers identifiers of the last accepted updates
+/// and rejects updates with the same identifiers.
+///
+/// Identifiers are not compared by order,
+/// since the Bot API may choose the next identifier randomly after a week without updates.
+///
+/// Clones share the remembered identifiers.
+#[derive(Clone)]
+pub struct UpdateDeduplicator {
+    state: Arc<Mutex<DeduplicatorState>>,
+    store: Option<Arc<dyn UpdateIdStore>>,
+    window_size: usize,
+}
+
+impl UpdateDeduplicator {
+    /// Creates a new `UpdateDeduplicator`.
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(DeduplicatorState::default())),
+            store: None,
+            window_size: DEFAULT_WINDOW_SIZE,
+        }
+    }
+
+    /// Sets a new size of the window.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Number of the last identifiers to remember; default - 1000.
+    pub fn with_window_size(mut self, value: usize) -> Self {
+        self.window_size = value;
+        self.trim(&mut self.state.lock().unwrap());
+        self
+    }
+
+    /// Sets a new store and loads identifiers from it.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Store of remembered identifiers.
+    pub fn with_store<T>(mut self, value: T) -> Self
+    where
+        T: UpdateIdStore + 'static,
+    {
+        let mut state = self.state.lock().unwrap();
+        for update_id in value.load() {
+            state.push(update_id);
+        }
+        drop(state);
+        self.store = Some(Arc::new(value));
+        self.trim(&mut self.state.lock().unwrap());
+        self
+    }
+
+    /// Returns `true` if the update was not received before and remembers it.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - An incoming update.
+    pub fn accept(&self, update: &Update) -> bool {
+        self.accept_id(update.id)
+    }
+
+    /// Returns `true` if the identifier was not received before and remembers it.
+    ///
+    /// # Arguments
+    ///
+    /// * `update_id` - Identifier of an incoming update.
+    pub fn accept_id(&self, update_id: Integer) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if self.window_size == 0 {
+            return true;
+        }
+        if !state.push(update_id) {
+            return false;
+        }
+        if let Some(ref store) = self.store {
+            store.insert(update_id);
+        }
+        self.trim(&mut state);
+        true
+    }
+
+    fn trim(&self, state: &mut DeduplicatorState) {
+        while state.ids.len() > self.window_size {
+            if let (Some(update_id), Some(store)) = (state.pop(), &self.store) {
+                store.remove(update_id);
+            }
+        }
+    }
+}
+
+impl Default for UpdateDeduplicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for UpdateDeduplicator {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        out.debug_struct("UpdateDeduplicator")
+            .field("store", &self.store.is_some())
+            .field("window_size", &self.window_size)
+            .finish()
+    }
+}
+
+#[derive(Debug, Default)]
+struct DeduplicatorState {
+    ids: HashSet<Integer>,
+    order: VecDeque<Integer>,
+}
+
+impl DeduplicatorState {
+    fn push(&mut self, update_id: Integer) -> bool {
+        let is_new = self.ids.insert(update_id);
+        if is_new {
+            self.order.push_back(update_id);
+        }
+        is_new
+    }
+
+    fn pop(&mut self) -> Option<Integer> {
+        let update_id = self.order.pop_front()?;
+        self.ids.remove(&update_id);
+        Some(update_id)
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt;
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{ChatId, Integer, Message, ReplyMarkup, ReplyParameters},
 };
 
@@ -217,6 +217,6 @@ impl Method for SendDice {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::json("sendDice", self)
+        Payload::json(MethodName::SendDice, self)
     }
 }
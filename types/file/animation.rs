@@ -1,5 +1,5 @@
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, Method, MethodName, Payload},
     types::{
         ChatId, InputFile, Integer, Message, ParseMode, PhotoSize, ReplyMarkup, ReplyMarkupError,
         ReplyParameters, ReplyParametersError, TextEntities, TextEntity, TextEntityError,
@@ -361,7 +361,7 @@ impl Method for SendAnimation {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::form("sendAnimation", self.form)
+        Payload::form(MethodName::SendAnimation, self.form)
     }
 }
 
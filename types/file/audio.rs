@@ -1,5 +1,5 @@
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, Method, MethodName, Payload},
     types::{
         ChatId, InputFile, Integer, Message, ParseMode, PhotoSize, ReplyMarkup, ReplyMarkupError,
         ReplyParameters, ReplyParametersError, TextEntities, TextEntity, TextEntityError,
@@ -366,7 +366,7 @@ impl Method for SendAudio {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::form("sendAudio", self.form)
+        Payload::form(MethodName::SendAudio, self.form)
     }
 }
 
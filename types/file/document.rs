@@ -1,5 +1,5 @@
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, Method, MethodName, Payload},
     types::{
         ChatId, InputFile, Integer, Message, ParseMode, PhotoSize, ReplyMarkup, ReplyMarkupError,
         ReplyParameters, ReplyParametersError, TextEntities, TextEntity, TextEntityError,
@@ -305,7 +305,7 @@ impl Method for SendDocument {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::form("sendDocument", self.form)
+        Payload::form(MethodName::SendDocument, self.form)
     }
 }
 
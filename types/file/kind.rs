@@ -0,0 +1,195 @@
+use crate::types::Document;
+use std::path::Path;
+
+/// Represents a kind of a file content.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FileKind {
+    /// An archive or compressed data.
+    Archive,
+    /// An audio file.
+    Audio,
+    /// An office document or PDF.
+    Document,
+    /// An executable file, a script or an installation package.
+    Executable,
+    /// An image.
+    Image,
+    /// A plain text file.
+    Text,
+    /// A video file.
+    Video,
+    /// Kind is not known.
+    Unknown,
+}
+
+impl FileKind {
+    /// Returns a kind for a MIME type.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - MIME type, parameters are ignored.
+    pub fn from_mime_type(value: &str) -> Self {
+        let value = value
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        let (top, sub) = value.split_once('/').unwrap_or((value.as_str(), ""));
+        match top {
+            "image" => return Self::Image,
+            "audio" => return Self::Audio,
+            "video" => return Self::Video,
+            "text" => return Self::Text,
+            "application" => {}
+            _ => return Self::Unknown,
+        }
+        match sub {
+            "pdf" | "msword" | "rtf" | "epub+zip" => Self::Document,
+            x if x.starts_with("vnd.openxmlformats-officedocument.")
+                || x.starts_with("vnd.ms-")
+                || x.starts_with("vnd.oasis.opendocument.") =>
+            {
+                Self::Document
+            }
+            "zip" | "gzip" | "x-gzip" | "x-tar" | "x-bzip2" | "x-xz" | "zstd" | "vnd.rar"
+            | "x-rar-compressed" | "x-7z-compressed" => Self::Archive,
+            "x-msdownload"
+            | "x-msdos-program"
+            | "x-msi"
+            | "vnd.microsoft.portable-executable"
+            | "x-executable"
+            | "x-elf"
+            | "x-mach-binary"
+            | "x-sh"
+            | "x-bat"
+            | "vnd.android.package-archive"
+            | "java-archive"
+            | "x-apple-diskimage"
+            | "vnd.debian.binary-package"
+            | "x-rpm" => Self::Executable,
+            "json" | "xml" | "x-yaml" => Self::Text,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Returns a kind for a file name.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Name of the file, only the extension is checked.
+    pub fn from_file_name(value: &str) -> Self {
+        let extension = match Path::new(value).extension().and_then(|x| x.to_str()) {
+            Some(x) => x.to_ascii_lowercase(),
+            None => return Self::Unknown,
+        };
+        match extension.as_str() {
+            "bmp" | "gif" | "heic" | "ico" | "jpeg" | "jpg" | "png" | "svg" | "tif" | "tiff"
+            | "webp" => Self::Image,
+            "7z" | "bz2" | "gz" | "rar" | "tar" | "tgz" | "xz" | "zip" | "zst" => Self::Archive,
+            "apk" | "app" | "bat" | "cmd" | "com" | "deb" | "dll" | "dmg" | "exe" | "jar"
+            | "msi" | "ps1" | "rpm" | "scr" | "sh" | "vbs" => Self::Executable,
+            "doc" | "docx" | "epub" | "odp" | "ods" | "odt" | "pdf" | "ppt" | "pptx" | "rtf"
+            | "xls" | "xlsx" => Self::Document,
+            "csv" | "json" | "log" | "md" | "txt" | "xml" | "yaml" | "yml" => Self::Text,
+            "aac" | "flac" | "m4a" | "mp3" | "oga" | "ogg" | "opus" | "wav" => Self::Audio,
+            "avi" | "m4v" | "mkv" | "mov" | "mp4" | "webm" => Self::Video,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Returns a kind detected by signatures of well-known formats.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - First bytes of the file; 16 bytes are enough for all supported formats.
+    ///
+    /// Note that ZIP is also a container for office documents and Android/Java packages,
+    /// see [`Document::sniff_kind`].
+    pub fn sniff(value: &[u8]) -> Self {
+        const SIGNATURES: &[(&[u8], FileKind)] = &[
+            (b"\x89PNG\r\n\x1a\n", FileKind::Image),
+            (b"\xff\xd8\xff", FileKind::Image),
+            (b"GIF87a", FileKind::Image),
+            (b"GIF89a", FileKind::Image),
+            (b"II*\x00", FileKind::Image),
+            (b"MM\x00*", FileKind::Image),
+            (b"PK\x03\x04", FileKind::Archive),
+            (b"PK\x05\x06", FileKind::Archive),
+            (b"Rar!\x1a\x07", FileKind::Archive),
+            (b"7z\xbc\xaf\x27\x1c", FileKind::Archive),
+            (b"\x1f\x8b", FileKind::Archive),
+            (b"BZh", FileKind::Archive),
+            (b"\xfd7zXZ\x00", FileKind::Archive),
+            (b"\x28\xb5\x2f\xfd", FileKind::Archive),
+            (b"\x7fELF", FileKind::Executable),
+            (b"MZ", FileKind::Executable),
+            (b"\xfe\xed\xfa\xce", FileKind::Executable),
+            (b"\xfe\xed\xfa\xcf", FileKind::Executable),
+            (b"\xce\xfa\xed\xfe", FileKind::Executable),
+            (b"\xcf\xfa\xed\xfe", FileKind::Executable),
+            (b"\xca\xfe\xba\xbe", FileKind::Executable),
+            (b"#!", FileKind::Executable),
+            (b"%PDF-", FileKind::Document),
+            (b"\xd0\xcf\x11\xe0\xa1\xb1\x1a\xe1", FileKind::Document),
+            (b"{\\rtf", FileKind::Document),
+            (b"ID3", FileKind::Audio),
+            (b"OggS", FileKind::Audio),
+            (b"fLaC", FileKind::Audio),
+            (b"\x1a\x45\xdf\xa3", FileKind::Video),
+        ];
+        if let Some((_, kind)) = SIGNATURES.iter().find(|(x, _)| value.starts_with(x)) {
+            return *kind;
+        }
+        if value.starts_with(b"RIFF") {
+            return match value.get(8..12) {
+                Some(b"WEBP") => Self::Image,
+                Some(b"WAVE") => Self::Audio,
+                Some(b"AVI ") => Self::Video,
+                _ => Self::Unknown,
+            };
+        }
+        if value.get(4..8) == Some(b"ftyp") {
+            return Self::Video;
+        }
+        Self::Unknown
+    }
+}
+
+impl Document {
+    /// Returns a kind of the document declared by the sender.
+    ///
+    /// The MIME type is checked first, then the extension of the file name;
+    /// both are defined by the sender and can't be trusted.
+    pub fn get_kind(&self) -> FileKind {
+        self.mime_type
+            .as_deref()
+            .map(FileKind::from_mime_type)
+            .filter(|x| *x != FileKind::Unknown)
+            .or_else(|| self.file_name.as_deref().map(FileKind::from_file_name))
+            .unwrap_or(FileKind::Unknown)
+    }
+
+    /// Returns a kind of the document detected by its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `head` - First downloaded bytes of the document.
+    ///
+    /// Falls back to [`Self::get_kind`] if the content is not recognized.
+    /// ZIP containers are reported as declared when the sender declares
+    /// a document or an executable (e.g. DOCX or APK), and as an archive otherwise.
+    pub fn sniff_kind(&self, head: &[u8]) -> FileKind {
+        let declared = self.get_kind();
+        match FileKind::sniff(head) {
+            FileKind::Unknown => declared,
+            FileKind::Archive
+                if head.starts_with(b"PK")
+                    && matches!(declared, FileKind::Document | FileKind::Executable) =>
+            {
+                declared
+            }
+            sniffed => sniffed,
+        }
+    }
+}
@@ -1,17 +1,20 @@
-pub use self::{animation::*, audio::*, document::*, photo::*, video::*, video_note::*, voice::*};
+pub use self::{
+    animation::*, audio::*, document::*, kind::*, photo::*, video::*, video_note::*, voice::*,
+};
 use crate::{
-    api::{FormValue, Method, Payload},
+    api::{FormValue, Method, MethodName, Payload},
     types::Integer,
 };
 use mime::Mime;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{fmt, path::Path, sync::Arc};
 use tokio::io::AsyncRead;
 use tokio_util::codec::{BytesCodec, FramedRead};
 
 mod animation;
 mod audio;
 mod document;
+mod kind;
 mod photo;
 mod video;
 mod video_note;
@@ -40,6 +43,8 @@ pub struct File {
     /// File path.
     ///
     /// Use [`crate::api::Client::download_file`] to get the file.
+    /// A local Bot API server started with `--local` returns an absolute path
+    /// on its file system instead, see [`Self::local_path`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file_path: Option<String>,
 }
@@ -86,6 +91,26 @@ impl File {
         self.file_path = Some(value.into());
         self
     }
+
+    /// Returns the path of the file on the file system of a local Bot API server.
+    ///
+    /// Such files can't be downloaded over HTTP; read them directly
+    /// when the server shares the file system with the bot.
+    ///
+    /// Returns [`None`] if the path is relative and the file can be downloaded.
+    pub fn local_path(&self) -> Option<&Path> {
+        self.file_path
+            .as_deref()
+            .filter(|x| is_local_file_path(x))
+            .map(Path::new)
+    }
+}
+
+/// Checks if a file path returned by a local Bot API server started with `--local` is absolute.
+///
+/// The server may run on another platform, so a leading slash is checked along with [`Path::is_absolute`].
+pub(crate) fn is_local_file_path(value: &str) -> bool {
+    value.starts_with('/') || Path::new(value).is_absolute()
 }
 
 /// Returns a basic information about a file and prepares it for downloading.
@@ -129,17 +154,21 @@ impl Method for GetFile {
     type Response = File;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getFile", self)
+        Payload::json(MethodName::GetFile, self)
     }
 }
 
 /// Represents a file reader for uploading files.
 pub struct InputFileReader {
     file_name: Option<String>,
+    file_size: Option<Integer>,
     mime_type: Option<Mime>,
+    progress_handler: Option<Arc<UploadProgressHandler>>,
     reader: FramedRead<Box<dyn AsyncRead + Send + Sync + Unpin>, BytesCodec>,
 }
 
+pub(crate) type UploadProgressHandler = dyn Fn(UploadProgress) + Send + Sync;
+
 impl InputFileReader {
     /// Creates a new `InputFileReader`.
     ///
@@ -153,7 +182,9 @@ impl InputFileReader {
         InputFileReader {
             reader: FramedRead::new(Box::new(reader), BytesCodec::new()),
             file_name: None,
+            file_size: None,
             mime_type: None,
+            progress_handler: None,
         }
     }
 
@@ -175,6 +206,24 @@ impl InputFileReader {
         self.file_name.as_deref()
     }
 
+    /// Sets a new size of the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The size of the file in bytes.
+    ///
+    /// When the size is known, the client rejects files exceeding [`crate::types::Limits`]
+    /// before sending a request.
+    pub fn with_file_size(mut self, value: Integer) -> Self {
+        self.file_size = Some(value);
+        self
+    }
+
+    /// Returns the size of the file.
+    pub fn file_size(&self) -> Option<Integer> {
+        self.file_size
+    }
+
     /// Sets a new MIME type of the file.
     ///
     /// # Arguments
@@ -189,6 +238,22 @@ impl InputFileReader {
     pub fn mime_type(&self) -> Option<&Mime> {
         self.mime_type.as_ref()
     }
+
+    /// Sets a new handler of the upload progress.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - A function called every time a chunk of the file is read for sending;
+    ///   the total size is known only if it is set with [`Self::with_file_size`].
+    ///
+    /// The file is streamed, so the handler is called as the request body is sent.
+    pub fn with_progress_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(UploadProgress) + Send + Sync + 'static,
+    {
+        self.progress_handler = Some(Arc::new(handler));
+        self
+    }
 }
 
 impl<T> From<T> for InputFileReader
@@ -210,11 +275,24 @@ impl fmt::Debug for InputFileReader {
     fn fmt(&self, out: &mut fmt::Formatter<'_>) -> fmt::Result {
         out.debug_struct("InputFileReader")
             .field("file_name", &self.file_name)
+            .field("file_size", &self.file_size)
             .field("mime_type", &self.mime_type)
+            .field("progress_handler", &self.progress_handler.is_some())
             .finish()
     }
 }
 
+/// Represents the progress of a file upload.
+///
+/// See [`InputFileReader::with_progress_handler`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UploadProgress {
+    /// Number of bytes sent.
+    pub bytes_sent: Integer,
+    /// Size of the file in bytes, if known.
+    pub total: Option<Integer>,
+}
+
 /// Represents a file to upload.
 #[derive(Debug, PartialEq)]
 pub enum InputFile {
@@ -267,12 +345,17 @@ impl From<InputFile> for FormValue {
             InputFile::Id(value) | InputFile::Url(value) => FormValue::Text(value),
             InputFile::Reader(InputFileReader {
                 file_name: name,
+                file_size: size,
                 mime_type,
+                progress_handler,
                 reader,
             }) => FormValue::File {
+                is_photo: false,
                 name,
                 mime_type,
+                progress_handler,
                 reader,
+                size,
             },
         }
     }
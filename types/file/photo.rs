@@ -1,5 +1,5 @@
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, FormValue, Method, MethodName, Payload},
     types::{
         ChatId, InputFile, Integer, Message, ParseMode, ReplyMarkup, ReplyMarkupError,
         ReplyParameters, ReplyParametersError, TextEntities, TextEntity, TextEntityError,
@@ -83,7 +83,7 @@ impl SendPhoto {
         Self {
             form: Form::from([
                 ("chat_id", chat_id.into().into()),
-                ("photo", photo.into().into()),
+                ("photo", FormValue::from(photo.into()).into_photo()),
             ]),
         }
     }
@@ -248,6 +248,6 @@ impl Method for SendPhoto {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::form("sendPhoto", self.form)
+        Payload::form(MethodName::SendPhoto, self.form)
     }
 }
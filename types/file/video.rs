@@ -1,5 +1,5 @@
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, Method, MethodName, Payload},
     types::{
         ChatId, InputFile, Integer, Message, ParseMode, PhotoSize, ReplyMarkup, ReplyMarkupError,
         ReplyParameters, ReplyParametersError, TextEntities, TextEntity, TextEntityError,
@@ -372,7 +372,7 @@ impl Method for SendVideo {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::form("sendVideo", self.form)
+        Payload::form(MethodName::SendVideo, self.form)
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, Method, MethodName, Payload},
     types::{
         ChatId, InputFile, Integer, Message, PhotoSize, ReplyMarkup, ReplyMarkupError,
         ReplyParameters, ReplyParametersError,
@@ -240,7 +240,7 @@ impl Method for SendVideoNote {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::form("sendVideoNote", self.form)
+        Payload::form(MethodName::SendVideoNote, self.form)
     }
 }
 
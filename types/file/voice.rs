@@ -1,5 +1,5 @@
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, Method, MethodName, Payload},
     types::{
         ChatId, InputFile, Integer, Message, ParseMode, ReplyMarkup, ReplyMarkupError,
         ReplyParameters, ReplyParametersError, TextEntities, TextEntity, TextEntityError,
@@ -255,6 +255,6 @@ impl Method for SendVoice {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::form("sendVoice", self.form)
+        Payload::form(MethodName::SendVoice, self.form)
     }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{ChatId, ForumTopicIconColor, Integer, Sticker},
 };
 
@@ -86,7 +86,7 @@ impl Method for CloseForumTopic {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("closeForumTopic", self)
+        Payload::json(MethodName::CloseForumTopic, self)
     }
 }
 
@@ -154,7 +154,7 @@ impl Method for CreateForumTopic {
     type Response = ForumTopic;
 
     fn into_payload(self) -> Payload {
-        Payload::json("createForumTopic", self)
+        Payload::json(MethodName::CreateForumTopic, self)
     }
 }
 
@@ -187,7 +187,7 @@ impl Method for CloseGeneralForumTopic {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("closeGeneralForumTopic", self)
+        Payload::json(MethodName::CloseGeneralForumTopic, self)
     }
 }
 
@@ -223,7 +223,7 @@ impl Method for DeleteForumTopic {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("deleteForumTopic", self)
+        Payload::json(MethodName::DeleteForumTopic, self)
     }
 }
 
@@ -298,7 +298,7 @@ impl Method for EditForumTopic {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("editForumTopic", self)
+        Payload::json(MethodName::EditForumTopic, self)
     }
 }
 
@@ -335,7 +335,7 @@ impl Method for EditGeneralForumTopic {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("editGeneralForumTopic", self)
+        Payload::json(MethodName::EditGeneralForumTopic, self)
     }
 }
 
@@ -347,7 +347,7 @@ impl Method for GetForumTopicIconStickers {
     type Response = Vec<Sticker>;
 
     fn into_payload(self) -> Payload {
-        Payload::empty("getForumTopicIconStickers")
+        Payload::empty(MethodName::GetForumTopicIconStickers)
     }
 }
 
@@ -381,7 +381,7 @@ impl Method for HideGeneralForumTopic {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("hideGeneralForumTopic", self)
+        Payload::json(MethodName::HideGeneralForumTopic, self)
     }
 }
 
@@ -418,7 +418,7 @@ impl Method for ReopenForumTopic {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("reopenForumTopic", self)
+        Payload::json(MethodName::ReopenForumTopic, self)
     }
 }
 
@@ -452,7 +452,7 @@ impl Method for ReopenGeneralForumTopic {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("reopenGeneralForumTopic", self)
+        Payload::json(MethodName::ReopenGeneralForumTopic, self)
     }
 }
 
@@ -485,7 +485,7 @@ impl Method for UnhideGeneralForumTopic {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("unhideGeneralForumTopic", self)
+        Payload::json(MethodName::UnhideGeneralForumTopic, self)
     }
 }
 
@@ -521,7 +521,7 @@ impl Method for UnpinAllForumTopicMessages {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("unpinAllForumTopicMessages", self)
+        Payload::json(MethodName::UnpinAllForumTopicMessages, self)
     }
 }
 
@@ -554,6 +554,6 @@ impl Method for UnpinAllGeneralForumTopicMessages {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("unpinAllGeneralForumTopicMessages", self)
+        Payload::json(MethodName::UnpinAllGeneralForumTopicMessages, self)
     }
 }
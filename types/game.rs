@@ -1,5 +1,5 @@
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{
         Animation, EditMessageResult, InlineKeyboardMarkup, Integer, Message, PhotoSize,
         ReplyParameters, Text, TextEntities, User,
@@ -205,7 +205,7 @@ impl Method for GetGameHighScores {
     type Response = Vec<GameHighScore>;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getGameHighScores", self)
+        Payload::json(MethodName::GetGameHighScores, self)
     }
 }
 
@@ -341,7 +341,7 @@ impl Method for SendGame {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::json("sendGame", self)
+        Payload::json(MethodName::SendGame, self)
     }
 }
 
@@ -441,6 +441,6 @@ impl Method for SetGameScore {
     type Response = EditMessageResult;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setGameScore", self)
+        Payload::json(MethodName::SetGameScore, self)
     }
 }
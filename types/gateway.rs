@@ -0,0 +1,167 @@
+use crate::types::{
+    hex::{decode_hex, encode_hex},
+    Update,
+};
+use ring::hmac;
+use serde_json::Error as JsonError;
+use std::{error::Error, fmt};
+
+/// Signs and verifies updates forwarded by a gateway.
+///
+/// A gateway receives updates of many bots and forwards them to internal services;
+/// each forwarded body is signed with a shared secret and the identifier of the tenant,
+/// so a signature for one bot can't be replayed to another.
+///
+/// A signature has the form `<key_id>:<hex encoded HMAC-SHA256>`;
+/// pass it in a header of the forwarded request.
+///
+/// To rotate a key, add the new key with [`Self::with_key`] to all services,
+/// then switch the gateway to the new key and remove the old one.
+#[derive(Clone, Debug)]
+pub struct GatewaySigner {
+    current_key_id: String,
+    keys: Vec<(String, hmac::Key)>,
+}
+
+impl GatewaySigner {
+    /// Creates a new `GatewaySigner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_id` - Identifier of the key used to sign updates.
+    /// * `secret` - The key used to sign updates.
+    pub fn new<K, S>(key_id: K, secret: S) -> Self
+    where
+        K: Into<String>,
+        S: AsRef<[u8]>,
+    {
+        let key_id = key_id.into();
+        Self {
+            current_key_id: key_id.clone(),
+            keys: vec![(key_id, hmac::Key::new(hmac::HMAC_SHA256, secret.as_ref()))],
+        }
+    }
+
+    /// Adds a key accepted when verifying signatures.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_id` - Identifier of the key.
+    /// * `secret` - The key.
+    pub fn with_key<K, S>(mut self, key_id: K, secret: S) -> Self
+    where
+        K: Into<String>,
+        S: AsRef<[u8]>,
+    {
+        let key_id = key_id.into();
+        let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_ref());
+        match self.keys.iter_mut().find(|(x, _)| *x == key_id) {
+            Some(item) => item.1 = key,
+            None => self.keys.push((key_id, key)),
+        }
+        self
+    }
+
+    /// Returns a signature of a forwarded body.
+    ///
+    /// # Arguments
+    ///
+    /// * `tenant` - Identifier of the tenant, e.g. ID of the bot.
+    /// * `body` - Body of the update.
+    pub fn sign(&self, tenant: &str, body: &[u8]) -> String {
+        let key = self
+            .get_key(&self.current_key_id)
+            .expect("current key is always present");
+        let tag = hmac::sign(key, &signed_data(tenant, body));
+        format!("{}:{}", self.current_key_id, encode_hex(tag.as_ref()))
+    }
+
+    /// Verifies a signature of a forwarded body.
+    ///
+    /// # Arguments
+    ///
+    /// * `tenant` - Identifier of the tenant the body is forwarded for.
+    /// * `body` - Body of the update.
+    /// * `signature` - Signature created by [`Self::sign`].
+    pub fn verify(
+        &self,
+        tenant: &str,
+        body: &[u8],
+        signature: &str,
+    ) -> Result<(), GatewaySignatureError> {
+        let (key_id, tag) = signature
+            .split_once(':')
+            .ok_or(GatewaySignatureError::InvalidSignature)?;
+        let tag = decode_hex(tag).ok_or(GatewaySignatureError::InvalidSignature)?;
+        let key = self
+            .get_key(key_id)
+            .ok_or_else(|| GatewaySignatureError::UnknownKey(String::from(key_id)))?;
+        hmac::verify(key, &signed_data(tenant, body), &tag)
+            .map_err(|_| GatewaySignatureError::InvalidHash)
+    }
+
+    /// Verifies a signature of a forwarded body and returns the update.
+    ///
+    /// # Arguments
+    ///
+    /// * `tenant` - Identifier of the tenant the body is forwarded for.
+    /// * `body` - Body of the update.
+    /// * `signature` - Signature created by [`Self::sign`].
+    pub fn verify_update(
+        &self,
+        tenant: &str,
+        body: &[u8],
+        signature: &str,
+    ) -> Result<Update, GatewaySignatureError> {
+        self.verify(tenant, body, signature)?;
+        serde_json::from_slice(body).map_err(GatewaySignatureError::InvalidUpdate)
+    }
+
+    fn get_key(&self, key_id: &str) -> Option<&hmac::Key> {
+        self.keys
+            .iter()
+            .find(|(x, _)| x == key_id)
+            .map(|(_, key)| key)
+    }
+}
+
+/// Represents an error occurred when verifying a forwarded update.
+#[derive(Debug)]
+pub enum GatewaySignatureError {
+    /// The signature doesn't match the body.
+    InvalidHash,
+    /// The signature is malformed.
+    InvalidSignature,
+    /// The body is not a valid update.
+    InvalidUpdate(JsonError),
+    /// The signature is created with an unknown key.
+    UnknownKey(String),
+}
+
+impl Error for GatewaySignatureError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidUpdate(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for GatewaySignatureError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidHash => write!(out, "gateway signature hash is invalid"),
+            Self::InvalidSignature => write!(out, "gateway signature is malformed"),
+            Self::InvalidUpdate(err) => write!(out, "forwarded update is invalid: {}", err),
+            Self::UnknownKey(key_id) => write!(out, "gateway signature key is unknown: {}", key_id),
+        }
+    }
+}
+
+fn signed_data(tenant: &str, body: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(tenant.len() + body.len() + 1);
+    result.extend_from_slice(tenant.as_bytes());
+    result.push(b'\n');
+    result.extend_from_slice(body);
+    result
+}
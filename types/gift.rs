@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes the types of gifts that can be gifted to a user or a chat.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct AcceptedGiftTypes {
+    /// Whether limited regular gifts are accepted.
+    pub limited_gifts: bool,
+    /// Whether a Telegram Premium subscription is accepted.
+    pub premium_subscription: bool,
+    /// Whether unique gifts or gifts that can be upgraded to unique for free are accepted.
+    pub unique_gifts: bool,
+    /// Whether unlimited regular gifts are accepted.
+    pub unlimited_gifts: bool,
+}
+
+impl AcceptedGiftTypes {
+    /// Returns `true` if any type of gifts is accepted.
+    pub fn accepts_any(&self) -> bool {
+        self.limited_gifts || self.premium_subscription || self.unique_gifts || self.unlimited_gifts
+    }
+}
@@ -0,0 +1,18 @@
+#[cfg(feature = "gateway")]
+pub(crate) fn encode_hex(value: &[u8]) -> String {
+    value.iter().map(|x| format!("{:02x}", x)).collect()
+}
+
+pub(crate) fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if !value.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|idx| {
+            value
+                .get(idx..idx + 2)
+                .and_then(|x| u8::from_str_radix(x, 16).ok())
+        })
+        .collect()
+}
@@ -0,0 +1,131 @@
+use crate::types::{hex::decode_hex, Integer};
+use ring::hmac;
+use serde::Deserialize;
+use std::{collections::BTreeMap, error::Error, fmt};
+
+const SECRET_KEY: &[u8] = b"WebAppData";
+
+/// Represents a user received in Mini App init data.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct WebAppUser {
+    /// Unique identifier of the user.
+    pub id: Integer,
+    /// First name of the user.
+    pub first_name: String,
+    /// Indicates whether the user allowed the bot to message them.
+    pub allows_write_to_pm: Option<bool>,
+    /// Indicates whether the user is a bot.
+    pub is_bot: Option<bool>,
+    /// Indicates whether the user is a Telegram Premium user.
+    pub is_premium: Option<bool>,
+    /// IETF language tag of the language of the user.
+    pub language_code: Option<String>,
+    /// Last name of the user.
+    pub last_name: Option<String>,
+    /// URL of the profile photo of the user.
+    pub photo_url: Option<String>,
+    /// Username of the user.
+    pub username: Option<String>,
+}
+
+/// Represents verified init data of a Mini App.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitData {
+    /// Date when the Mini App was opened; Unix timestamp.
+    pub auth_date: Integer,
+    /// Global identifier of the chat from which the Mini App was opened.
+    pub chat_instance: Option<String>,
+    /// Type of the chat from which the Mini App was opened.
+    pub chat_type: Option<String>,
+    /// Unique identifier of the session.
+    ///
+    /// Used to send a message with [`crate::types::AnswerWebAppQuery`].
+    pub query_id: Option<String>,
+    /// Value of the `startattach` or `startapp` parameter of the link.
+    pub start_param: Option<String>,
+    /// The current user.
+    pub user: Option<WebAppUser>,
+    /// All received fields except `hash`, including the ones above.
+    pub fields: BTreeMap<String, String>,
+}
+
+/// Validates init data received from a Mini App.
+///
+/// # Arguments
+///
+/// * `init_data` - Value of `Telegram.WebApp.initData`, a URL-encoded query string.
+/// * `bot_token` - Token of the bot which opened the Mini App.
+///
+/// The data don't expire, compare [`InitData::auth_date`] with the current time
+/// to reject outdated data.
+pub fn validate_init_data<A, B>(init_data: A, bot_token: B) -> Result<InitData, InitDataError>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    let mut fields: BTreeMap<String, String> =
+        form_urlencoded::parse(init_data.as_ref().as_bytes())
+            .into_owned()
+            .collect();
+    let hash = fields
+        .remove("hash")
+        .ok_or(InitDataError::MissingField("hash"))?;
+    let hash = decode_hex(&hash).ok_or(InitDataError::InvalidField("hash"))?;
+    let data_check_string = fields
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let secret = hmac::sign(
+        &hmac::Key::new(hmac::HMAC_SHA256, SECRET_KEY),
+        bot_token.as_ref().as_bytes(),
+    );
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_ref());
+    hmac::verify(&key, data_check_string.as_bytes(), &hash)
+        .map_err(|_| InitDataError::InvalidHash)?;
+
+    Ok(InitData {
+        auth_date: fields
+            .get("auth_date")
+            .ok_or(InitDataError::MissingField("auth_date"))?
+            .parse()
+            .map_err(|_| InitDataError::InvalidField("auth_date"))?,
+        chat_instance: fields.get("chat_instance").cloned(),
+        chat_type: fields.get("chat_type").cloned(),
+        query_id: fields.get("query_id").cloned(),
+        start_param: fields.get("start_param").cloned(),
+        user: fields
+            .get("user")
+            .map(|x| serde_json::from_str(x))
+            .transpose()
+            .map_err(|_| InitDataError::InvalidField("user"))?,
+        fields,
+    })
+}
+
+/// Represents an error occurred when validating Mini App init data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InitDataError {
+    /// A field has an invalid value.
+    InvalidField(&'static str),
+    /// The hash doesn't match the data.
+    InvalidHash,
+    /// A required field is missing.
+    MissingField(&'static str),
+}
+
+impl Error for InitDataError {}
+
+impl fmt::Display for InitDataError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidField(name) => {
+                write!(out, "init data contain an invalid field: {}", name)
+            }
+            Self::InvalidHash => write!(out, "init data hash is invalid"),
+            Self::MissingField(name) => {
+                write!(out, "init data don't contain a required field: {}", name)
+            }
+        }
+    }
+}
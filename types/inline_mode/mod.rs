@@ -1,6 +1,7 @@
-pub use self::{content::*, query::*, result::*, web_app::*};
+pub use self::{content::*, paginator::*, query::*, result::*, web_app::*};
 
 mod content;
+mod paginator;
 mod query;
 mod result;
 mod web_app;
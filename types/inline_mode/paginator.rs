@@ -0,0 +1,87 @@
+use crate::types::{AnswerInlineQuery, InlineQuery, InlineQueryResult, MAX_INLINE_QUERY_RESULTS};
+
+/// Builds paginated answers to inline queries.
+///
+/// The paginator decodes the offset of a query, asks the provider for a page of results,
+/// and sets the next offset so that clients request the next page when the user scrolls.
+///
+/// The provider receives the query, the number of results to skip, and the maximum number
+/// of results to return; the limit includes one extra result
+/// used to detect whether there is a next page.
+///
+/// # Example
+///
+/// ```
+/// use tg::{InlinePaginator, InlineQueryResult, InlineQueryResultArticle, InputMessageContentText};
+///
+/// let items: Vec<String> = (0..120).map(|x| x.to_string()).collect();
+/// let paginator = InlinePaginator::new(|_query, offset, limit| {
+///     items
+///         .iter()
+///         .skip(offset)
+///         .take(limit)
+///         .map(|x| {
+///             InlineQueryResult::from(InlineQueryResultArticle::new(
+///                 x.as_str(),
+///                 InputMessageContentText::new(x.as_str()),
+///                 x.as_str(),
+///             ))
+///         })
+///         .collect::<Vec<_>>()
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct InlinePaginator<F> {
+    page_size: usize,
+    provider: F,
+}
+
+impl<F, I> InlinePaginator<F>
+where
+    F: Fn(&InlineQuery, usize, usize) -> I,
+    I: IntoIterator<Item = InlineQueryResult>,
+{
+    /// Creates a new `InlinePaginator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - A function returning results for the query, offset and limit.
+    pub fn new(provider: F) -> Self {
+        Self {
+            page_size: MAX_INLINE_QUERY_RESULTS,
+            provider,
+        }
+    }
+
+    /// Sets a new page size.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Number of results per answer; 1-50; default - 50.
+    pub fn with_page_size(mut self, value: usize) -> Self {
+        self.page_size = value.clamp(1, MAX_INLINE_QUERY_RESULTS);
+        self
+    }
+
+    /// Returns an answer containing the requested page of results.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query to answer.
+    pub fn answer(&self, query: &InlineQuery) -> AnswerInlineQuery {
+        let offset = query.get_offset_number();
+        let mut results: Vec<InlineQueryResult> =
+            (self.provider)(query, offset, self.page_size + 1)
+                .into_iter()
+                .take(self.page_size + 1)
+                .collect();
+        let has_next_page = results.len() > self.page_size;
+        results.truncate(self.page_size);
+        let answer = AnswerInlineQuery::new(query.id.as_str(), results);
+        if has_next_page {
+            answer.with_next_page(offset + self.page_size)
+        } else {
+            answer.with_last_page()
+        }
+    }
+}
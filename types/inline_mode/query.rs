@@ -1,10 +1,20 @@
 use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, error::Error, fmt};
 
 use crate::{
-    api::{Method, Payload},
-    types::{InlineQueryResult, InlineQueryResultsButton, Integer, Location, User},
+    api::{Method, MethodName, Payload},
+    types::{
+        InlineQueryResult, InlineQueryResultError, InlineQueryResultsButton, Integer, Location,
+        User,
+    },
 };
 
+/// Maximum number of results in an answer to an inline query.
+pub const MAX_INLINE_QUERY_RESULTS: usize = 50;
+
+/// Maximum length of the `next_offset` of an answer to an inline query in bytes.
+pub const MAX_INLINE_QUERY_OFFSET_LENGTH: usize = 64;
+
 
 
 /// Represents an incoming inline query.
@@ -78,6 +88,15 @@ impl InlineQuery {
         self.location = Some(value);
         self
     }
+
+    /// Returns the offset parsed as a number of already sent results.
+    ///
+    /// An empty or non-numeric offset is treated as the first page.
+    ///
+    /// See [`AnswerInlineQuery::with_next_page`].
+    pub fn get_offset_number(&self) -> usize {
+        self.offset.parse().unwrap_or_default()
+    }
 }
 
 /// Represents a type of the chat, from which the inline query was sent.
@@ -186,12 +205,94 @@ impl AnswerInlineQuery {
         self.next_offset = Some(value.into());
         self
     }
+
+    /// Sets a numeric next offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Number of results sent with the current and previous pages.
+    ///
+    /// Use [`InlineQuery::get_offset_number`] to get the offset of the next query.
+    pub fn with_next_page(self, value: usize) -> Self {
+        self.with_next_offset(value.to_string())
+    }
+
+    /// Indicates that there are no more results.
+    pub fn with_last_page(self) -> Self {
+        self.with_next_offset("")
+    }
+
+    /// Checks the answer against constraints of the Bot API.
+    ///
+    /// Checks the number of results, uniqueness of their IDs, every result
+    /// (see [`InlineQueryResult::validate`]) and the length of the next offset.
+    pub fn validate(&self) -> Result<(), AnswerInlineQueryError> {
+        if self.results.len() > MAX_INLINE_QUERY_RESULTS {
+            return Err(AnswerInlineQueryError::TooManyResults(self.results.len()));
+        }
+        let mut ids = HashSet::with_capacity(self.results.len());
+        for (index, result) in self.results.iter().enumerate() {
+            let id = result
+                .validate_id()
+                .map_err(|error| AnswerInlineQueryError::InvalidResult { index, error })?;
+            if !ids.insert(id.clone()) {
+                return Err(AnswerInlineQueryError::DuplicateId(id));
+            }
+        }
+        match self.next_offset {
+            Some(ref offset) if offset.len() > MAX_INLINE_QUERY_OFFSET_LENGTH => {
+                Err(AnswerInlineQueryError::NextOffsetTooLong(offset.len()))
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 impl Method for AnswerInlineQuery {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("answerInlineQuery", self)
+        Payload::json(MethodName::AnswerInlineQuery, self)
+    }
+}
+
+/// Represents a violation of the Bot API constraints in an answer to an inline query.
+#[derive(Debug)]
+pub enum AnswerInlineQueryError {
+    /// Two or more results have the same ID.
+    DuplicateId(String),
+    /// A result is invalid.
+    InvalidResult {
+        /// Index of the result.
+        index: usize,
+        /// The violation.
+        error: InlineQueryResultError,
+    },
+    /// Next offset is longer than 64 bytes.
+    NextOffsetTooLong(usize),
+    /// More than 50 results.
+    TooManyResults(usize),
+}
+
+impl Error for AnswerInlineQueryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidResult { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AnswerInlineQueryError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        use self::AnswerInlineQueryError::*;
+        match self {
+            DuplicateId(id) => write!(out, "duplicate inline query result ID: {}", id),
+            InvalidResult { index, error } => write!(out, "result #{}: {}", index, error),
+            NextOffsetTooLong(length) => {
+                write!(out, "next offset must be up to 64 bytes, got {}", length)
+            }
+            TooManyResults(count) => write!(out, "up to 50 results allowed, got {}", count),
+        }
     }
 }
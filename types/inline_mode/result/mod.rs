@@ -1,10 +1,11 @@
-use self::raw::RawInlineQueryResult;
+use self::raw::{RawInlineQueryResult, RawInlineQueryResultType};
 pub use self::{
     article::*, audio::*, contact::*, document::*, game::*, gif::*, location::*, mpeg4_gif::*,
-    photo::*, sticker::*, venue::*, video::*, voice::*,
+    photo::*, sticker::*, thumbnail::*, venue::*, video::*, voice::*,
 };
 use crate::types::{Location, User, WebAppInfo};
 use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt};
 
 mod article;
 mod audio;
@@ -17,10 +18,18 @@ mod mpeg4_gif;
 mod photo;
 mod raw;
 mod sticker;
+mod thumbnail;
 mod venue;
 mod video;
 mod voice;
 
+/// Maximum length of an inline query result ID in bytes.
+pub const MAX_INLINE_QUERY_RESULT_ID_LENGTH: usize = 64;
+
+const GIF_THUMBNAIL_MIME_TYPES: [&str; 3] = ["image/jpeg", "image/gif", "video/mp4"];
+const VIDEO_MIME_TYPES: [&str; 2] = ["text/html", "video/mp4"];
+const EMBEDDED_VIDEO_MIME_TYPE: &str = "text/html";
+
 /// Represents a result of an inline query.
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, derive_more::From, Deserialize, PartialEq, Serialize)]
@@ -69,6 +78,85 @@ pub enum InlineQueryResult {
     Voice(InlineQueryResultVoice),
 }
 
+impl InlineQueryResult {
+    /// Checks the result against constraints of the Bot API.
+    ///
+    /// Telegram rejects the whole answer when a single result is invalid,
+    /// so it is useful to validate results before sending them.
+    pub fn validate(&self) -> Result<(), InlineQueryResultError> {
+        self.validate_id().map(|_| ())
+    }
+
+    pub(crate) fn validate_id(&self) -> Result<String, InlineQueryResultError> {
+        use self::RawInlineQueryResultType::*;
+        let raw = RawInlineQueryResult::from(self.clone());
+        let id_length = raw.id.len();
+        if id_length == 0 || id_length > MAX_INLINE_QUERY_RESULT_ID_LENGTH {
+            return Err(InlineQueryResultError::InvalidId(id_length));
+        }
+        let data = raw.data;
+        if matches!(raw.result_type, Gif | Mpeg4Gif | Photo | Video)
+            && data.thumbnail_url.as_deref().is_none_or(str::is_empty)
+        {
+            return Err(InlineQueryResultError::MissingThumbnail);
+        }
+        if matches!(raw.result_type, Gif | Mpeg4Gif) {
+            if let Some(mime_type) = data.thumbnail_mime_type {
+                if !GIF_THUMBNAIL_MIME_TYPES.contains(&mime_type.as_str()) {
+                    return Err(InlineQueryResultError::UnsupportedThumbnailMimeType(mime_type));
+                }
+            }
+        }
+        if let (Video, Some(mime_type)) = (raw.result_type, data.mime_type) {
+            if !VIDEO_MIME_TYPES.contains(&mime_type.as_str()) {
+                return Err(InlineQueryResultError::UnsupportedMimeType(mime_type));
+            }
+            if mime_type == EMBEDDED_VIDEO_MIME_TYPE && data.input_message_content.is_none() {
+                return Err(InlineQueryResultError::MissingInputMessageContent);
+            }
+        }
+        Ok(raw.id)
+    }
+}
+
+/// Represents a violation of the Bot API constraints in an inline query result.
+#[derive(Debug)]
+pub enum InlineQueryResultError {
+    /// Length of the ID in bytes is not in range 1-64.
+    InvalidId(usize),
+    /// An embedded video requires input message content.
+    MissingInputMessageContent,
+    /// Thumbnail URL is required but empty.
+    MissingThumbnail,
+    /// MIME type of the content is not supported.
+    UnsupportedMimeType(String),
+    /// MIME type of the thumbnail is not supported.
+    UnsupportedThumbnailMimeType(String),
+}
+
+impl Error for InlineQueryResultError {}
+
+impl fmt::Display for InlineQueryResultError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        use self::InlineQueryResultError::*;
+        write!(
+            out,
+            "invalid inline query result: {}",
+            match self {
+                InvalidId(length) => format!("ID must be 1-64 bytes, got {}", length),
+                MissingInputMessageContent => {
+                    String::from("embedded video requires input message content")
+                }
+                MissingThumbnail => String::from("thumbnail URL is required"),
+                UnsupportedMimeType(value) => format!("unsupported MIME type {}", value),
+                UnsupportedThumbnailMimeType(value) => {
+                    format!("unsupported thumbnail MIME type {}", value)
+                }
+            }
+        )
+    }
+}
+
 /// Represents a button to be shown above inline query results.
 #[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize)]
 pub struct InlineQueryResultsButton {
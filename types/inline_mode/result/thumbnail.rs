@@ -0,0 +1,163 @@
+use mime::Mime;
+
+/// Default maximum size of a thumbnail in bytes.
+pub const DEFAULT_THUMBNAIL_MAX_FILE_SIZE: u64 = 200 * 1024;
+
+/// Represents a candidate for a thumbnail of an inline query result.
+///
+/// Unknown MIME type and size are not validated.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThumbnailCandidate {
+    url: String,
+    file_size: Option<u64>,
+    mime_type: Option<Mime>,
+}
+
+impl ThumbnailCandidate {
+    /// Creates a new `ThumbnailCandidate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - URL of the image.
+    pub fn new<T>(url: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            url: url.into(),
+            file_size: None,
+            mime_type: None,
+        }
+    }
+
+    /// Sets a new file size.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Size of the image in bytes.
+    pub fn with_file_size(mut self, value: u64) -> Self {
+        self.file_size = Some(value);
+        self
+    }
+
+    /// Sets a new MIME type.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - MIME type of the image.
+    pub fn with_mime_type(mut self, value: Mime) -> Self {
+        self.mime_type = Some(value);
+        self
+    }
+}
+
+/// Selects a thumbnail URL for an inline query result using a fallback chain.
+///
+/// The chain is: an explicit thumbnail, the media itself (e.g. a photo is a valid thumbnail
+/// for itself when it is small enough), and a placeholder URL.
+/// Candidates with an unsupported MIME type or a file size over the limit are skipped.
+///
+/// # Example
+///
+/// ```
+/// use tg::{ThumbnailCandidate, ThumbnailFallback};
+///
+/// let fallback = ThumbnailFallback::default().with_placeholder_url("https://example.com/thumb.jpg");
+/// let photo = ThumbnailCandidate::new("https://example.com/photo.jpg")
+///     .with_mime_type(mime::IMAGE_JPEG)
+///     .with_file_size(1024 * 1024);
+/// let url = fallback.select(None, Some(photo));
+/// assert_eq!(url.as_deref(), Some("https://example.com/thumb.jpg"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ThumbnailFallback {
+    max_file_size: u64,
+    mime_types: Vec<Mime>,
+    placeholder_url: Option<String>,
+}
+
+impl Default for ThumbnailFallback {
+    fn default() -> Self {
+        Self {
+            max_file_size: DEFAULT_THUMBNAIL_MAX_FILE_SIZE,
+            mime_types: vec![mime::IMAGE_JPEG],
+            placeholder_url: None,
+        }
+    }
+}
+
+impl ThumbnailFallback {
+    /// Sets a new maximum file size.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Maximum size of a thumbnail in bytes;
+    ///   default - [`DEFAULT_THUMBNAIL_MAX_FILE_SIZE`].
+    pub fn with_max_file_size(mut self, value: u64) -> Self {
+        self.max_file_size = value;
+        self
+    }
+
+    /// Sets a new list of supported MIME types.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Supported MIME types; default - `image/jpeg`;
+    ///   GIF and MPEG4 GIF results also accept `image/gif` and `video/mp4`.
+    pub fn with_mime_types<T>(mut self, value: T) -> Self
+    where
+        T: IntoIterator<Item = Mime>,
+    {
+        self.mime_types = value.into_iter().collect();
+        self
+    }
+
+    /// Sets a new placeholder URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - URL of an image used when there are no valid candidates.
+    pub fn with_placeholder_url<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.placeholder_url = Some(value.into());
+        self
+    }
+
+    /// Returns `true` if the candidate satisfies the constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidate` - The candidate to check.
+    pub fn is_valid(&self, candidate: &ThumbnailCandidate) -> bool {
+        !candidate.url.is_empty()
+            && candidate.mime_type.as_ref().is_none_or(|x| {
+                self.mime_types
+                    .iter()
+                    .any(|y| y.essence_str() == x.essence_str())
+            })
+            && candidate.file_size.is_none_or(|x| x <= self.max_file_size)
+    }
+
+    /// Selects a thumbnail URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `explicit` - A thumbnail provided for the result.
+    /// * `media` - The media of the result; pass it for photos only.
+    ///
+    /// Returns [`None`] if there are no valid candidates and no placeholder URL.
+    pub fn select(
+        &self,
+        explicit: Option<ThumbnailCandidate>,
+        media: Option<ThumbnailCandidate>,
+    ) -> Option<String> {
+        explicit
+            .into_iter()
+            .chain(media)
+            .find(|x| self.is_valid(x))
+            .map(|x| x.url)
+            .or_else(|| self.placeholder_url.clone())
+    }
+}
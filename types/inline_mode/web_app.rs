@@ -1,5 +1,5 @@
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::InlineQueryResult,
 };
 use serde::{Deserialize, Serialize};
@@ -61,6 +61,6 @@ impl Method for AnswerWebAppQuery {
     type Response = SentWebAppMessage;
 
     fn into_payload(self) -> Payload {
-        Payload::json("answerWebAppQuery", self)
+        Payload::json(MethodName::AnswerWebAppQuery, self)
     }
 }
@@ -0,0 +1,78 @@
+use crate::types::Integer;
+
+const MB: Integer = 1024 * 1024;
+
+/// Represents limits of a Bot API server.
+///
+/// A [local Bot API server][1] accepts larger files and more webhook connections
+/// than the cloud one;
+/// pass [`Limits::LOCAL`] to [`crate::api::Client::with_limits`] when using it.
+///
+/// [1]: https://core.telegram.org/bots/api#using-a-local-bot-api-server
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Limits {
+    /// Maximum size of a file to download; in bytes.
+    ///
+    /// [`None`] if the size is not limited.
+    pub max_download_size: Option<Integer>,
+    /// Maximum size of a photo to upload; in bytes.
+    pub max_photo_upload_size: Integer,
+    /// Maximum size of a file to upload; in bytes.
+    pub max_upload_size: Integer,
+    /// Maximum number of simultaneous webhook connections.
+    pub max_webhook_connections: Integer,
+}
+
+impl Limits {
+    /// Limits of the cloud Bot API server.
+    pub const CLOUD: Self = Self {
+        max_download_size: Some(20 * MB),
+        max_photo_upload_size: 10 * MB,
+        max_upload_size: 50 * MB,
+        max_webhook_connections: 100,
+    };
+
+    /// Limits of a local Bot API server.
+    pub const LOCAL: Self = Self {
+        max_download_size: None,
+        max_photo_upload_size: 10 * MB,
+        max_upload_size: 2000 * MB,
+        max_webhook_connections: 100_000,
+    };
+
+    /// Returns `true` if a file can be downloaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_size` - Size of the file in bytes; a file of unknown size is allowed.
+    pub fn can_download(&self, file_size: Option<Integer>) -> bool {
+        match (self.max_download_size, file_size) {
+            (Some(max_size), Some(size)) => size <= max_size,
+            _ => true,
+        }
+    }
+
+    /// Returns `true` if a file can be uploaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_size` - Size of the file in bytes.
+    pub fn can_upload(&self, file_size: Integer) -> bool {
+        file_size <= self.max_upload_size
+    }
+
+    /// Returns `true` if a photo can be uploaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_size` - Size of the photo in bytes.
+    pub fn can_upload_photo(&self, file_size: Integer) -> bool {
+        file_size <= self.max_photo_upload_size
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::CLOUD
+    }
+}
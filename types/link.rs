@@ -1,4 +1,11 @@
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "qr")]
+use std::error::Error;
+use std::fmt;
+#[cfg(feature = "qr")]
+use png::{BitDepth, ColorType, Encoder, EncodingError};
+#[cfg(feature = "qr")]
+use qrcode::{types::QrError, Color, QrCode};
 
 /// Represents the options used for link preview generation.
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
@@ -79,3 +86,185 @@ impl LinkPreviewOptions {
         self
     }
 }
+
+/// Maximum length of a deep link payload.
+pub const MAX_DEEP_LINK_PAYLOAD_LENGTH: usize = 64;
+
+/// Represents a kind of a deep link.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum DeepLinkKind {
+    /// Opens a private chat with the bot; the payload is passed to the `/start` command.
+    #[default]
+    Start,
+    /// Opens the main Mini App of the bot; the payload is passed as `start_param`.
+    StartApp,
+    /// Adds the bot to a group; the payload is passed to the `/start` command.
+    StartGroup,
+}
+
+impl DeepLinkKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            DeepLinkKind::Start => "start",
+            DeepLinkKind::StartApp => "startapp",
+            DeepLinkKind::StartGroup => "startgroup",
+        }
+    }
+}
+
+/// Represents a `t.me` deep link to a bot.
+///
+/// Use [`ToString::to_string`] to get the URL.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DeepLink {
+    bot_username: String,
+    kind: DeepLinkKind,
+    payload: Option<String>,
+}
+
+impl DeepLink {
+    /// Creates a new `DeepLink`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bot_username` - Username of the bot without leading `@`.
+    pub fn new<T>(bot_username: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            bot_username: bot_username.into(),
+            kind: DeepLinkKind::default(),
+            payload: None,
+        }
+    }
+
+    /// Sets a new kind.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Kind of the link; default - [`DeepLinkKind::Start`].
+    pub fn with_kind(mut self, value: DeepLinkKind) -> Self {
+        self.kind = value;
+        self
+    }
+
+    /// Sets a new payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Payload of the link; 1-64 characters;
+    ///   only `A-Z`, `a-z`, `0-9`, `_` and `-` are allowed.
+    pub fn with_payload<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.payload = Some(value.into());
+        self
+    }
+
+    /// Returns `true` if the payload satisfies the constraints.
+    pub fn is_valid(&self) -> bool {
+        self.payload.as_deref().is_none_or(|x| {
+            !x.is_empty()
+                && x.len() <= MAX_DEEP_LINK_PAYLOAD_LENGTH
+                && x.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        })
+    }
+}
+
+#[cfg(feature = "qr")]
+impl DeepLink {
+    /// Renders the link as a QR code in PNG format.
+    ///
+    /// # Arguments
+    ///
+    /// * `module_size` - Size of a single QR code module in pixels.
+    ///
+    /// The image includes a quiet zone of 4 modules on each side.
+    /// Wrap the bytes into a [`std::io::Cursor`] and an [`crate::types::InputFileReader`]
+    /// to send the code using [`crate::types::SendPhoto`].
+    pub fn to_qr_png(&self, module_size: u32) -> Result<Vec<u8>, DeepLinkQrError> {
+        const QUIET_ZONE: u32 = 4;
+        let code = QrCode::new(self.to_string())?;
+        let width = code.width() as u32;
+        let colors = code.to_colors();
+        let module_size = module_size.max(1);
+        let side = (width + QUIET_ZONE * 2) * module_size;
+        let mut pixels = vec![u8::MAX; (side * side) as usize];
+        for (idx, color) in colors.into_iter().enumerate() {
+            if color == Color::Light {
+                continue;
+            }
+            let x = (idx as u32 % width + QUIET_ZONE) * module_size;
+            let y = (idx as u32 / width + QUIET_ZONE) * module_size;
+            for row in y..y + module_size {
+                let start = (row * side + x) as usize;
+                pixels[start..start + module_size as usize].fill(0);
+            }
+        }
+        let mut result = Vec::new();
+        let mut encoder = Encoder::new(&mut result, side, side);
+        encoder.set_color(ColorType::Grayscale);
+        encoder.set_depth(BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&pixels)?;
+        writer.finish()?;
+        Ok(result)
+    }
+}
+
+/// Represents an error occurred when rendering a deep link as a QR code.
+#[cfg(feature = "qr")]
+#[derive(Debug)]
+pub enum DeepLinkQrError {
+    /// Failed to encode the link as a QR code.
+    Encode(QrError),
+    /// Failed to write the PNG image.
+    Png(EncodingError),
+}
+
+#[cfg(feature = "qr")]
+impl From<QrError> for DeepLinkQrError {
+    fn from(err: QrError) -> Self {
+        Self::Encode(err)
+    }
+}
+
+#[cfg(feature = "qr")]
+impl From<EncodingError> for DeepLinkQrError {
+    fn from(err: EncodingError) -> Self {
+        Self::Png(err)
+    }
+}
+
+#[cfg(feature = "qr")]
+impl Error for DeepLinkQrError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Encode(err) => Some(err),
+            Self::Png(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "qr")]
+impl fmt::Display for DeepLinkQrError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Encode(err) => write!(out, "failed to encode deep link as QR code: {}", err),
+            Self::Png(err) => write!(out, "failed to write QR code image: {}", err),
+        }
+    }
+}
+
+impl fmt::Display for DeepLink {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        write!(out, "https://t.me/{}", self.bot_username)?;
+        match (self.kind, &self.payload) {
+            (DeepLinkKind::Start, None) => Ok(()),
+            (kind, None) => write!(out, "?{}", kind.as_str()),
+            (kind, Some(payload)) => write!(out, "?{}={}", kind.as_str(), payload),
+        }
+    }
+}
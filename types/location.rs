@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{ChatId, Float, Integer, Message, ReplyMarkup, ReplyParameters},
 };
 
@@ -287,6 +287,6 @@ impl Method for SendLocation {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::json("sendLocation", self)
+        Payload::json(MethodName::SendLocation, self)
     }
 }
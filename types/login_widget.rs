@@ -0,0 +1,192 @@
+use crate::{
+    api::{Clock, SystemClock},
+    types::{hex::decode_hex, Integer},
+};
+use ring::{digest, hmac};
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Represents data of a user authorized with the Telegram Login widget.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoginData {
+    /// Unique identifier of the user.
+    pub id: Integer,
+    /// First name of the user.
+    pub first_name: String,
+    /// Date of the authorization; Unix timestamp.
+    pub auth_date: Integer,
+    /// Last name of the user.
+    pub last_name: Option<String>,
+    /// URL of the profile photo of the user.
+    pub photo_url: Option<String>,
+    /// Username of the user.
+    pub username: Option<String>,
+}
+
+/// Verifies data received from the Telegram Login widget.
+///
+/// The widget is used to authorize users on websites;
+/// the data is passed to the callback URL as query parameters
+/// or to the `onauth` JavaScript callback as an object.
+///
+/// Note that Mini Apps use a different algorithm to sign `initData`.
+#[derive(Clone, Debug)]
+pub struct LoginWidget {
+    clock: Arc<dyn Clock>,
+    key: hmac::Key,
+    max_age: Option<Duration>,
+}
+
+impl LoginWidget {
+    /// Creates a new `LoginWidget`.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Token of the bot linked to the website.
+    pub fn new<T>(token: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let secret = digest::digest(&digest::SHA256, token.as_ref().as_bytes());
+        Self {
+            clock: Arc::new(SystemClock),
+            key: hmac::Key::new(hmac::HMAC_SHA256, secret.as_ref()),
+            max_age: None,
+        }
+    }
+
+    /// Sets a new clock used to check the age of the data.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The clock; default - [`SystemClock`].
+    pub fn with_clock<T>(mut self, value: T) -> Self
+    where
+        T: Clock + 'static,
+    {
+        self.clock = Arc::new(value);
+        self
+    }
+
+    /// Sets a new maximum age of the data.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Data authorized earlier are rejected;
+    ///   by default the age is not checked.
+    pub fn with_max_age(mut self, value: Duration) -> Self {
+        self.max_age = Some(value);
+        self
+    }
+
+    /// Verifies the data and returns the authorized user.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - All fields received from the widget, including `hash`.
+    pub fn verify<I, K, V>(&self, fields: I) -> Result<LoginData, LoginWidgetError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.verify_at(fields, self.clock.now())
+    }
+
+    /// Verifies the data at the given time and returns the authorized user.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - All fields received from the widget, including `hash`.
+    /// * `now` - Current time used to check the age of the data.
+    pub fn verify_at<I, K, V>(
+        &self,
+        fields: I,
+        now: SystemTime,
+    ) -> Result<LoginData, LoginWidgetError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let mut fields: BTreeMap<String, String> = fields
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+        let hash = fields
+            .remove("hash")
+            .ok_or(LoginWidgetError::MissingField("hash"))?;
+        let hash = decode_hex(&hash).ok_or(LoginWidgetError::InvalidField("hash"))?;
+        let data_check_string = fields
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join("\n");
+        hmac::verify(&self.key, data_check_string.as_bytes(), &hash)
+            .map_err(|_| LoginWidgetError::InvalidHash)?;
+
+        let data = LoginData {
+            id: parse_integer(&fields, "id")?,
+            first_name: fields
+                .remove("first_name")
+                .ok_or(LoginWidgetError::MissingField("first_name"))?,
+            auth_date: parse_integer(&fields, "auth_date")?,
+            last_name: fields.remove("last_name"),
+            photo_url: fields.remove("photo_url"),
+            username: fields.remove("username"),
+        };
+        if let Some(max_age) = self.max_age {
+            let auth_date = UNIX_EPOCH + Duration::from_secs(data.auth_date.max(0) as u64);
+            if now.duration_since(auth_date).is_ok_and(|x| x > max_age) {
+                return Err(LoginWidgetError::Expired);
+            }
+        }
+        Ok(data)
+    }
+}
+
+/// Represents an error occurred when verifying Telegram Login widget data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LoginWidgetError {
+    /// The data are older than the maximum age.
+    Expired,
+    /// A field has an invalid value.
+    InvalidField(&'static str),
+    /// The hash doesn't match the data.
+    InvalidHash,
+    /// A required field is missing.
+    MissingField(&'static str),
+}
+
+impl Error for LoginWidgetError {}
+
+impl fmt::Display for LoginWidgetError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Expired => write!(out, "login data are expired"),
+            Self::InvalidField(name) => {
+                write!(out, "login data contain an invalid field: {}", name)
+            }
+            Self::InvalidHash => write!(out, "login data hash is invalid"),
+            Self::MissingField(name) => {
+                write!(out, "login data don't contain a required field: {}", name)
+            }
+        }
+    }
+}
+
+fn parse_integer(
+    fields: &BTreeMap<String, String>,
+    name: &'static str,
+) -> Result<Integer, LoginWidgetError> {
+    fields
+        .get(name)
+        .ok_or(LoginWidgetError::MissingField(name))?
+        .parse()
+        .map_err(|_| LoginWidgetError::InvalidField(name))
+}
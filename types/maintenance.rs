@@ -0,0 +1,124 @@
+use crate::types::{ReplyParameters, SendMessage, Update, UpdateType, UserPeerId};
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+const DEFAULT_REPLY_TEXT: &str = "The bot is under maintenance, please try again later.";
+
+/// Short-circuits updates of non-admin users while the bot is under maintenance.
+///
+/// Clones share the switch, so the mode can be toggled at runtime,
+/// e.g. from an admin command handler, and observed by the update loop.
+///
+/// Call [`Self::is_blocked`] before other handlers
+/// and send [`Self::reply`] instead of handling blocked updates.
+#[derive(Clone, Debug)]
+pub struct MaintenanceMode {
+    admins: HashSet<UserPeerId>,
+    enabled: Arc<AtomicBool>,
+    reply_text: String,
+}
+
+impl MaintenanceMode {
+    /// Creates a new disabled `MaintenanceMode`.
+    pub fn new() -> Self {
+        Self {
+            admins: HashSet::new(),
+            enabled: Arc::new(AtomicBool::new(false)),
+            reply_text: String::from(DEFAULT_REPLY_TEXT),
+        }
+    }
+
+    /// Adds an admin whose updates are processed during maintenance.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - ID of the user.
+    pub fn with_admin<T>(mut self, value: T) -> Self
+    where
+        T: Into<UserPeerId>,
+    {
+        self.admins.insert(value.into());
+        self
+    }
+
+    /// Sets a new text of the auto-reply.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Text sent in response to blocked messages.
+    pub fn with_reply_text<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.reply_text = value.into();
+        self
+    }
+
+    /// Returns `true` if the maintenance mode is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Enables or disables the maintenance mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Whether the mode is enabled.
+    pub fn set_enabled(&self, value: bool) {
+        self.enabled.store(value, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the user is an admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - ID of the user.
+    pub fn is_admin(&self, user_id: UserPeerId) -> bool {
+        self.admins.contains(&user_id)
+    }
+
+    /// Returns `true` if the update must not be processed.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - An incoming update.
+    ///
+    /// Updates without a user are blocked as well.
+    pub fn is_blocked(&self, update: &Update) -> bool {
+        self.is_enabled() && !update.get_user_id().is_some_and(|x| self.is_admin(x))
+    }
+
+    /// Returns an auto-reply to a blocked update.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - The blocked update.
+    ///
+    /// Returns [`None`] if the update is not blocked or it is not a new message.
+    pub fn reply(&self, update: &Update) -> Option<SendMessage> {
+        if !self.is_blocked(update) {
+            return None;
+        }
+        let message = match update.update_type {
+            UpdateType::Message(ref message) => message,
+            _ => return None,
+        };
+        let mut method = SendMessage::new(message.chat.get_id(), self.reply_text.as_str())
+            .with_reply_parameters(ReplyParameters::new(message.id));
+        if let Some(message_thread_id) = message.message_thread_id {
+            method = method.with_message_thread_id(message_thread_id);
+        }
+        Some(method)
+    }
+}
+
+impl Default for MaintenanceMode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,7 +2,7 @@ use std::{error::Error, fmt};
 use serde::Serialize;
 use serde_json::Error as JsonError;
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, FormValue, Method, MethodName, Payload},
     types::{
         ChatId,
         InputFile,
@@ -48,11 +48,12 @@ impl MediaGroup {
 
         let mut form = Form::default();
 
-        let mut add_file = |key: String, file: InputFile| -> String {
+        let mut add_file = |key: String, file: InputFile, is_photo: bool| -> String {
             match &file {
                 InputFile::Id(text) | InputFile::Url(text) => text.clone(),
                 _ => {
-                    form.insert_field(&key, file);
+                    let value = FormValue::from(file);
+                    form.insert_field(&key, if is_photo { value.into_photo() } else { value });
                     format!("attach://{}", key)
                 }
             }
@@ -60,10 +61,11 @@ impl MediaGroup {
 
         let mut info = Vec::new();
         for (idx, item) in items {
-            let media = add_file(format!("tgbot_im_file_{}", idx), item.file);
+            let is_photo = matches!(item.item_type, MediaGroupItemType::Photo(_));
+            let media = add_file(format!("tgbot_im_file_{}", idx), item.file, is_photo);
             let thumbnail = item
                 .thumbnail
-                .map(|thumbnail| add_file(format!("tgbot_im_thumb_{}", idx), thumbnail));
+                .map(|thumbnail| add_file(format!("tgbot_im_thumb_{}", idx), thumbnail, false));
             let data = match item.item_type {
                 MediaGroupItemType::Audio(info) => MediaGroupItemData::Audio { media, thumbnail, info },
                 MediaGroupItemType::Document(info) => MediaGroupItemData::Document { media, thumbnail, info },
@@ -344,6 +346,6 @@ impl Method for SendMediaGroup {
     type Response = Vec<Message>;
 
     fn into_payload(self) -> Payload {
-        Payload::form("sendMediaGroup", self.form)
+        Payload::form(MethodName::SendMediaGroup, self.form)
     }
 }
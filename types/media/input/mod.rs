@@ -1,5 +1,8 @@
 pub use self::{animation::*, audio::*, document::*, photo::*, video::*};
-use crate::{api::Form, types::InputFile};
+use crate::{
+    api::{Form, FormValue},
+    types::InputFile,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Error as JsonError;
 use std::{error::Error, fmt};
@@ -65,19 +68,22 @@ impl InputMedia {
     {
         let mut form = Form::default();
 
-        let add_file = |form: &mut Form, key: &str, file: InputFile| -> String {
+        let add_file = |form: &mut Form, key: &str, file: InputFile, is_photo: bool| -> String {
             match file {
                 InputFile::Id(text) | InputFile::Url(text) => text,
                 _ => {
-                    form.insert_field(key, file);
+                    let value = FormValue::from(file);
+                    form.insert_field(key, if is_photo { value.into_photo() } else { value });
                     format!("attach://{}", key)
                 }
             }
         };
 
-        let media = add_file(&mut form, "tgbot_im_file", media.into());
-        let thumbnail = thumbnail.map(|thumb| add_file(&mut form, "tgbot_im_thumb", thumb.into()));
-        let data = match media_type.into() {
+        let media_type = media_type.into();
+        let is_photo = matches!(media_type, InputMediaType::Photo(_));
+        let media = add_file(&mut form, "tgbot_im_file", media.into(), is_photo);
+        let thumbnail = thumbnail.map(|thumb| add_file(&mut form, "tgbot_im_thumb", thumb.into(), false));
+        let data = match media_type {
             InputMediaType::Animation(info) => InputMediaData::Animation {
                 media,
                 thumbnail,
@@ -1,5 +1,5 @@
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, FormValue, Method, MethodName, Payload},
     types::{
         ChatId, InputFile, Integer, Message, ParseMode, PhotoSize, ReplyMarkup, ReplyMarkupError,
         ReplyParameters, ReplyParametersError, TextEntities, TextEntity, TextEntityError, User,
@@ -143,16 +143,6 @@ enum RawPaidMedia {
     },
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize)]
-struct RawPaidMediaPhoto {
-    photo: Vec<PhotoSize>,
-}
-
-#[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize)]
-struct RawPaidMediaVideo {
-    video: Video,
-}
-
 impl From<RawPaidMedia> for PaidMedia {
     fn from(value: RawPaidMedia) -> Self {
         match value {
@@ -347,7 +337,7 @@ impl Method for SendPaidMedia {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::form("sendPaidMedia", self.form)
+        Payload::form(MethodName::SendPaidMedia, self.form)
     }
 }
 
@@ -385,26 +375,27 @@ impl InputPaidMediaGroup {
         }
 
         let mut form = Form::default();
-        let mut add_file = |key: String, file: InputFile| -> String {
+        let mut add_file = |key: String, file: InputFile, is_photo: bool| -> String {
             match &file {
                 InputFile::Id(text) | InputFile::Url(text) => text.clone(),
                 _ => {
-                    form.insert_field(&key, file);
+                    let value = FormValue::from(file);
+                    form.insert_field(&key, if is_photo { value.into_photo() } else { value });
                     format!("attach://{}", key)
                 }
             }
         };
         let mut info = Vec::new();
         for (idx, item) in items {
-            let media = add_file(format!("tgbot_ipm_file_{}", idx), item.file);
-            let thumbnail = item
-                .thumbnail
-                .map(|thumbnail| add_file(format!("tgbot_ipm_thumb_{}", idx), thumbnail));
+            let is_photo = matches!(item.item_type, InputPaidMediaGroupItemType::Photo);
+            let media = add_file(format!("tgbot_ipm_file_{}", idx), item.file, is_photo);
             let data = match item.item_type {
                 InputPaidMediaGroupItemType::Photo => InputPaidMediaGroupItemData::Photo { media },
                 InputPaidMediaGroupItemType::Video(info) => InputPaidMediaGroupItemData::Video {
                     media,
-                    thumbnail,
+                    thumbnail: item.thumbnail.map(|thumbnail| {
+                        add_file(format!("tgbot_ipm_thumb_{}", idx), thumbnail, false)
+                    }),
                     info,
                 },
             };
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{Integer, WebAppInfo},
 };
 
@@ -105,7 +105,7 @@ impl Method for GetChatMenuButton {
     type Response = MenuButton;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getChatMenuButton", self)
+        Payload::json(MethodName::GetChatMenuButton, self)
     }
 }
 
@@ -146,6 +146,6 @@ impl Method for SetChatMenuButton {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setChatMenuButton", self)
+        Payload::json(MethodName::SetChatMenuButton, self)
     }
 }
@@ -0,0 +1,191 @@
+use crate::types::{ChatPeerId, Integer, Message, Update, UpdateType};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// A bounded cache of recently received messages used to resolve replies.
+///
+/// A replied message is included without its own reply,
+/// and a reply to another chat or forum topic contains a part of the original message only.
+/// The cache keeps full messages, see [`Message::resolve_reply`].
+///
+/// The least recently used message is evicted when the cache is full.
+/// Messages are shared, so clones of the cache and resolved replies don't copy them.
+#[derive(Clone)]
+pub struct MessageCache {
+    capacity: usize,
+    state: Arc<Mutex<MessageCacheState>>,
+}
+
+impl MessageCache {
+    /// Creates a new `MessageCache`.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum number of messages to keep.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Arc::new(Mutex::new(MessageCacheState::default())),
+        }
+    }
+
+    /// Returns a cached message.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the chat.
+    /// * `message_id` - Identifier of the message inside the chat.
+    pub fn get<T>(&self, chat_id: T, message_id: Integer) -> Option<Arc<Message>>
+    where
+        T: Into<ChatPeerId>,
+    {
+        self.lock().get((chat_id.into(), message_id))
+    }
+
+    /// Adds a message to the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - A received or sent message;
+    ///   replaces a cached message with the same identifier, e.g. after an edit.
+    pub fn insert(&self, message: &Message) {
+        if self.capacity == 0 {
+            return;
+        }
+        let key = (message.chat.get_id(), message.id);
+        let mut state = self.lock();
+        state.insert(key, Arc::new(message.clone()));
+        while state.order.len() > self.capacity {
+            state.pop();
+        }
+    }
+
+    /// Adds a message of the update to the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - An incoming update;
+    ///   new and edited messages, channel posts and business messages are cached.
+    pub fn insert_update(&self, update: &Update) {
+        match update.update_type {
+            UpdateType::BusinessMessage(ref x)
+            | UpdateType::ChannelPost(ref x)
+            | UpdateType::EditedBusinessMessage(ref x)
+            | UpdateType::EditedChannelPost(ref x)
+            | UpdateType::EditedMessage(ref x)
+            | UpdateType::Message(ref x) => self.insert(x),
+            _ => {}
+        }
+    }
+
+    /// Removes a message from the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the chat.
+    /// * `message_id` - Identifier of the message inside the chat.
+    pub fn remove<T>(&self, chat_id: T, message_id: Integer)
+    where
+        T: Into<ChatPeerId>,
+    {
+        self.lock().remove((chat_id.into(), message_id));
+    }
+
+    /// Returns the number of cached messages.
+    pub fn len(&self) -> usize {
+        self.lock().messages.len()
+    }
+
+    /// Returns `true` if the cache contains no messages.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MessageCacheState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl fmt::Debug for MessageCache {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        out.debug_struct("MessageCache")
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+type MessageKey = (ChatPeerId, Integer);
+
+/// Messages are ordered by the generation of their last use,
+/// so the least recently used one is the first entry of `order`.
+#[derive(Default)]
+struct MessageCacheState {
+    generation: u64,
+    messages: HashMap<MessageKey, (u64, Arc<Message>)>,
+    order: BTreeMap<u64, MessageKey>,
+}
+
+impl MessageCacheState {
+    fn get(&mut self, key: MessageKey) -> Option<Arc<Message>> {
+        let generation = self.next_generation();
+        let (last_generation, message) = self.messages.get_mut(&key)?;
+        self.order.remove(last_generation);
+        self.order.insert(generation, key);
+        *last_generation = generation;
+        Some(message.clone())
+    }
+
+    fn insert(&mut self, key: MessageKey, message: Arc<Message>) {
+        let generation = self.next_generation();
+        if let Some((last_generation, _)) = self.messages.insert(key, (generation, message)) {
+            self.order.remove(&last_generation);
+        }
+        self.order.insert(generation, key);
+    }
+
+    fn remove(&mut self, key: MessageKey) {
+        if let Some((generation, _)) = self.messages.remove(&key) {
+            self.order.remove(&generation);
+        }
+    }
+
+    fn pop(&mut self) {
+        if let Some((_, key)) = self.order.pop_first() {
+            self.messages.remove(&key);
+        }
+    }
+
+    fn next_generation(&mut self) -> u64 {
+        self.generation += 1;
+        self.generation
+    }
+}
+
+impl Message {
+    /// Returns the full message this message replies to.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - Cache of recently received messages.
+    ///
+    /// For replies in the same chat, the cached message is returned when available,
+    /// otherwise a copy of the included message.
+    /// For replies to other chats, the cached message is returned
+    /// when the original chat and message identifier are known.
+    pub fn resolve_reply(&self, cache: &MessageCache) -> Option<Arc<Message>> {
+        if let Some(message) = self.get_reply_to_message() {
+            return cache
+                .get(message.chat.get_id(), message.id)
+                .or_else(|| Some(Arc::new(message.clone())));
+        }
+        let reply = self.get_external_reply()?;
+        match (reply.chat.as_ref(), reply.message_id) {
+            (Some(chat), Some(message_id)) => cache.get(chat.get_id(), message_id),
+            _ => None,
+        }
+    }
+}
@@ -1,28 +1,32 @@
-use std::{convert::TryFrom, error::Error, fmt, string::FromUtf16Error};
-use crate::types::Message;
+use std::{convert::TryFrom, error::Error, fmt, ops::Range};
+use crate::types::{Message, Text, TextEntity};
 
 /// A simple command implementation.
 ///
-/// We just take first command from a message and ignore others.
+/// We just take first command from a message and ignore others;
+/// use [`Commands`] to get all of them.
 /// Also we assume that entire text after command is arguments separated by whitespace.
 /// In order to include space in argument you need to wrap it with `'`: `'arg1 v' arg2`.
 ///
 /// # Example
 /// ```
-/// use tgbot::types::{Command, Message};
+/// use tg::{Command, Message};
 /// use std::convert::TryFrom;
 ///
 /// fn handle_command(message: Message) {
 ///     let command = Command::try_from(message).unwrap();
 ///     println!("NAME: {}", command.get_name());
 ///     println!("ARGUMENTS: {:?}", command.get_args());
+///     println!("RAW ARGUMENTS: {}", command.get_raw_args());
 ///     println!("MESSAGE: {:?}", command.get_message());
 /// }
 /// ```
 #[derive(Clone, Debug)]
 pub struct Command {
     name: String,
+    bot_username: Option<String>,
     args: Vec<String>,
+    raw_args: String,
     message: Message,
 }
 
@@ -32,11 +36,25 @@ impl Command {
         &self.name
     }
 
+    /// Returns the username of the bot the command is addressed to.
+    ///
+    /// The username is available only when the command is sent as `/command@username`.
+    pub fn get_bot_username(&self) -> Option<&str> {
+        self.bot_username.as_deref()
+    }
+
     /// Returns the list of arguments.
     pub fn get_args(&self) -> &[String] {
         &self.args
     }
 
+    /// Returns the text after the command as is.
+    ///
+    /// Leading and trailing whitespace is removed.
+    pub fn get_raw_args(&self) -> &str {
+        &self.raw_args
+    }
+
     /// Returns the message where the command comes from.
     pub fn get_message(&self) -> &Message {
         &self.message
@@ -48,27 +66,11 @@ impl Command {
 pub enum CommandError {
     /// A command is not found in a message.
     NotFound,
-    /// Failed to create an UTF-16 string when reading a command from a message.
-    Utf16(FromUtf16Error),
     /// An error when splitting an arguments string with mismatched quotes.
     MismatchedQuotes,
 }
 
-impl From<FromUtf16Error> for CommandError {
-    fn from(err: FromUtf16Error) -> Self {
-        Self::Utf16(err)
-    }
-}
-
-impl Error for CommandError {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            CommandError::NotFound => None,
-            CommandError::Utf16(err) => Some(err),
-            CommandError::MismatchedQuotes => None,
-        }
-    }
-}
+impl Error for CommandError {}
 
 impl fmt::Display for CommandError {
     fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
@@ -77,7 +79,6 @@ impl fmt::Display for CommandError {
             "failed to parse command: {}",
             match self {
                 CommandError::NotFound => String::from("not found"),
-                CommandError::Utf16(err) => err.to_string(),
                 CommandError::MismatchedQuotes => String::from("mismatched quotes"),
             }
         )
@@ -88,23 +89,128 @@ impl TryFrom<Message> for Command {
     type Error = CommandError;
 
     fn try_from(message: Message) -> Result<Self, Self::Error> {
-        match message.get_text().map(|text| (text.get_bot_commands(), text)) {
-            Some((Some(commands), text)) => {
-                // just take first command and ignore others
-                let command = &commands[0];
-                let name = command.command.clone();
-                // assume that all text after command is arguments
-                let offset = text.data.find(&name).unwrap_or(0);
-                // bot suffix is 1 character longer due to '@' symbol
-                let length = name.len() + command.bot_name.as_ref().map(|x| x.len() + 1).unwrap_or(0);
-                let pos = offset + length;
-                // pos is UTF-16 offset
-                let raw_args: Vec<u16> = text.data.encode_utf16().skip(pos).collect();
-                let raw_args = String::from_utf16(&raw_args)?;
-				let args = raw_args.split_whitespace().map(ToOwned::to_owned).collect();
-                Ok(Command { name, args, message })
-            }
-            _ => Err(CommandError::NotFound),
+        let text = message.get_text().ok_or(CommandError::NotFound)?;
+        // just take first command and ignore others
+        let &(start, end) = get_command_ranges(text)
+            .first()
+            .ok_or(CommandError::NotFound)?;
+        // assume that all text after command is arguments
+        let (name, bot_username, args, raw_args) = parse_command(text, start..end, end..text.utf16_len())?;
+        Ok(Command {
+            name,
+            bot_username,
+            args,
+            raw_args,
+            message,
+        })
+    }
+}
+
+/// All commands found in a message.
+///
+/// Arguments of a command are the text between the command and the next command.
+///
+/// # Example
+/// ```
+/// use tg::{Commands, Message};
+/// use std::convert::TryFrom;
+///
+/// fn handle_commands(message: Message) {
+///     for command in Commands::try_from(message).unwrap() {
+///         println!("NAME: {}", command.get_name());
+///         println!("ARGUMENTS: {:?}", command.get_args());
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Commands {
+    items: Vec<Command>,
+}
+
+impl Commands {
+    /// Returns the list of commands in the order they appear in the text.
+    pub fn get_commands(&self) -> &[Command] {
+        &self.items
+    }
+}
+
+impl IntoIterator for Commands {
+    type Item = Command;
+    type IntoIter = std::vec::IntoIter<Command>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Commands {
+    type Item = &'a Command;
+    type IntoIter = std::slice::Iter<'a, Command>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl TryFrom<Message> for Commands {
+    type Error = CommandError;
+
+    fn try_from(message: Message) -> Result<Self, Self::Error> {
+        let text = message.get_text().ok_or(CommandError::NotFound)?;
+        let ranges = get_command_ranges(text);
+        if ranges.is_empty() {
+            return Err(CommandError::NotFound);
+        }
+        let mut items = Vec::with_capacity(ranges.len());
+        for (idx, &(start, end)) in ranges.iter().enumerate() {
+            let args_end = ranges
+                .get(idx + 1)
+                .map(|&(x, _)| x)
+                .unwrap_or_else(|| text.utf16_len());
+            let (name, bot_username, args, raw_args) = parse_command(text, start..end, end..args_end)?;
+            items.push(Command {
+                name,
+                bot_username,
+                args,
+                raw_args,
+                message: message.clone(),
+            });
         }
+        Ok(Self { items })
     }
 }
+
+fn get_command_ranges(text: &Text) -> Vec<(usize, usize)> {
+    let mut result: Vec<(usize, usize)> = text
+        .entities
+        .iter()
+        .flatten()
+        .filter_map(|x| match x {
+            TextEntity::BotCommand(position) => {
+                let offset = position.offset as usize;
+                Some((offset, offset + position.length as usize))
+            }
+            _ => None,
+        })
+        .collect();
+    result.sort_unstable();
+    result
+}
+
+fn parse_command(
+    text: &Text,
+    name_range: Range<usize>,
+    args_range: Range<usize>,
+) -> Result<(String, Option<String>, Vec<String>, String), CommandError> {
+    let name = text.slice_utf16(name_range).ok_or(CommandError::NotFound)?;
+    let (name, bot_username) = match name.split_once('@') {
+        Some((name, bot_username)) => (name, Some(String::from(bot_username))),
+        None => (name, None),
+    };
+    let raw_args = text
+        .slice_utf16(args_range)
+        .ok_or(CommandError::NotFound)?
+        .trim();
+    let args = raw_args.split_whitespace().map(ToOwned::to_owned).collect();
+    Ok((String::from(name), bot_username, args, String::from(raw_args)))
+}
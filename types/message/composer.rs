@@ -0,0 +1,179 @@
+use crate::{
+    api::{Method, Payload},
+    types::{
+        wrap_label, ChatId, InputFileReader, Message, SendDocument, SendMessage, Text,
+        MAX_MESSAGE_TEXT_LENGTH,
+    },
+};
+use std::io::Cursor;
+
+const DEFAULT_FILE_NAME: &str = "message.txt";
+const DEFAULT_MAX_PARTS: usize = 3;
+const DEFAULT_SUMMARY_LENGTH: usize = 200;
+
+/// Represents a way to send a text longer than a message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LongTextPolicy {
+    /// Split the text into several messages.
+    Split,
+    /// Send the text as a `.txt` document with a summary caption.
+    Document,
+    /// Split the text if it fits into the given number of messages,
+    /// send it as a document otherwise.
+    Auto {
+        /// Maximum number of messages.
+        max_parts: usize,
+    },
+}
+
+impl Default for LongTextPolicy {
+    fn default() -> Self {
+        Self::Auto {
+            max_parts: DEFAULT_MAX_PARTS,
+        }
+    }
+}
+
+/// Represents a method created by [`LongTextComposer`].
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum ComposedMessage {
+    /// A text message.
+    Text(SendMessage),
+    /// A document containing the text.
+    Document(SendDocument),
+}
+
+impl Method for ComposedMessage {
+    type Response = Message;
+
+    fn into_payload(self) -> Payload {
+        match self {
+            Self::Text(x) => x.into_payload(),
+            Self::Document(x) => x.into_payload(),
+        }
+    }
+}
+
+/// Composes messages for texts of any length.
+///
+/// A text which fits into a message is sent as is;
+/// a longer text is handled according to [`LongTextPolicy`].
+/// Splitting keeps entities, see [`Text::split`];
+/// a document contains the plain text without entities.
+#[derive(Clone, Debug)]
+pub struct LongTextComposer {
+    file_name: String,
+    max_length: usize,
+    policy: LongTextPolicy,
+    summary_length: usize,
+}
+
+impl LongTextComposer {
+    /// Creates a new `LongTextComposer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Way to send long texts.
+    pub fn new(policy: LongTextPolicy) -> Self {
+        Self {
+            file_name: String::from(DEFAULT_FILE_NAME),
+            max_length: MAX_MESSAGE_TEXT_LENGTH,
+            policy,
+            summary_length: DEFAULT_SUMMARY_LENGTH,
+        }
+    }
+
+    /// Sets a new name of the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Name of the file; default - `message.txt`.
+    pub fn with_file_name<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.file_name = value.into();
+        self
+    }
+
+    /// Sets a new maximum length of a message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Maximum length in UTF-16 code units;
+    ///   default - [`MAX_MESSAGE_TEXT_LENGTH`].
+    pub fn with_max_length(mut self, value: usize) -> Self {
+        self.max_length = value.clamp(2, MAX_MESSAGE_TEXT_LENGTH);
+        self
+    }
+
+    /// Sets a new maximum length of the document caption.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Maximum number of characters taken from the start of the text;
+    ///   default - 200; zero disables the caption.
+    pub fn with_summary_length(mut self, value: usize) -> Self {
+        self.summary_length = value;
+        self
+    }
+
+    /// Returns methods to send the text.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the target chat.
+    /// * `text` - The text to send.
+    pub fn compose<A, B>(&self, chat_id: A, text: B) -> Vec<ComposedMessage>
+    where
+        A: Into<ChatId>,
+        B: Into<Text>,
+    {
+        let chat_id = chat_id.into();
+        let text = text.into();
+        if text.utf16_len() <= self.max_length {
+            return vec![ComposedMessage::Text(send_text(chat_id, text))];
+        }
+        let parts = match self.policy {
+            LongTextPolicy::Document => None,
+            LongTextPolicy::Split => Some(text.split(self.max_length)),
+            LongTextPolicy::Auto { max_parts } => {
+                Some(text.split(self.max_length)).filter(|x| x.len() <= max_parts)
+            }
+        };
+        match parts {
+            Some(parts) => parts
+                .into_iter()
+                .map(|x| ComposedMessage::Text(send_text(chat_id.clone(), x)))
+                .collect(),
+            None => vec![ComposedMessage::Document(self.send_document(chat_id, text))],
+        }
+    }
+
+    fn send_document(&self, chat_id: ChatId, text: Text) -> SendDocument {
+        let summary = wrap_label(text.data.trim(), self.summary_length);
+        let reader = InputFileReader::new(Cursor::new(text.data.into_bytes()))
+            .with_file_name(self.file_name.as_str());
+        let method = SendDocument::new(chat_id, reader);
+        if summary.is_empty() {
+            method
+        } else {
+            method.with_caption(summary)
+        }
+    }
+}
+
+impl Default for LongTextComposer {
+    fn default() -> Self {
+        Self::new(LongTextPolicy::default())
+    }
+}
+
+fn send_text(chat_id: ChatId, text: Text) -> SendMessage {
+    let method = SendMessage::new(chat_id, text.data);
+    match text.entities {
+        Some(entities) => method.with_entities(entities),
+        None => method,
+    }
+}
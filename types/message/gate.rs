@@ -0,0 +1,149 @@
+use crate::types::{
+    Chat, Command, DeepLink, InlineKeyboardButton, InlineKeyboardMarkup, ReplyParameters,
+    SendMessage,
+};
+use std::collections::HashSet;
+
+const DEFAULT_BUTTON_TEXT: &str = "Continue in PM";
+const DEFAULT_MESSAGE_TEXT: &str = "This command is available in a private chat only.";
+const START_COMMAND: &str = "/start";
+const START_PAYLOAD_PREFIX: &str = "cmd_";
+
+/// Redirects sensitive commands issued in groups to a private chat with the bot.
+///
+/// Use [`Self::is_restricted`] as a filter and [`Self::redirect`] as a response;
+/// the response contains a deep link button which opens a private chat
+/// and sends `/start` with a payload.
+/// Use [`Self::get_redirected_command`] to restore the original command from `/start`.
+///
+/// Arguments of the original command are not passed to the private chat,
+/// since they may contain sensitive data and the payload is limited to 64 characters.
+#[derive(Clone, Debug)]
+pub struct PrivateCommandGate {
+    bot_username: String,
+    button_text: String,
+    commands: HashSet<String>,
+    message_text: String,
+}
+
+impl PrivateCommandGate {
+    /// Creates a new `PrivateCommandGate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bot_username` - Username of the bot without leading `@`.
+    pub fn new<T>(bot_username: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            bot_username: bot_username.into(),
+            button_text: String::from(DEFAULT_BUTTON_TEXT),
+            commands: HashSet::new(),
+            message_text: String::from(DEFAULT_MESSAGE_TEXT),
+        }
+    }
+
+    /// Adds a sensitive command.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Name of the command with or without leading slash.
+    pub fn with_command<T>(mut self, value: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.commands
+            .insert(String::from(value.as_ref().trim_start_matches('/')));
+        self
+    }
+
+    /// Sets a new text of the button.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Text of the button; default - “Continue in PM”.
+    pub fn with_button_text<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.button_text = value.into();
+        self
+    }
+
+    /// Sets a new text of the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Text of the message sent in response to a restricted command.
+    pub fn with_message_text<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.message_text = value.into();
+        self
+    }
+
+    /// Returns `true` if the command is sensitive and was issued outside of a private chat.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command to check.
+    ///
+    /// Commands addressed to another bot, e.g. `/command@other_bot`, are not restricted.
+    pub fn is_restricted(&self, command: &Command) -> bool {
+        !matches!(command.get_message().chat, Chat::Private(_))
+            && command
+                .get_bot_username()
+                .is_none_or(|x| x.eq_ignore_ascii_case(&self.bot_username))
+            && self.commands.contains(command_name(command))
+    }
+
+    /// Returns a response to a restricted command.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command to respond to.
+    ///
+    /// Returns [`None`] if the command is not restricted, see [`Self::is_restricted`].
+    pub fn redirect(&self, command: &Command) -> Option<SendMessage> {
+        if !self.is_restricted(command) {
+            return None;
+        }
+        let message = command.get_message();
+        let url = DeepLink::new(self.bot_username.as_str())
+            .with_payload(format!("{}{}", START_PAYLOAD_PREFIX, command_name(command)))
+            .to_string();
+        let mut method = SendMessage::new(message.chat.get_id(), self.message_text.as_str())
+            .with_reply_markup(InlineKeyboardMarkup::from([[
+                InlineKeyboardButton::for_url(self.button_text.as_str(), url),
+            ]]))
+            .with_reply_parameters(ReplyParameters::new(message.id));
+        if let Some(message_thread_id) = message.message_thread_id {
+            method = method.with_message_thread_id(message_thread_id);
+        }
+        Some(method)
+    }
+
+    /// Returns the name of a redirected command without leading slash.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - A `/start` command received in a private chat.
+    ///
+    /// Returns [`None`] if the command is not a redirect created by [`Self::redirect`].
+    pub fn get_redirected_command<'a>(&self, command: &'a Command) -> Option<&'a str> {
+        if command.get_name() != START_COMMAND {
+            return None;
+        }
+        command
+            .get_args()
+            .first()
+            .and_then(|x| x.strip_prefix(START_PAYLOAD_PREFIX))
+            .filter(|x| self.commands.contains(*x))
+    }
+}
+
+fn command_name(command: &Command) -> &str {
+    command.get_name().trim_start_matches('/')
+}
@@ -1,6 +1,6 @@
 use serde::Serialize;
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, Method, MethodName, Payload},
     types::{
         ChatId,
         EditMessageResult,
@@ -15,11 +15,24 @@ use crate::{
         ParseMode,
         ReplyMarkup,
         ReplyParameters,
+        Text,
         TextEntities,
         TextEntity,
     },
 };
 
+/// Maximum number of message IDs accepted by batch methods.
+pub const MAX_BATCH_MESSAGE_IDS: usize = 100;
+
+fn chunk_message_ids(mut message_ids: Vec<Integer>) -> Vec<Vec<Integer>> {
+    message_ids.sort_unstable();
+    message_ids.dedup();
+    message_ids
+        .chunks(MAX_BATCH_MESSAGE_IDS)
+        .map(Vec::from)
+        .collect()
+}
+
 /// Copies a message.
 ///
 /// Service messages, paid media messages, giveaway messages, giveaway winners messages,
@@ -50,6 +63,8 @@ pub struct CopyMessage {
     pub reply_parameters: Option<ReplyParameters>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_caption_above_media: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_start_timestamp: Option<Integer>,
 }
 
 impl CopyMessage {
@@ -78,6 +93,7 @@ impl CopyMessage {
             reply_markup: None,
             reply_parameters: None,
             show_caption_above_media: None,
+            video_start_timestamp: None,
         }
     }
 
@@ -88,6 +104,7 @@ impl CopyMessage {
     /// * `value` - Caption; 0-1024 characters after entities parsing.
     ///
     /// If not specified, the original caption is kept.
+    /// Pass an empty string to copy the message without caption.
     pub fn with_caption<T>(mut self, value: T) -> Self
     where
         T: Into<String>,
@@ -125,6 +142,34 @@ impl CopyMessage {
         self
     }
 
+    /// Sets a new caption with entities.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Caption; 0-1024 characters after entities parsing.
+    ///
+    /// Caption parse mode will be set to [`None`] when this method is called.
+    pub fn with_caption_text<T>(mut self, value: T) -> Self
+    where
+        T: Into<Text>,
+    {
+        let value = value.into();
+        self.caption = Some(value.data);
+        self.caption_entities = value.entities;
+        self.parse_mode = None;
+        self
+    }
+
+    /// Keeps the caption and caption entities of the original message.
+    ///
+    /// Resets the caption, caption entities and parse mode set earlier.
+    pub fn with_original_caption(mut self) -> Self {
+        self.caption = None;
+        self.caption_entities = None;
+        self.parse_mode = None;
+        self
+    }
+
     /// Sets a new value for a `disable_notification` flag.
     ///
     /// # Arguments
@@ -191,13 +236,23 @@ impl CopyMessage {
         self.show_caption_above_media = Some(value);
         self
     }
+
+    /// Sets a new start timestamp of a video.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New start timestamp for the copied video in the message; in seconds.
+    pub fn with_video_start_timestamp(mut self, value: Integer) -> Self {
+        self.video_start_timestamp = Some(value);
+        self
+    }
 }
 
 impl Method for CopyMessage {
     type Response = MessageId;
 
     fn into_payload(self) -> Payload {
-        Payload::json("copyMessage", self)
+        Payload::json(MethodName::CopyMessage, self)
     }
 }
 
@@ -293,13 +348,26 @@ impl CopyMessages {
         self.remove_caption = Some(value);
         self
     }
+
+    /// Splits the method into methods with at most [`MAX_BATCH_MESSAGE_IDS`] messages each.
+    ///
+    /// Message IDs are sorted and deduplicated.
+    pub fn into_chunks(mut self) -> Vec<Self> {
+        chunk_message_ids(std::mem::take(&mut self.message_ids))
+            .into_iter()
+            .map(|message_ids| Self {
+                message_ids,
+                ..self.clone()
+            })
+            .collect()
+    }
 }
 
 impl Method for CopyMessages {
     type Response = Vec<MessageId>;
 
     fn into_payload(self) -> Payload {
-        Payload::json("copyMessages", self)
+        Payload::json(MethodName::CopyMessages, self)
     }
 }
 
@@ -344,7 +412,7 @@ impl Method for DeleteMessage {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("deleteMessage", self)
+        Payload::json(MethodName::DeleteMessage, self)
     }
 }
 
@@ -376,13 +444,26 @@ impl DeleteMessages {
             message_ids: message_ids.into_iter().collect(),
         }
     }
+
+    /// Splits the method into methods with at most [`MAX_BATCH_MESSAGE_IDS`] messages each.
+    ///
+    /// Message IDs are sorted and deduplicated.
+    pub fn into_chunks(self) -> Vec<Self> {
+        chunk_message_ids(self.message_ids)
+            .into_iter()
+            .map(|message_ids| Self {
+                chat_id: self.chat_id.clone(),
+                message_ids,
+            })
+            .collect()
+    }
 }
 
 impl Method for DeleteMessages {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("deleteMessages", self)
+        Payload::json(MethodName::DeleteMessages, self)
     }
 }
 
@@ -396,11 +477,11 @@ pub struct EditMessageCaption {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<TextEntities>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<ChatId>,
+    chat_id: Option<ChatId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
+    inline_message_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    message_id: Option<Integer>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -539,7 +620,7 @@ impl Method for EditMessageCaption {
     type Response = EditMessageResult;
 
     fn into_payload(self) -> Payload {
-        Payload::json("editMessageCaption", self)
+        Payload::json(MethodName::EditMessageCaption, self)
     }
 }
 
@@ -554,17 +635,17 @@ pub struct EditMessageLiveLocation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<ChatId>,
+    chat_id: Option<ChatId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub heading: Option<Integer>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub horizontal_accuracy: Option<Float>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
+    inline_message_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub live_period: Option<Integer>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    message_id: Option<Integer>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proximity_alert_radius: Option<Integer>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -702,7 +783,7 @@ impl Method for EditMessageLiveLocation {
     type Response = EditMessageResult;
 
     fn into_payload(self) -> Payload {
-        Payload::json("editMessageLiveLocation", self)
+        Payload::json(MethodName::EditMessageLiveLocation, self)
     }
 }
 
@@ -783,7 +864,7 @@ impl Method for EditMessageMedia {
     type Response = EditMessageResult;
 
     fn into_payload(self) -> Payload {
-        Payload::form("editMessageMedia", self.form)
+        Payload::form(MethodName::EditMessageMedia, self.form)
     }
 }
 
@@ -793,11 +874,11 @@ pub struct EditMessageReplyMarkup {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<ChatId>,
+    chat_id: Option<ChatId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
+    inline_message_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    message_id: Option<Integer>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
@@ -871,7 +952,7 @@ impl Method for EditMessageReplyMarkup {
     type Response = EditMessageResult;
 
     fn into_payload(self) -> Payload {
-        Payload::json("editMessageReplyMarkup", self)
+        Payload::json(MethodName::EditMessageReplyMarkup, self)
     }
 }
 
@@ -882,15 +963,15 @@ pub struct EditMessageText {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<ChatId>,
+    chat_id: Option<ChatId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entities: Option<TextEntities>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub link_preview_options: Option<LinkPreviewOptions>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
+    inline_message_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    message_id: Option<Integer>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -986,6 +1067,20 @@ impl EditMessageText {
         self
     }
 
+    /// Sets a new value for a `disable_web_page_preview` flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Indicates whether to disable link previews for links in the message.
+    ///
+    /// Other link preview options set before are kept.
+    #[deprecated(note = "use `with_link_preview_options` instead")]
+    pub fn with_disable_web_page_preview(mut self, value: bool) -> Self {
+        let options = self.link_preview_options.take().unwrap_or_default();
+        self.link_preview_options = Some(options.with_is_disabled(value));
+        self
+    }
+
     /// Sets a new parse mode.
     ///
     /// # Arguments
@@ -1017,7 +1112,58 @@ impl Method for EditMessageText {
     type Response = EditMessageResult;
 
     fn into_payload(self) -> Payload {
-        Payload::json("editMessageText", self)
+        Payload::json(MethodName::EditMessageText, self)
+    }
+}
+
+/// Executes an edit method treating the “message is not modified” error as success.
+///
+/// Telegram rejects an edit when the message already has the same content;
+/// the wrapper returns [`None`] instead of the error.
+/// Useful for refresh loops which edit a message regardless of changes.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run(client: tg::Client) -> Result<(), tg::ExecuteError> {
+/// use tg::{EditMessageText, NotModifiedAsSuccess};
+///
+/// let method = EditMessageText::for_chat_message(1, 2, "Status: running");
+/// if client.execute(NotModifiedAsSuccess::new(method)).await?.is_none() {
+///     // the message already has the same text
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct NotModifiedAsSuccess<M> {
+    method: M,
+}
+
+impl<M> NotModifiedAsSuccess<M>
+where
+    M: Method,
+{
+    /// Creates a new `NotModifiedAsSuccess`.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - An edit method, e.g. [`EditMessageText`] or [`EditMessageReplyMarkup`].
+    pub fn new(method: M) -> Self {
+        Self { method }
+    }
+}
+
+impl<M> Method for NotModifiedAsSuccess<M>
+where
+    M: Method,
+{
+    type Response = Option<M::Response>;
+
+    fn into_payload(self) -> Payload {
+        self.method
+            .into_payload()
+            .with_not_modified_as_success(true)
     }
 }
 
@@ -1096,7 +1242,7 @@ impl Method for ForwardMessage {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::json("forwardMessage", self)
+        Payload::json(MethodName::ForwardMessage, self)
     }
 }
 
@@ -1175,13 +1321,26 @@ impl ForwardMessages {
         self.protect_content = Some(value);
         self
     }
+
+    /// Splits the method into methods with at most [`MAX_BATCH_MESSAGE_IDS`] messages each.
+    ///
+    /// Message IDs are sorted and deduplicated.
+    pub fn into_chunks(mut self) -> Vec<Self> {
+        chunk_message_ids(std::mem::take(&mut self.message_ids))
+            .into_iter()
+            .map(|message_ids| Self {
+                message_ids,
+                ..self.clone()
+            })
+            .collect()
+    }
 }
 
 impl Method for ForwardMessages {
     type Response = Vec<MessageId>;
 
     fn into_payload(self) -> Payload {
-        Payload::json("forwardMessages", self)
+        Payload::json(MethodName::ForwardMessages, self)
     }
 }
 
@@ -1290,6 +1449,20 @@ impl SendMessage {
         self
     }
 
+    /// Sets a new value for a `disable_web_page_preview` flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Indicates whether to disable link previews for links in the message.
+    ///
+    /// Other link preview options set before are kept.
+    #[deprecated(note = "use `with_link_preview_options` instead")]
+    pub fn with_disable_web_page_preview(mut self, value: bool) -> Self {
+        let options = self.link_preview_options.take().unwrap_or_default();
+        self.link_preview_options = Some(options.with_is_disabled(value));
+        self
+    }
+
     /// Sets a new message effect ID.
     ///
     /// # Arguments
@@ -1366,7 +1539,7 @@ impl Method for SendMessage {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::json("sendMessage", self)
+        Payload::json(MethodName::SendMessage, self)
     }
 }
 
@@ -1376,11 +1549,11 @@ pub struct StopMessageLiveLocation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_connection_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<ChatId>,
+    chat_id: Option<ChatId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
+    inline_message_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<Integer>,
+    message_id: Option<Integer>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
@@ -1454,6 +1627,6 @@ impl Method for StopMessageLiveLocation {
     type Response = EditMessageResult;
 
     fn into_payload(self) -> Payload {
-        Payload::json("stopMessageLiveLocation", self)
+        Payload::json(MethodName::StopMessageLiveLocation, self)
     }
 }
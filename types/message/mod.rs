@@ -1,14 +1,23 @@
-pub use self::{command::*, data::*, methods::*, origin::*, quote::*, reply::*, sender::*};
-use crate::types::{Chat, InlineKeyboardMarkup, Integer, LinkPreviewOptions, Text, User};
+pub use self::{
+    cache::*, command::*, composer::*, data::*, gate::*, methods::*, origin::*, outgoing::*,
+    quote::*, relay::*, reply::*, sender::*, target::*,
+};
+use crate::types::{Chat, InlineKeyboardMarkup, Integer, LinkPreviewOptions, Story, Text, User};
 use serde::{Deserialize, Deserializer, Serialize};
 
+mod cache;
 mod command;
+mod composer;
 mod data;
+mod gate;
 mod methods;
 mod origin;
+mod outgoing;
 mod quote;
+mod relay;
 mod reply;
 mod sender;
+mod target;
 
 /// Represents a result of `EditMessage*` requests.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -97,7 +106,7 @@ pub struct Message {
     /// Information about the message that is being replied to, which may come from another chat or forum topic.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_reply: Option<ExternalReplyInfo>,
-    /// formation about the original message for forwarded messages.
+    /// Information about the original message for forwarded messages.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_origin: Option<MessageOrigin>,
     /// Indicates whether the message media is covered by a spoiler animation.
@@ -204,6 +213,37 @@ impl Message {
         self.edit_date.is_some()
     }
 
+    /// Returns information about the original message for forwarded messages.
+    pub fn get_origin(&self) -> Option<&MessageOrigin> {
+        self.forward_origin.as_ref()
+    }
+
+    /// Returns information about a replied message from another chat or forum topic.
+    pub fn get_external_reply(&self) -> Option<&ExternalReplyInfo> {
+        self.external_reply.as_ref()
+    }
+
+    /// Returns the original message for replies to messages.
+    pub fn get_reply_to_message(&self) -> Option<&Message> {
+        match self.reply_to {
+            Some(ReplyTo::Message(ref message)) => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Returns the original story for replies to stories.
+    pub fn get_reply_to_story(&self) -> Option<&Story> {
+        match self.reply_to {
+            Some(ReplyTo::Story(ref story)) => Some(story),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the message is a reply to a story and `false` otherwise.
+    pub fn is_story_reply(&self) -> bool {
+        matches!(self.reply_to, Some(ReplyTo::Story(_)))
+    }
+
     /// Returns a text of the message (includes caption).
     pub fn get_text(&self) -> Option<&Text> {
         match self.data {
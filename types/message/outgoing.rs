@@ -0,0 +1,439 @@
+use crate::{
+    api::{Method, Payload},
+    types::{
+        ChatId, Contact, DiceType, InputFile, InputPollOption, Integer, InvoiceParameters,
+        LabeledPrice, Location, MediaGroup, Message, SendAnimation, SendAudio, SendContact,
+        SendDice, SendDocument, SendInvoice, SendLocation, SendMediaGroup, SendMessage, SendPhoto,
+        SendPoll, SendQuiz, SendSticker, SendVenue, SendVideo, SendVideoNote, SendVoice, Text,
+        TextEntityError, Venue,
+    },
+};
+use serde::Deserialize;
+
+macro_rules! with_caption {
+    ($method:expr, $media:expr) => {{
+        let mut method = $method;
+        if let Some(caption) = $media.caption {
+            method = method.with_caption(caption.data);
+            if let Some(entities) = caption.entities {
+                method = method.with_caption_entities(entities)?;
+            }
+        }
+        method
+    }};
+}
+
+/// Represents a content of a message to send.
+///
+/// Use [`Self::send_to`] to get a method which sends the content to a chat,
+/// so code which sends arbitrary content doesn't need to match on its type.
+#[derive(Debug)]
+pub enum OutgoingContent {
+    /// An animation.
+    Animation(OutgoingMedia),
+    /// An audio.
+    Audio(OutgoingMedia),
+    /// A contact.
+    Contact(Contact),
+    /// A dice.
+    Dice(DiceType),
+    /// A document.
+    Document(OutgoingMedia),
+    /// An invoice.
+    Invoice(OutgoingInvoice),
+    /// A location.
+    Location(Location),
+    /// An album.
+    MediaGroup(MediaGroup),
+    /// A photo.
+    Photo(OutgoingMedia),
+    /// A poll or a quiz.
+    Poll(OutgoingPoll),
+    /// A sticker.
+    Sticker(InputFile),
+    /// A text.
+    Text(Text),
+    /// A venue.
+    Venue(Venue),
+    /// A video.
+    Video(OutgoingMedia),
+    /// A video note.
+    VideoNote(InputFile),
+    /// A voice message.
+    Voice(OutgoingMedia),
+}
+
+impl OutgoingContent {
+    /// Returns a method which sends the content.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the target chat.
+    pub fn send_to<T>(self, chat_id: T) -> Result<OutgoingMessage, TextEntityError>
+    where
+        T: Into<ChatId>,
+    {
+        let chat_id = chat_id.into();
+        Ok(match self {
+            Self::Animation(x) => OutgoingMessage::Animation(with_caption!(
+                SendAnimation::new(x.file, chat_id).with_has_spoiler(x.has_spoiler),
+                x
+            )),
+            Self::Audio(x) => {
+                OutgoingMessage::Audio(with_caption!(SendAudio::new(chat_id, x.file), x))
+            }
+            Self::Contact(x) => {
+                let mut method = SendContact::new(chat_id, x.first_name, x.phone_number);
+                if let Some(value) = x.last_name {
+                    method = method.with_last_name(value);
+                }
+                if let Some(value) = x.vcard {
+                    method = method.with_vcard(value);
+                }
+                OutgoingMessage::Contact(method)
+            }
+            Self::Dice(x) => OutgoingMessage::Dice(SendDice::new(chat_id, x)),
+            Self::Document(x) => {
+                OutgoingMessage::Document(with_caption!(SendDocument::new(chat_id, x.file), x))
+            }
+            Self::Invoice(x) => {
+                let mut method = SendInvoice::new(
+                    chat_id,
+                    x.title,
+                    x.description,
+                    x.payload,
+                    x.currency,
+                    x.prices,
+                );
+                if let Some(value) = x.parameters {
+                    method = method.with_parameters(value);
+                }
+                OutgoingMessage::Invoice(method)
+            }
+            Self::Location(x) => {
+                let mut method = SendLocation::new(chat_id, x.latitude, x.longitude);
+                if let Some(value) = x.horizontal_accuracy {
+                    method = method.with_horizontal_accuracy(value);
+                }
+                OutgoingMessage::Location(method)
+            }
+            Self::MediaGroup(x) => OutgoingMessage::MediaGroup(SendMediaGroup::new(chat_id, x)),
+            Self::Photo(x) => OutgoingMessage::Photo(with_caption!(
+                SendPhoto::new(chat_id, x.file).with_has_spoiler(x.has_spoiler),
+                x
+            )),
+            Self::Poll(x) => match x.correct_option_id {
+                Some(correct_option_id) => {
+                    let mut method =
+                        SendQuiz::new(chat_id, x.question.data, correct_option_id, x.options);
+                    if let Some(entities) = x.question.entities {
+                        method = method.with_question_entities(entities);
+                    }
+                    if let Some(value) = x.is_anonymous {
+                        method = method.with_is_anonymous(value);
+                    }
+                    OutgoingMessage::Quiz(method)
+                }
+                None => {
+                    let mut method = SendPoll::new(chat_id, x.question.data, x.options)
+                        .with_allows_multiple_answers(x.allows_multiple_answers);
+                    if let Some(entities) = x.question.entities {
+                        method = method.with_question_entities(entities);
+                    }
+                    if let Some(value) = x.is_anonymous {
+                        method = method.with_is_anonymous(value);
+                    }
+                    OutgoingMessage::Poll(method)
+                }
+            },
+            Self::Sticker(x) => OutgoingMessage::Sticker(SendSticker::new(chat_id, x)),
+            Self::Text(x) => {
+                let mut method = SendMessage::new(chat_id, x.data);
+                if let Some(entities) = x.entities {
+                    method = method.with_entities(entities);
+                }
+                OutgoingMessage::Text(method)
+            }
+            Self::Venue(x) => {
+                let mut method = SendVenue::new(
+                    chat_id,
+                    x.location.latitude,
+                    x.location.longitude,
+                    x.title,
+                    x.address,
+                );
+                if let Some(value) = x.foursquare_id {
+                    method = method.with_foursquare_id(value);
+                }
+                if let Some(value) = x.foursquare_type {
+                    method = method.with_foursquare_type(value);
+                }
+                if let Some(value) = x.google_place_id {
+                    method = method.with_google_place_id(value);
+                }
+                if let Some(value) = x.google_place_type {
+                    method = method.with_google_place_type(value);
+                }
+                OutgoingMessage::Venue(method)
+            }
+            Self::Video(x) => OutgoingMessage::Video(with_caption!(
+                SendVideo::new(chat_id, x.file).with_has_spoiler(x.has_spoiler),
+                x
+            )),
+            Self::VideoNote(x) => OutgoingMessage::VideoNote(SendVideoNote::new(chat_id, x)),
+            Self::Voice(x) => {
+                OutgoingMessage::Voice(with_caption!(SendVoice::new(chat_id, x.file), x))
+            }
+        })
+    }
+}
+
+/// Represents a media file to send.
+#[derive(Debug)]
+pub struct OutgoingMedia {
+    caption: Option<Text>,
+    file: InputFile,
+    has_spoiler: bool,
+}
+
+impl OutgoingMedia {
+    /// Creates a new `OutgoingMedia`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The file to send.
+    pub fn new<T>(file: T) -> Self
+    where
+        T: Into<InputFile>,
+    {
+        Self {
+            caption: None,
+            file: file.into(),
+            has_spoiler: false,
+        }
+    }
+
+    /// Sets a new caption.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Caption with entities; 0-1024 characters.
+    pub fn with_caption<T>(mut self, value: T) -> Self
+    where
+        T: Into<Text>,
+    {
+        self.caption = Some(value.into());
+        self
+    }
+
+    /// Sets a new value for the `has_spoiler` flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Indicates whether the media must be covered with a spoiler animation;
+    ///   ignored for audios, documents and voice messages.
+    pub fn with_has_spoiler(mut self, value: bool) -> Self {
+        self.has_spoiler = value;
+        self
+    }
+}
+
+/// Represents an invoice to send.
+#[derive(Clone, Debug)]
+pub struct OutgoingInvoice {
+    currency: String,
+    description: String,
+    parameters: Option<InvoiceParameters>,
+    payload: String,
+    prices: Vec<LabeledPrice>,
+    title: String,
+}
+
+impl OutgoingInvoice {
+    /// Creates a new `OutgoingInvoice`.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Product name; 1-32 characters.
+    /// * `description` - Product description; 1-255 characters.
+    /// * `payload` - Bot-defined invoice payload; 1-128 bytes.
+    /// * `currency` - Three-letter ISO 4217 currency code.
+    /// * `prices` - Price breakdown.
+    pub fn new<A, B, C, D, E>(title: A, description: B, payload: C, currency: D, prices: E) -> Self
+    where
+        A: Into<String>,
+        B: Into<String>,
+        C: Into<String>,
+        D: Into<String>,
+        E: IntoIterator<Item = LabeledPrice>,
+    {
+        Self {
+            currency: currency.into(),
+            description: description.into(),
+            parameters: None,
+            payload: payload.into(),
+            prices: prices.into_iter().collect(),
+            title: title.into(),
+        }
+    }
+
+    /// Sets a new invoice parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Invoice parameters.
+    pub fn with_parameters(mut self, value: InvoiceParameters) -> Self {
+        self.parameters = Some(value);
+        self
+    }
+}
+
+/// Represents a poll to send.
+#[derive(Clone, Debug)]
+pub struct OutgoingPoll {
+    allows_multiple_answers: bool,
+    correct_option_id: Option<Integer>,
+    is_anonymous: Option<bool>,
+    options: Vec<InputPollOption>,
+    question: Text,
+}
+
+impl OutgoingPoll {
+    /// Creates a new `OutgoingPoll`.
+    ///
+    /// # Arguments
+    ///
+    /// * `question` - Question with entities; 1-300 characters.
+    /// * `options` - Answer options; 2-10 items.
+    pub fn new<A, B, C>(question: A, options: B) -> Self
+    where
+        A: Into<Text>,
+        B: IntoIterator<Item = C>,
+        C: Into<InputPollOption>,
+    {
+        Self {
+            allows_multiple_answers: false,
+            correct_option_id: None,
+            is_anonymous: None,
+            options: options.into_iter().map(Into::into).collect(),
+            question: question.into(),
+        }
+    }
+
+    /// Sets a new value for the `allows_multiple_answers` flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Indicates whether the poll allows multiple answers;
+    ///   ignored for quizzes.
+    pub fn with_allows_multiple_answers(mut self, value: bool) -> Self {
+        self.allows_multiple_answers = value;
+        self
+    }
+
+    /// Sets a new correct option ID and turns the poll into a quiz.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - 0-based identifier of the correct answer option.
+    pub fn with_correct_option_id(mut self, value: Integer) -> Self {
+        self.correct_option_id = Some(value);
+        self
+    }
+
+    /// Sets a new value for the `is_anonymous` flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Indicates whether the poll needs to be anonymous; default - `true`.
+    pub fn with_is_anonymous(mut self, value: bool) -> Self {
+        self.is_anonymous = Some(value);
+        self
+    }
+}
+
+/// Represents a method created by [`OutgoingContent::send_to`].
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum OutgoingMessage {
+    /// Sends an animation.
+    Animation(SendAnimation),
+    /// Sends an audio.
+    Audio(SendAudio),
+    /// Sends a contact.
+    Contact(SendContact),
+    /// Sends a dice.
+    Dice(SendDice),
+    /// Sends a document.
+    Document(SendDocument),
+    /// Sends an invoice.
+    Invoice(SendInvoice),
+    /// Sends a location.
+    Location(SendLocation),
+    /// Sends an album.
+    MediaGroup(SendMediaGroup),
+    /// Sends a photo.
+    Photo(SendPhoto),
+    /// Sends a poll.
+    Poll(SendPoll),
+    /// Sends a quiz.
+    Quiz(SendQuiz),
+    /// Sends a sticker.
+    Sticker(SendSticker),
+    /// Sends a text.
+    Text(SendMessage),
+    /// Sends a venue.
+    Venue(SendVenue),
+    /// Sends a video.
+    Video(SendVideo),
+    /// Sends a video note.
+    VideoNote(SendVideoNote),
+    /// Sends a voice message.
+    Voice(SendVoice),
+}
+
+impl Method for OutgoingMessage {
+    type Response = SentContent;
+
+    fn into_payload(self) -> Payload {
+        match self {
+            Self::Animation(x) => x.into_payload(),
+            Self::Audio(x) => x.into_payload(),
+            Self::Contact(x) => x.into_payload(),
+            Self::Dice(x) => x.into_payload(),
+            Self::Document(x) => x.into_payload(),
+            Self::Invoice(x) => x.into_payload(),
+            Self::Location(x) => x.into_payload(),
+            Self::MediaGroup(x) => x.into_payload(),
+            Self::Photo(x) => x.into_payload(),
+            Self::Poll(x) => x.into_payload(),
+            Self::Quiz(x) => x.into_payload(),
+            Self::Sticker(x) => x.into_payload(),
+            Self::Text(x) => x.into_payload(),
+            Self::Venue(x) => x.into_payload(),
+            Self::Video(x) => x.into_payload(),
+            Self::VideoNote(x) => x.into_payload(),
+            Self::Voice(x) => x.into_payload(),
+        }
+    }
+}
+
+/// Represents a result of [`OutgoingMessage`].
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum SentContent {
+    /// A sent message.
+    Message(Box<Message>),
+    /// Sent messages of an album.
+    MediaGroup(Vec<Message>),
+}
+
+impl SentContent {
+    /// Returns all sent messages.
+    pub fn into_messages(self) -> Vec<Message> {
+        match self {
+            Self::Message(x) => vec![*x],
+            Self::MediaGroup(x) => x,
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use crate::types::{
+    InputFile, InputMediaAudio, InputMediaDocument, InputMediaPhoto, InputMediaVideo, MediaGroup,
+    MediaGroupError, MediaGroupItem, Message, MessageData, OutgoingContent, OutgoingMedia,
+    OutgoingPoll, PhotoSize, Poll, Text, TextEntity,
+};
+use std::ops::Range;
+
+const LINK_PREFIXES: [&str; 2] = ["t.me/", "telegram.me/"];
+
+macro_rules! with_caption {
+    ($relay:expr, $media:expr, $caption:expr) => {{
+        let mut media = $media;
+        if let Some(ref caption) = $caption.as_ref().map(|x| $relay.anonymize(x)) {
+            media = media.with_caption(caption.data.as_str());
+            if let Some(ref entities) = caption.entities {
+                media = media.with_caption_entities(entities.into_iter().cloned());
+            }
+        }
+        media
+    }};
+}
+
+/// Converts incoming messages into content to send to another chat.
+///
+/// Files are sent by their IDs, so they are not downloaded and uploaded again;
+/// captions, entities and spoilers are preserved.
+///
+/// Content is always sent as a copy, so forward origins and author signatures are not preserved.
+///
+/// Use [`Self::convert_album`] for messages with the same `media_group_id`.
+#[derive(Clone, Debug, Default)]
+pub struct Relay {
+    destination_username: Option<String>,
+    source_username: Option<String>,
+}
+
+impl Relay {
+    /// Creates a new `Relay`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables the anonymized mode.
+    ///
+    /// Mentions of the source chat are removed from texts and captions.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Username of the source chat without leading `@`.
+    pub fn with_source_username<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.source_username = Some(value.into());
+        self
+    }
+
+    /// Sets a new username of the destination chat.
+    ///
+    /// In the anonymized mode, `t.me` links to the source chat are rewritten to point to this chat;
+    /// otherwise links are kept as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Username of the destination chat without leading `@`.
+    pub fn with_destination_username<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.destination_username = Some(value.into());
+        self
+    }
+
+    /// Returns content equivalent to the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - An incoming message.
+    ///
+    /// Returns [`None`] for service messages and content which can't be sent by bots,
+    /// e.g. games, invoices, stories or paid media.
+    /// A dice is sent as a new dice with a random value.
+    pub fn convert(&self, message: &Message) -> Option<OutgoingContent> {
+        let has_spoiler = message.has_media_spoiler.unwrap_or(false);
+        let media = |file_id: &str, caption: Option<&Text>| {
+            let media = OutgoingMedia::new(InputFile::file_id(file_id));
+            match caption {
+                Some(caption) => media.with_caption(self.anonymize(caption)),
+                None => media,
+            }
+            .with_has_spoiler(has_spoiler)
+        };
+        Some(match message.data {
+            MessageData::Animation(ref x) => OutgoingContent::Animation(media(&x.file_id, None)),
+            MessageData::Audio(ref x) => {
+                OutgoingContent::Audio(media(&x.data.file_id, x.caption.as_ref()))
+            }
+            MessageData::Contact(ref x) => OutgoingContent::Contact(x.clone()),
+            MessageData::Dice(ref x) => OutgoingContent::Dice(x.dice_type()),
+            MessageData::Document(ref x) => {
+                OutgoingContent::Document(media(&x.data.file_id, x.caption.as_ref()))
+            }
+            MessageData::Location(ref x) => OutgoingContent::Location(*x),
+            MessageData::Photo(ref x) => {
+                let photo = get_largest_photo(&x.data)?;
+                OutgoingContent::Photo(media(&photo.file_id, x.caption.as_ref()))
+            }
+            MessageData::Poll(ref x) => OutgoingContent::Poll(match x {
+                Poll::Regular(x) => {
+                    OutgoingPoll::new(x.question.clone(), x.options.iter().map(|x| x.text.clone()))
+                        .with_allows_multiple_answers(x.allows_multiple_answers)
+                        .with_is_anonymous(x.is_anonymous)
+                }
+                Poll::Quiz(x) => {
+                    OutgoingPoll::new(x.question.clone(), x.options.iter().map(|x| x.text.clone()))
+                        .with_correct_option_id(x.correct_option_id)
+                        .with_is_anonymous(x.is_anonymous)
+                }
+            }),
+            MessageData::Sticker(ref x) => {
+                OutgoingContent::Sticker(InputFile::file_id(x.file_id.as_str()))
+            }
+            MessageData::Text(ref x) => OutgoingContent::Text(self.anonymize(x)),
+            MessageData::Venue(ref x) => OutgoingContent::Venue(x.clone()),
+            MessageData::Video(ref x) => {
+                OutgoingContent::Video(media(&x.data.file_id, x.caption.as_ref()))
+            }
+            MessageData::VideoNote(ref x) => {
+                OutgoingContent::VideoNote(InputFile::file_id(x.file_id.as_str()))
+            }
+            MessageData::Voice(ref x) => {
+                OutgoingContent::Voice(media(&x.data.file_id, x.caption.as_ref()))
+            }
+            _ => return None,
+        })
+    }
+
+    /// Returns an album equivalent to the messages.
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - Messages of an album in the order they were received.
+    ///
+    /// Messages which can't be a part of an album are skipped.
+    pub fn convert_album<'a, I>(&self, messages: I) -> Result<OutgoingContent, MediaGroupError>
+    where
+        I: IntoIterator<Item = &'a Message>,
+    {
+        let items = messages.into_iter().filter_map(|message| {
+            let has_spoiler = message.has_media_spoiler.unwrap_or(false);
+            Some(match message.data {
+                MessageData::Audio(ref x) => MediaGroupItem::for_audio(
+                    InputFile::file_id(x.data.file_id.as_str()),
+                    with_caption!(self, InputMediaAudio::default(), &x.caption),
+                ),
+                MessageData::Document(ref x) => MediaGroupItem::for_document(
+                    InputFile::file_id(x.data.file_id.as_str()),
+                    with_caption!(self, InputMediaDocument::default(), &x.caption),
+                ),
+                MessageData::Photo(ref x) => MediaGroupItem::for_photo(
+                    InputFile::file_id(get_largest_photo(&x.data)?.file_id.as_str()),
+                    with_caption!(
+                        self,
+                        InputMediaPhoto::default().with_has_spoiler(has_spoiler),
+                        &x.caption
+                    ),
+                ),
+                MessageData::Video(ref x) => MediaGroupItem::for_video(
+                    InputFile::file_id(x.data.file_id.as_str()),
+                    with_caption!(
+                        self,
+                        InputMediaVideo::default().with_has_spoiler(has_spoiler),
+                        &x.caption
+                    ),
+                ),
+                _ => return None,
+            })
+        });
+        MediaGroup::new(items).map(OutgoingContent::MediaGroup)
+    }
+
+    fn anonymize(&self, text: &Text) -> Text {
+        let mut result = text.clone();
+        let source = match self.source_username {
+            Some(ref source) => source.as_str(),
+            None => return result,
+        };
+        let units: Vec<u16> = text.data.encode_utf16().collect();
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for entity in text.entities.iter().flatten() {
+            if let TextEntity::Mention(position) = entity {
+                let start = position.offset as usize;
+                let end = (start + position.length as usize).min(units.len());
+                let content = String::from_utf16_lossy(&units[start.min(end)..end]);
+                if content
+                    .strip_prefix('@')
+                    .is_some_and(|x| x.eq_ignore_ascii_case(source))
+                {
+                    ranges.push(start..end);
+                }
+            }
+        }
+        ranges.sort_by_key(|x| x.start);
+        for range in ranges.into_iter().rev() {
+            result.replace_range(range, "");
+        }
+        if let Some(ref destination) = self.destination_username {
+            for range in find_links(&result.data, source).into_iter().rev() {
+                let start = result.data[..range.start].encode_utf16().count();
+                let end = start + result.data[range].encode_utf16().count();
+                result.replace_range(start..end, destination);
+            }
+            for entity in result.entities.iter_mut().flatten() {
+                if let TextEntity::TextLink { url, .. } = entity {
+                    for range in find_links(url, source).into_iter().rev() {
+                        url.replace_range(range, destination);
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Returns byte ranges of the username in `t.me` links to a chat.
+fn find_links(text: &str, username: &str) -> Vec<Range<usize>> {
+    let lowercase = text.to_ascii_lowercase();
+    let username = username.to_ascii_lowercase();
+    let mut result = Vec::new();
+    for prefix in LINK_PREFIXES {
+        let pattern = format!("{}{}", prefix, username);
+        for (idx, _) in lowercase.match_indices(pattern.as_str()) {
+            let (start, end) = (idx + prefix.len(), idx + pattern.len());
+            let is_host_start =
+                !lowercase[..idx].ends_with(|x: char| x.is_ascii_alphanumeric() || x == '.');
+            let is_username_end =
+                !lowercase[end..].starts_with(|x: char| x.is_ascii_alphanumeric() || x == '_');
+            if is_host_start && is_username_end {
+                result.push(start..end);
+            }
+        }
+    }
+    result.sort_by_key(|x| x.start);
+    result
+}
+
+fn get_largest_photo(sizes: &[PhotoSize]) -> Option<&PhotoSize> {
+    sizes.iter().max_by_key(|x| x.width * x.height)
+}
@@ -0,0 +1,240 @@
+use crate::types::{
+    CallbackQuery, ChatId, EditMessageCaption, EditMessageLiveLocation, EditMessageMedia,
+    EditMessageReplyMarkup, EditMessageText, Float, GetGameHighScores, InputMedia, Integer,
+    MaybeInaccessibleMessage, Message, SetGameScore, StopMessageLiveLocation,
+};
+
+/// Represents a message to edit.
+///
+/// Messages sent by the bot are identified by a chat and a message ID;
+/// messages sent via the bot in inline mode are identified by an inline message ID.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MessageTarget {
+    /// A message in a chat.
+    Chat {
+        /// Unique identifier of the chat.
+        chat_id: ChatId,
+        /// Identifier of the message.
+        message_id: Integer,
+    },
+    /// A message sent in inline mode.
+    Inline(String),
+}
+
+impl MessageTarget {
+    /// Creates a new `MessageTarget` for a message in a chat.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the chat.
+    /// * `message_id` - Identifier of the message.
+    pub fn chat<T>(chat_id: T, message_id: Integer) -> Self
+    where
+        T: Into<ChatId>,
+    {
+        Self::Chat {
+            chat_id: chat_id.into(),
+            message_id,
+        }
+    }
+
+    /// Creates a new `MessageTarget` for a message sent in inline mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `inline_message_id` - Identifier of the inline message.
+    pub fn inline<T>(inline_message_id: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::Inline(inline_message_id.into())
+    }
+}
+
+impl From<&Message> for MessageTarget {
+    fn from(value: &Message) -> Self {
+        Self::chat(value.chat.get_id(), value.id)
+    }
+}
+
+impl CallbackQuery {
+    /// Returns a message with the button that originated the query.
+    ///
+    /// Returns [`None`] if the query contains neither a message nor an inline message ID.
+    pub fn get_message_target(&self) -> Option<MessageTarget> {
+        match (&self.message, &self.inline_message_id) {
+            (Some(MaybeInaccessibleMessage::Message(message)), _) => {
+                Some(MessageTarget::from(message.as_ref()))
+            }
+            (Some(MaybeInaccessibleMessage::InaccessibleMessage(message)), _) => Some(
+                MessageTarget::chat(message.chat.get_id(), message.message_id),
+            ),
+            (None, Some(inline_message_id)) => Some(MessageTarget::inline(inline_message_id)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl EditMessageCaption {
+    /// Creates a new `EditMessageCaption` for a chat or an inline message.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The message to edit.
+    pub fn for_target(target: MessageTarget) -> Self {
+        match target {
+            MessageTarget::Chat {
+                chat_id,
+                message_id,
+            } => Self::for_chat_message(chat_id, message_id),
+            MessageTarget::Inline(inline_message_id) => Self::for_inline_message(inline_message_id),
+        }
+    }
+}
+
+impl EditMessageLiveLocation {
+    /// Creates a new `EditMessageLiveLocation` for a chat or an inline message.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The message to edit.
+    /// * `latitude` - Latitude of new location.
+    /// * `longitude` - Longitude of new location.
+    pub fn for_target(target: MessageTarget, latitude: Float, longitude: Float) -> Self {
+        match target {
+            MessageTarget::Chat {
+                chat_id,
+                message_id,
+            } => Self::for_chat_message(chat_id, message_id, latitude, longitude),
+            MessageTarget::Inline(inline_message_id) => {
+                Self::for_inline_message(inline_message_id, latitude, longitude)
+            }
+        }
+    }
+}
+
+impl EditMessageMedia {
+    /// Creates a new `EditMessageMedia` for a chat or an inline message.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The message to edit.
+    /// * `media` - New media content of the message.
+    pub fn for_target(target: MessageTarget, media: InputMedia) -> Self {
+        match target {
+            MessageTarget::Chat {
+                chat_id,
+                message_id,
+            } => Self::for_chat_message(chat_id, message_id, media),
+            MessageTarget::Inline(inline_message_id) => {
+                Self::for_inline_message(inline_message_id, media)
+            }
+        }
+    }
+}
+
+impl EditMessageReplyMarkup {
+    /// Creates a new `EditMessageReplyMarkup` for a chat or an inline message.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The message to edit.
+    pub fn for_target(target: MessageTarget) -> Self {
+        match target {
+            MessageTarget::Chat {
+                chat_id,
+                message_id,
+            } => Self::for_chat_message(chat_id, message_id),
+            MessageTarget::Inline(inline_message_id) => Self::for_inline_message(inline_message_id),
+        }
+    }
+}
+
+impl EditMessageText {
+    /// Creates a new `EditMessageText` for a chat or an inline message.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The message to edit.
+    /// * `text` - New text of the message.
+    pub fn for_target<T>(target: MessageTarget, text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        match target {
+            MessageTarget::Chat {
+                chat_id,
+                message_id,
+            } => Self::for_chat_message(chat_id, message_id, text),
+            MessageTarget::Inline(inline_message_id) => {
+                Self::for_inline_message(inline_message_id, text)
+            }
+        }
+    }
+}
+
+impl GetGameHighScores {
+    /// Creates a new `GetGameHighScores` for a chat or an inline message.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - Target user ID.
+    /// * `target` - The message with the game.
+    ///
+    /// Returns [`None`] if the chat is specified by a username,
+    /// since games require a chat ID.
+    pub fn for_target(user_id: Integer, target: MessageTarget) -> Option<Self> {
+        Some(match target {
+            MessageTarget::Chat {
+                chat_id: ChatId::Id(chat_id),
+                message_id,
+            } => Self::for_chat_message(user_id, chat_id.into(), message_id),
+            MessageTarget::Chat { .. } => return None,
+            MessageTarget::Inline(inline_message_id) => {
+                Self::for_inline_message(user_id, inline_message_id)
+            }
+        })
+    }
+}
+
+impl SetGameScore {
+    /// Creates a new `SetGameScore` for a chat or an inline message.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The message with the game.
+    /// * `user_id` - User identifier.
+    /// * `score` - New score, must be non-negative.
+    ///
+    /// Returns [`None`] if the chat is specified by a username,
+    /// since games require a chat ID.
+    pub fn for_target(target: MessageTarget, user_id: Integer, score: Integer) -> Option<Self> {
+        Some(match target {
+            MessageTarget::Chat {
+                chat_id: ChatId::Id(chat_id),
+                message_id,
+            } => Self::for_chat_message(chat_id.into(), message_id, user_id, score),
+            MessageTarget::Chat { .. } => return None,
+            MessageTarget::Inline(inline_message_id) => {
+                Self::for_inline_message(inline_message_id, user_id, score)
+            }
+        })
+    }
+}
+
+impl StopMessageLiveLocation {
+    /// Creates a new `StopMessageLiveLocation` for a chat or an inline message.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The message with the live location.
+    pub fn for_target(target: MessageTarget) -> Self {
+        match target {
+            MessageTarget::Chat {
+                chat_id,
+                message_id,
+            } => Self::for_chat_message(chat_id, message_id),
+            MessageTarget::Inline(inline_message_id) => Self::for_inline_message(inline_message_id),
+        }
+    }
+}
@@ -7,15 +7,20 @@ pub use self::{
     callback::*,
     chat::*,
     color::*,
+    consent::*,
     contact::*,
+    deduplicator::*,
     dice::*,
     file::*,
     forum::*,
     game::*,
+    gift::*,
     giveaway::*,
     inline_mode::*,
+    limits::*,
     link::*,
     location::*,
+    maintenance::*,
     media::*,
     menu::*,
     message::*,
@@ -23,6 +28,7 @@ pub use self::{
     payment::*,
     poll::*,
     primitive::*,
+    privacy::*,
     reaction::*,
     reply::*,
     response::*,
@@ -36,21 +42,42 @@ pub use self::{
     webhook::*,
 };
 
+#[cfg(feature = "gateway")]
+pub use self::gateway::*;
+#[cfg(feature = "web-app")]
+pub use self::init_data::*;
+#[cfg(feature = "login-widget")]
+pub use self::login_widget::*;
+
 mod background;
 mod bot;
 mod business;
 mod callback;
 mod chat;
 mod color;
+mod command_registry;
+mod consent;
 mod contact;
+mod deduplicator;
 mod dice;
 mod file;
 mod forum;
 mod game;
+#[cfg(feature = "gateway")]
+mod gateway;
+mod gift;
 mod giveaway;
+#[cfg(any(feature = "gateway", feature = "login-widget", feature = "web-app"))]
+mod hex;
 mod inline_mode;
+#[cfg(feature = "web-app")]
+mod init_data;
+mod limits;
 mod link;
 mod location;
+#[cfg(feature = "login-widget")]
+mod login_widget;
+mod maintenance;
 mod media;
 mod menu;
 mod message;
@@ -58,6 +85,7 @@ mod passport;
 mod payment;
 mod poll;
 mod primitive;
+mod privacy;
 mod reaction;
 mod reply;
 mod response;
@@ -1,7 +1,7 @@
 use std::{error::Error, fmt};
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{EncryptedPassportElementType, Integer},
 };
 
@@ -510,6 +510,6 @@ impl Method for SetPassportDataErrors {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setPassportDataErrors", self)
+        Payload::json(MethodName::SetPassportDataErrors, self)
     }
 }
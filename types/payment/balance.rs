@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    api::{Method, MethodName, Payload},
+    types::Integer,
+};
+
+/// Describes an amount of Telegram Stars.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct StarAmount {
+    /// Integer amount of Telegram Stars, rounded to 0; can be negative.
+    pub amount: Integer,
+    /// The number of 1/1000000000 shares of Telegram Stars; from -999999999 to 999999999;
+    /// can be negative if and only if `amount` is non-positive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nanostar_amount: Option<Integer>,
+}
+
+impl StarAmount {
+    /// Creates a new `StarAmount`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Integer amount of Telegram Stars.
+    pub fn new(amount: Integer) -> Self {
+        Self {
+            amount,
+            nanostar_amount: None,
+        }
+    }
+
+    /// Sets a new number of nanostars.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The number of 1/1000000000 shares of Telegram Stars.
+    pub fn with_nanostar_amount(mut self, value: Integer) -> Self {
+        self.nanostar_amount = Some(value);
+        self
+    }
+}
+
+/// Returns the current Telegram Stars balance of the bot.
+#[derive(Clone, Copy, Debug)]
+pub struct GetBotStarBalance;
+
+impl Method for GetBotStarBalance {
+    type Response = StarAmount;
+
+    fn into_payload(self) -> Payload {
+        Payload::empty(MethodName::GetMyStarBalance)
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{Integer, OrderInfo, User},
 };
 
@@ -145,6 +146,44 @@ impl Method for AnswerPreCheckoutQuery {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("answerPreCheckoutQuery", self)
+        Payload::json(MethodName::AnswerPreCheckoutQuery, self)
+    }
+}
+
+/// Represents a reason to reject a pre-checkout or a shipping query.
+///
+/// Can be passed as a message to [`AnswerPreCheckoutQuery::error`]
+/// and [`crate::types::AnswerShippingQuery::error`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PaymentRejection {
+    /// The goods are out of stock.
+    OutOfStock,
+    /// The price has changed since the invoice was sent.
+    PriceChanged,
+    /// Delivery to the shipping address is not available.
+    ShippingUnavailable,
+    /// A custom reason in human readable form.
+    Other(String),
+}
+
+impl fmt::Display for PaymentRejection {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::OutOfStock => write!(out, "Sorry, the goods are out of stock."),
+            Self::PriceChanged => write!(
+                out,
+                "Sorry, the price has changed, please request a new invoice."
+            ),
+            Self::ShippingUnavailable => {
+                write!(out, "Sorry, delivery to your address is not available.")
+            }
+            Self::Other(message) => out.write_str(message),
+        }
+    }
+}
+
+impl From<PaymentRejection> for String {
+    fn from(value: PaymentRejection) -> Self {
+        value.to_string()
     }
 }
@@ -1,9 +1,26 @@
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{ChatId, InlineKeyboardMarkup, Integer, Message, ReplyParameters},
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Error as JsonError;
+use std::{error::Error, fmt};
+
+const STARS_CURRENCY: &str = "XTR";
+
+/// Currencies supported by Telegram Payments; three-letter ISO 4217 codes.
+///
+/// See [currencies.json](https://core.telegram.org/bots/payments/currencies.json)
+/// for minimum and maximum amounts of each currency.
+pub const SUPPORTED_CURRENCIES: [&str; 87] = [
+    "AED", "AFN", "ALL", "AMD", "ARS", "AUD", "AZN", "BAM", "BDT", "BGN", "BND", "BOB", "BRL",
+    "BYN", "CAD", "CHF", "CLP", "CNY", "COP", "CRC", "CZK", "DKK", "DOP", "DZD", "EGP", "ETB",
+    "EUR", "GBP", "GEL", "GTQ", "HKD", "HNL", "HRK", "HUF", "IDR", "ILS", "INR", "ISK", "JMD",
+    "JPY", "KES", "KGS", "KRW", "KZT", "LBP", "LKR", "MAD", "MDL", "MNT", "MUR", "MVR", "MXN",
+    "MYR", "MZN", "NGN", "NIO", "NOK", "NPR", "NZD", "PAB", "PEN", "PHP", "PKR", "PLN", "PYG",
+    "QAR", "RON", "RSD", "RUB", "SAR", "SEK", "SGD", "THB", "TJS", "TRY", "TTD", "TWD", "TZS",
+    "UAH", "UGX", "USD", "UYU", "UZS", "VND", "YER", "ZAR", "XTR",
+];
 
 /// Represents an invoice.
 #[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize)]
@@ -285,7 +302,8 @@ impl InvoiceParameters {
     /// # Arguments
     ///
     /// * `value` - Payment provider token, obtained via @BotFather.
-    ///             Pass an empty string for payments in Telegram Stars.
+    ///
+    /// Omit the token or pass an empty string for payments in Telegram Stars.
     pub fn with_provider_token<T>(mut self, value: T) -> Self
     where
         T: Into<String>,
@@ -373,6 +391,29 @@ impl CreateInvoiceLink {
         }
     }
 
+    /// Creates a new `CreateInvoiceLink` for a payment in Telegram Stars.
+    ///
+    /// The currency is set to `XTR` and the price contains a single component,
+    /// as required for payments in Telegram Stars.
+    /// Provider token must be omitted for such invoices.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Product name; 1-32 characters.
+    /// * `description` - Product description; 1-255 characters.
+    /// * `payload` - Bot-defined invoice payload; 1-128 bytes.
+    /// * `amount` - Price of the product in Telegram Stars.
+    pub fn stars<A, B, C>(title: A, description: B, payload: C, amount: Integer) -> Self
+    where
+        A: Into<String>,
+        B: Into<String>,
+        C: Into<String>,
+    {
+        let title = title.into();
+        let price = LabeledPrice::new(amount, title.clone());
+        Self::new(title, description, payload, STARS_CURRENCY, [price])
+    }
+
     /// Sets a new invoice parameters.
     ///
     /// # Arguments
@@ -384,11 +425,21 @@ impl CreateInvoiceLink {
     }
 }
 
+impl CreateInvoiceLink {
+    /// Checks the price breakdown against constraints of the Bot API.
+    ///
+    /// Checks the currency, the number of price components and the total amount;
+    /// minimum and maximum amounts of the currency are not checked.
+    pub fn validate(&self) -> Result<(), InvoiceError> {
+        validate_prices(&self.currency, &self.prices)
+    }
+}
+
 impl Method for CreateInvoiceLink {
     type Response = String;
 
     fn into_payload(self) -> Payload {
-        Payload::json("createInvoiceLink", self)
+        Payload::json(MethodName::CreateInvoiceLink, self)
     }
 }
 
@@ -467,6 +518,37 @@ impl SendInvoice {
         }
     }
 
+    /// Creates a new `SendInvoice` for a payment in Telegram Stars.
+    ///
+    /// The currency is set to `XTR` and the price contains a single component,
+    /// as required for payments in Telegram Stars.
+    /// Provider token must be omitted for such invoices.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the target chat.
+    /// * `title` - Product name; 1-32 characters.
+    /// * `description` - Product description; 1-255 characters.
+    /// * `payload` - Bot-defined invoice payload; 1-128 bytes.
+    /// * `amount` - Price of the product in Telegram Stars.
+    pub fn stars<A, B, C, D>(
+        chat_id: A,
+        title: B,
+        description: C,
+        payload: D,
+        amount: Integer,
+    ) -> Self
+    where
+        A: Into<ChatId>,
+        B: Into<String>,
+        C: Into<String>,
+        D: Into<String>,
+    {
+        let title = title.into();
+        let price = LabeledPrice::new(amount, title.clone());
+        Self::new(chat_id, title, description, payload, STARS_CURRENCY, [price])
+    }
+
     /// Sets a new value for a `disable_notification` flag.
     ///
     /// # Arguments
@@ -569,10 +651,72 @@ impl SendInvoice {
     }
 }
 
+impl SendInvoice {
+    /// Checks the price breakdown against constraints of the Bot API.
+    ///
+    /// Checks the currency, the number of price components and the total amount;
+    /// minimum and maximum amounts of the currency are not checked.
+    pub fn validate(&self) -> Result<(), InvoiceError> {
+        validate_prices(&self.currency, &self.prices)
+    }
+}
+
 impl Method for SendInvoice {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::json("sendInvoice", self)
+        Payload::json(MethodName::SendInvoice, self)
+    }
+}
+
+/// Represents a violation of the Bot API constraints in an invoice.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvoiceError {
+    /// The price breakdown is empty.
+    EmptyPrices,
+    /// An invoice in Telegram Stars contains more than one price component.
+    InvalidStarsPrices(usize),
+    /// Total amount of the price breakdown is not positive.
+    InvalidTotalAmount(Integer),
+    /// The currency is not in [`SUPPORTED_CURRENCIES`].
+    UnsupportedCurrency(String),
+}
+
+impl Error for InvoiceError {}
+
+impl fmt::Display for InvoiceError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        use self::InvoiceError::*;
+        match self {
+            EmptyPrices => write!(out, "price breakdown must not be empty"),
+            InvalidStarsPrices(count) => write!(
+                out,
+                "invoice in Telegram Stars must have exactly one price, got {}",
+                count
+            ),
+            InvalidTotalAmount(amount) => {
+                write!(out, "total amount must be positive, got {}", amount)
+            }
+            UnsupportedCurrency(currency) => write!(out, "currency is not supported: {}", currency),
+        }
+    }
+}
+
+fn validate_prices(currency: &str, prices: &[LabeledPrice]) -> Result<(), InvoiceError> {
+    if !SUPPORTED_CURRENCIES.contains(&currency) {
+        return Err(InvoiceError::UnsupportedCurrency(String::from(currency)));
+    }
+    if prices.is_empty() {
+        return Err(InvoiceError::EmptyPrices);
+    }
+    if currency == STARS_CURRENCY && prices.len() != 1 {
+        return Err(InvoiceError::InvalidStarsPrices(prices.len()));
+    }
+    let total = prices
+        .iter()
+        .fold(0, |acc: Integer, x| acc.saturating_add(x.amount));
+    if total <= 0 {
+        return Err(InvoiceError::InvalidTotalAmount(total));
     }
+    Ok(())
 }
@@ -0,0 +1,177 @@
+use std::io::{Result as IoResult, Write};
+use serde::{Deserialize, Serialize};
+use crate::types::{
+    Integer, RevenueWithdrawalState, StarTransaction, TransactionPartner, UserPeerId, UserUsername,
+};
+
+const CSV_HEADER: &str =
+    "id,date,amount,partner_type,user_id,username,payload,request_count,withdrawal_state,withdrawal_url";
+
+/// Represents a list of Telegram Star transactions for bookkeeping.
+///
+/// Use [`crate::types::GetStarTransactions`] to get transactions,
+/// or [`Self::fetch`] to get all of them.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct StarLedger {
+    /// Records in chronological order.
+    pub records: Vec<StarLedgerRecord>,
+}
+
+impl StarLedger {
+    /// Writes the records in CSV format with a header.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination of the data.
+    pub fn write_csv<W>(&self, mut writer: W) -> IoResult<()>
+    where
+        W: Write,
+    {
+        writeln!(writer, "{}", CSV_HEADER)?;
+        for record in &self.records {
+            let fields = [
+                escape_csv(&record.id),
+                record.date.to_string(),
+                record.amount.to_string(),
+                escape_csv(&record.partner_type),
+                display_optional(record.user_id.as_ref()),
+                escape_csv(&display_optional(record.username.as_ref())),
+                escape_csv(&display_optional(record.payload.as_ref())),
+                display_optional(record.request_count.as_ref()),
+                escape_csv(&display_optional(record.withdrawal_state.as_ref())),
+                escape_csv(&display_optional(record.withdrawal_url.as_ref())),
+            ];
+            writeln!(writer, "{}", fields.join(","))?;
+        }
+        Ok(())
+    }
+
+    /// Writes the records in JSON Lines format.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination of the data.
+    pub fn write_json_lines<W>(&self, mut writer: W) -> IoResult<()>
+    where
+        W: Write,
+    {
+        for record in &self.records {
+            serde_json::to_writer(&mut writer, record)?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> From<T> for StarLedger
+where
+    T: IntoIterator<Item = StarTransaction>,
+{
+    fn from(value: T) -> Self {
+        Self {
+            records: value.into_iter().map(StarLedgerRecord::from).collect(),
+        }
+    }
+}
+
+/// Represents a Telegram Star transaction as a flat record.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StarLedgerRecord {
+    /// Unique identifier of the transaction.
+    pub id: String,
+    /// Date the transaction was created in Unix time.
+    pub date: Integer,
+    /// Number of Telegram Stars; negative for outgoing transactions.
+    pub amount: Integer,
+    /// Type of the source or the receiver:
+    /// `fragment`, `other`, `telegram_ads`, `telegram_api` or `user`.
+    pub partner_type: String,
+    /// Identifier of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserPeerId>,
+    /// Username of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<UserUsername>,
+    /// Bot-specified invoice or paid media payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+    /// The number of billed requests for paid broadcasting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_count: Option<Integer>,
+    /// State of a withdrawal: `failed`, `pending` or `succeeded`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub withdrawal_state: Option<String>,
+    /// An HTTPS URL of a succeeded withdrawal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub withdrawal_url: Option<String>,
+}
+
+impl From<StarTransaction> for StarLedgerRecord {
+    fn from(value: StarTransaction) -> Self {
+        let (amount, partner) = match (value.source, value.receiver) {
+            (_, Some(receiver)) => (-value.amount, receiver),
+            (Some(source), None) => (value.amount, source),
+            (None, None) => (value.amount, TransactionPartner::Other),
+        };
+        let mut record = Self {
+            id: value.id,
+            date: value.date,
+            amount,
+            partner_type: String::new(),
+            user_id: None,
+            username: None,
+            payload: None,
+            request_count: None,
+            withdrawal_state: None,
+            withdrawal_url: None,
+        };
+        record.partner_type = String::from(match partner {
+            TransactionPartner::Fragment(state) => {
+                if let Some(state) = state {
+                    record.withdrawal_state = Some(String::from(match state {
+                        RevenueWithdrawalState::Failed => "failed",
+                        RevenueWithdrawalState::Pending => "pending",
+                        RevenueWithdrawalState::Succeeded { url, .. } => {
+                            record.withdrawal_url = Some(url);
+                            "succeeded"
+                        }
+                    }));
+                }
+                "fragment"
+            }
+            TransactionPartner::Other => "other",
+            TransactionPartner::TelegramAds => "telegram_ads",
+            TransactionPartner::TelegramApi { request_count } => {
+                record.request_count = Some(request_count);
+                "telegram_api"
+            }
+            TransactionPartner::User {
+                user,
+                invoice_payload,
+                paid_media_payload,
+                ..
+            } => {
+                record.user_id = Some(user.id);
+                record.username = user.username;
+                record.payload = invoice_payload.or(paid_media_payload);
+                "user"
+            }
+        });
+        record
+    }
+}
+
+fn display_optional<T>(value: Option<&T>) -> String
+where
+    T: ToString,
+{
+    value.map(ToString::to_string).unwrap_or_default()
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        String::from(value)
+    }
+}
@@ -1,9 +1,13 @@
 use serde::{Deserialize, Serialize};
-pub use self::{checkout::*, invoice::*, order::*, refund::*, shipping::*, transaction::*};
+pub use self::{
+    balance::*, checkout::*, invoice::*, ledger::*, order::*, refund::*, shipping::*, transaction::*,
+};
 use crate::types::Integer;
 
+mod balance;
 mod checkout;
 mod invoice;
+mod ledger;
 mod order;
 mod refund;
 mod shipping;
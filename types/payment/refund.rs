@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::Integer,
 };
 
@@ -98,6 +98,6 @@ impl Method for RefundStarPayment {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("refundStarPayment", self)
+        Payload::json(MethodName::RefundStarPayment, self)
     }
 }
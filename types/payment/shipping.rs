@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{LabeledPrice, User},
 };
 
@@ -204,6 +204,6 @@ impl Method for AnswerShippingQuery {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("answerShippingQuery", self)
+        Payload::json(MethodName::AnswerShippingQuery, self)
     }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{Integer, PaidMedia, User},
 };
 
@@ -98,6 +98,11 @@ pub enum TransactionPartner {
     Other,
     /// Describes a withdrawal transaction to the Telegram Ads platform.
     TelegramAds,
+    /// Describes a transaction with payment for paid broadcasting.
+    TelegramApi {
+        /// The number of successful requests that exceeded regular limits and were therefore billed.
+        request_count: Integer,
+    },
     /// Describes a transaction with a user.
     User {
         /// Information about the user.
@@ -120,6 +125,9 @@ enum RawTransactionPartner {
     },
     Other {},
     TelegramAds {},
+    TelegramApi {
+        request_count: Integer,
+    },
     User {
         user: User,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -139,6 +147,9 @@ impl From<RawTransactionPartner> for TransactionPartner {
             }
             RawTransactionPartner::Other {} => Self::Other,
             RawTransactionPartner::TelegramAds {} => Self::TelegramAds,
+            RawTransactionPartner::TelegramApi { request_count } => {
+                Self::TelegramApi { request_count }
+            }
             RawTransactionPartner::User {
                 user,
                 invoice_payload,
@@ -160,6 +171,9 @@ impl From<TransactionPartner> for RawTransactionPartner {
             TransactionPartner::Fragment(withdrawal_state) => Self::Fragment { withdrawal_state },
             TransactionPartner::Other => Self::Other {},
             TransactionPartner::TelegramAds => Self::TelegramAds {},
+            TransactionPartner::TelegramApi { request_count } => {
+                Self::TelegramApi { request_count }
+            }
             TransactionPartner::User {
                 user,
                 invoice_payload,
@@ -260,6 +274,6 @@ impl Method for GetStarTransactions {
     type Response = StarTransactions;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getStarTransactions", self)
+        Payload::json(MethodName::GetStarTransactions, self)
     }
 }
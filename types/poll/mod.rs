@@ -1,12 +1,18 @@
+use std::{error::Error, fmt, ops::RangeInclusive};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{
         Chat, ChatId, InlineKeyboardMarkup, Integer, Message, ParseMode, ReplyMarkup,
         ReplyParameters, Text, TextEntities, TextEntity, User,
     },
 };
 
+pub use self::{reaction::*, tracker::*};
+
+mod reaction;
+mod tracker;
+
 /// Represents a poll.
 #[derive(Clone, Debug, derive_more::From, Deserialize, PartialEq, PartialOrd, Serialize)]
 #[serde(tag = "type")]
@@ -559,6 +565,14 @@ where
     }
 }
 
+/// Allowed number of options in a poll.
+pub const POLL_OPTION_COUNT: RangeInclusive<usize> = 2..=10;
+
+const EXPLANATION_MAX_LENGTH: usize = 200;
+const OPEN_PERIOD: RangeInclusive<Integer> = 5..=600;
+const OPTION_LENGTH: RangeInclusive<usize> = 1..=100;
+const QUESTION_LENGTH: RangeInclusive<usize> = 1..=300;
+
 #[derive(Clone, Debug, Serialize)]
 struct PollParameters {
     chat_id: ChatId,
@@ -636,6 +650,87 @@ impl PollParameters {
             reply_parameters: None,
         }
     }
+
+    fn validate(&self) -> Result<(), PollError> {
+        let length = self.question.chars().count();
+        if !QUESTION_LENGTH.contains(&length) {
+            return Err(PollError::InvalidQuestionLength(length));
+        }
+        if !POLL_OPTION_COUNT.contains(&self.options.len()) {
+            return Err(PollError::InvalidOptionCount(self.options.len()));
+        }
+        for (index, option) in self.options.iter().enumerate() {
+            let length = option.text.chars().count();
+            if !OPTION_LENGTH.contains(&length) {
+                return Err(PollError::InvalidOptionLength { index, length });
+            }
+        }
+        if let Some(correct_option_id) = self.correct_option_id {
+            if usize::try_from(correct_option_id).map_or(true, |x| x >= self.options.len()) {
+                return Err(PollError::InvalidCorrectOptionId(correct_option_id));
+            }
+        }
+        if let Some(ref explanation) = self.explanation {
+            let length = explanation.chars().count();
+            if length > EXPLANATION_MAX_LENGTH {
+                return Err(PollError::ExplanationTooLong(length));
+            }
+        }
+        match self.open_period {
+            Some(open_period) if !OPEN_PERIOD.contains(&open_period) => {
+                Err(PollError::InvalidOpenPeriod(open_period))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Represents a violation of the Bot API constraints in a poll to send.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PollError {
+    /// Explanation of the quiz is longer than 200 characters.
+    ExplanationTooLong(usize),
+    /// Correct option ID of the quiz doesn't point to an option.
+    InvalidCorrectOptionId(Integer),
+    /// Number of options is not in the range 2-10.
+    InvalidOptionCount(usize),
+    /// Length of an option is not in the range 1-100 characters.
+    InvalidOptionLength {
+        /// Index of the option.
+        index: usize,
+        /// Length of the option in characters.
+        length: usize,
+    },
+    /// Open period is not in the range 5-600 seconds.
+    InvalidOpenPeriod(Integer),
+    /// Length of the question is not in the range 1-300 characters.
+    InvalidQuestionLength(usize),
+}
+
+impl Error for PollError {}
+
+impl fmt::Display for PollError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        use self::PollError::*;
+        match self {
+            ExplanationTooLong(length) => {
+                write!(out, "explanation must be up to 200 characters, got {}", length)
+            }
+            InvalidCorrectOptionId(id) => write!(out, "correct option ID is out of range: {}", id),
+            InvalidOptionCount(count) => write!(out, "poll must have 2-10 options, got {}", count),
+            InvalidOptionLength { index, length } => write!(
+                out,
+                "option #{} must be 1-100 characters, got {}",
+                index, length
+            ),
+            InvalidOpenPeriod(period) => {
+                write!(out, "open period must be 5-600 seconds, got {}", period)
+            }
+            InvalidQuestionLength(length) => {
+                write!(out, "question must be 1-300 characters, got {}", length)
+            }
+        }
+    }
 }
 
 /// Sends a quiz.
@@ -870,13 +965,21 @@ impl SendQuiz {
         self.inner.reply_parameters = Some(value);
         self
     }
+
+    /// Checks the quiz against constraints of the Bot API.
+    ///
+    /// Checks lengths of the question, options and explanation,
+    /// the number of options, the correct option ID and the open period.
+    pub fn validate(&self) -> Result<(), PollError> {
+        self.inner.validate()
+    }
 }
 
 impl Method for SendQuiz {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::json("sendPoll", self)
+        Payload::json(MethodName::SendPoll, self)
     }
 }
 
@@ -1076,13 +1179,21 @@ impl SendPoll {
         self.inner.reply_parameters = Some(value);
         self
     }
+
+    /// Checks the poll against constraints of the Bot API.
+    ///
+    /// Checks lengths of the question and options,
+    /// the number of options and the open period.
+    pub fn validate(&self) -> Result<(), PollError> {
+        self.inner.validate()
+    }
 }
 
 impl Method for SendPoll {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::json("sendPoll", self)
+        Payload::json(MethodName::SendPoll, self)
     }
 }
 
@@ -1154,6 +1265,6 @@ impl Method for StopPoll {
     type Response = Poll;
 
     fn into_payload(self) -> Payload {
-        Payload::json("stopPoll", self)
+        Payload::json(MethodName::StopPoll, self)
     }
 }
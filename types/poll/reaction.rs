@@ -0,0 +1,171 @@
+use crate::types::{
+    ChatId, ChatPeerId, EditMessageText, Integer, Message, MessageReactionCountUpdated,
+    MessageReactionUpdated, ReactionType, SendMessage, Update, UpdateType,
+};
+
+/// Runs a vote using reactions to a message.
+///
+/// Reactions of users are received with `message_reaction` updates,
+/// counts of anonymous reactions and reactions in channels
+/// are received with `message_reaction_count` updates;
+/// the bot must be an administrator in the chat,
+/// and the updates must be explicitly specified in the list of allowed updates.
+///
+/// Typical flow:
+///
+/// 1. Send the message returned by [`Self::prompt`] and pass the result to [`Self::set_message`].
+/// 2. Pass incoming updates to [`Self::handle_update`].
+/// 3. Edit the message with [`Self::close`] to publish results.
+#[derive(Clone, Debug)]
+pub struct ReactionPoll {
+    message: Option<(ChatPeerId, Integer)>,
+    options: Vec<(String, Integer)>,
+    text: String,
+    is_closed: bool,
+}
+
+impl ReactionPoll {
+    /// Creates a new `ReactionPoll`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text of the message.
+    /// * `options` - Emoji counted as votes; reactions with other emoji are ignored.
+    pub fn new<A, B, C>(text: A, options: B) -> Self
+    where
+        A: Into<String>,
+        B: IntoIterator<Item = C>,
+        C: Into<String>,
+    {
+        Self {
+            message: None,
+            options: options.into_iter().map(|x| (x.into(), 0)).collect(),
+            text: text.into(),
+            is_closed: false,
+        }
+    }
+
+    /// Returns a message to send.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - Unique identifier of the target chat.
+    pub fn prompt<T>(&self, chat_id: T) -> SendMessage
+    where
+        T: Into<ChatId>,
+    {
+        let options: Vec<&str> = self.options.iter().map(|(x, _)| x.as_str()).collect();
+        SendMessage::new(chat_id, format!("{}\n\n{}", self.text, options.join(" ")))
+    }
+
+    /// Sets a sent message to track.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message sent with [`Self::prompt`].
+    pub fn set_message(&mut self, message: &Message) {
+        self.message = Some((message.chat.get_id(), message.id));
+    }
+
+    /// Handles an update.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - An incoming update.
+    ///
+    /// Returns `true` if the tally was changed.
+    pub fn handle_update(&mut self, update: &Update) -> bool {
+        match update.update_type {
+            UpdateType::MessageReaction(ref x) => self.handle_reaction(x),
+            UpdateType::MessageReactionCount(ref x) => self.handle_reaction_count(x),
+            _ => false,
+        }
+    }
+
+    /// Updates the tally with a changed reaction of a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Changed reaction of a user.
+    ///
+    /// Returns `true` if the tally was changed;
+    /// reactions to other messages and reactions received after closing are ignored.
+    pub fn handle_reaction(&mut self, value: &MessageReactionUpdated) -> bool {
+        if self.is_closed || self.message != Some((value.chat.get_id(), value.message_id)) {
+            return false;
+        }
+        let has_emoji = |reactions: &[ReactionType], emoji: &str| {
+            reactions
+                .iter()
+                .any(|x| matches!(x, ReactionType::Emoji(ref y) if y == emoji))
+        };
+        let mut is_changed = false;
+        for (emoji, count) in self.options.iter_mut() {
+            let was_set = has_emoji(&value.old_reaction, emoji);
+            let is_set = has_emoji(&value.new_reaction, emoji);
+            if was_set != is_set {
+                *count = if is_set {
+                    *count + 1
+                } else {
+                    (*count - 1).max(0)
+                };
+                is_changed = true;
+            }
+        }
+        is_changed
+    }
+
+    /// Updates the tally with counts of reactions.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Changed counts of reactions.
+    ///
+    /// Returns `true` if the tally was changed;
+    /// counts for other messages and counts received after closing are ignored.
+    pub fn handle_reaction_count(&mut self, value: &MessageReactionCountUpdated) -> bool {
+        if self.is_closed || self.message != Some((value.chat.get_id(), value.message_id)) {
+            return false;
+        }
+        let mut is_changed = false;
+        for (emoji, count) in self.options.iter_mut() {
+            let new_count = value
+                .reactions
+                .iter()
+                .find(|x| matches!(x.reaction_type, ReactionType::Emoji(ref y) if y == emoji))
+                .map(|x| x.total_count)
+                .unwrap_or(0);
+            is_changed |= *count != new_count;
+            *count = new_count;
+        }
+        is_changed
+    }
+
+    /// Returns counts of votes for each option.
+    pub fn tally(&self) -> &[(String, Integer)] {
+        &self.options
+    }
+
+    /// Returns `true` if the vote is closed.
+    pub fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+
+    /// Closes the vote and returns a method to publish results.
+    ///
+    /// Returns [`None`] if a message is not set, see [`Self::set_message`].
+    pub fn close(&mut self) -> Option<EditMessageText> {
+        let (chat_id, message_id) = self.message?;
+        self.is_closed = true;
+        let results: Vec<String> = self
+            .options
+            .iter()
+            .map(|(emoji, count)| format!("{} — {}", emoji, count))
+            .collect();
+        Some(EditMessageText::for_chat_message(
+            chat_id,
+            message_id,
+            format!("{}\n\n{}", self.text, results.join("\n")),
+        ))
+    }
+}
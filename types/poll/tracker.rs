@@ -0,0 +1,182 @@
+use crate::types::{Integer, Poll, PollAnswer, PollAnswerVoter, Update, UpdateType};
+use std::collections::HashMap;
+
+/// Represents a storage of poll tallies and choices used by [`PollTracker`].
+pub trait PollStorage {
+    /// Returns numbers of votes for each option of a poll.
+    ///
+    /// # Arguments
+    ///
+    /// * `poll_id` - Unique identifier of the poll.
+    fn get_tally(&self, poll_id: &str) -> Option<Vec<Integer>>;
+
+    /// Replaces numbers of votes for each option of a poll.
+    ///
+    /// # Arguments
+    ///
+    /// * `poll_id` - Unique identifier of the poll.
+    /// * `value` - Numbers of votes.
+    fn set_tally(&mut self, poll_id: &str, value: Vec<Integer>);
+
+    /// Returns options chosen by a voter.
+    ///
+    /// # Arguments
+    ///
+    /// * `poll_id` - Unique identifier of the poll.
+    /// * `voter_id` - Identifier of the user or the chat.
+    fn get_choice(&self, poll_id: &str, voter_id: Integer) -> Option<Vec<Integer>>;
+
+    /// Replaces options chosen by a voter.
+    ///
+    /// # Arguments
+    ///
+    /// * `poll_id` - Unique identifier of the poll.
+    /// * `voter_id` - Identifier of the user or the chat.
+    /// * `value` - 0-based identifiers of the options; empty if the vote is retracted.
+    fn set_choice(&mut self, poll_id: &str, voter_id: Integer, value: Vec<Integer>);
+}
+
+/// Represents an in-memory [`PollStorage`].
+#[derive(Clone, Debug, Default)]
+pub struct MemoryPollStorage {
+    choices: HashMap<(String, Integer), Vec<Integer>>,
+    tallies: HashMap<String, Vec<Integer>>,
+}
+
+impl PollStorage for MemoryPollStorage {
+    fn get_tally(&self, poll_id: &str) -> Option<Vec<Integer>> {
+        self.tallies.get(poll_id).cloned()
+    }
+
+    fn set_tally(&mut self, poll_id: &str, value: Vec<Integer>) {
+        self.tallies.insert(String::from(poll_id), value);
+    }
+
+    fn get_choice(&self, poll_id: &str, voter_id: Integer) -> Option<Vec<Integer>> {
+        self.choices
+            .get(&(String::from(poll_id), voter_id))
+            .cloned()
+    }
+
+    fn set_choice(&mut self, poll_id: &str, voter_id: Integer, value: Vec<Integer>) {
+        let key = (String::from(poll_id), voter_id);
+        if value.is_empty() {
+            self.choices.remove(&key);
+        } else {
+            self.choices.insert(key, value);
+        }
+    }
+}
+
+/// Tracks votes in polls.
+///
+/// Poll updates contain final numbers of votes and replace the tally;
+/// poll answer updates, received for non-anonymous polls only,
+/// update choices of voters and adjust the tally between poll updates.
+#[derive(Clone, Debug, Default)]
+pub struct PollTracker<S = MemoryPollStorage> {
+    storage: S,
+}
+
+impl<S> PollTracker<S>
+where
+    S: PollStorage,
+{
+    /// Creates a new `PollTracker`.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage` - Storage of tallies and choices.
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+
+    /// Returns the storage.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Handles an update.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - An incoming update.
+    ///
+    /// Returns `false` if the update doesn't contain a poll or a poll answer.
+    pub fn handle_update(&mut self, update: &Update) -> bool {
+        match update.update_type {
+            UpdateType::Poll(ref poll) => self.handle_poll(poll),
+            UpdateType::PollAnswer(ref answer) => self.handle_answer(answer),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Replaces the tally with numbers of votes from a poll.
+    ///
+    /// # Arguments
+    ///
+    /// * `poll` - A poll with final numbers of votes.
+    pub fn handle_poll(&mut self, poll: &Poll) {
+        let (poll_id, options) = match poll {
+            Poll::Regular(x) => (&x.id, &x.options),
+            Poll::Quiz(x) => (&x.id, &x.options),
+        };
+        self.storage
+            .set_tally(poll_id, options.iter().map(|x| x.voter_count).collect());
+    }
+
+    /// Updates a choice of the voter and the tally.
+    ///
+    /// # Arguments
+    ///
+    /// * `answer` - A changed answer of the voter.
+    pub fn handle_answer(&mut self, answer: &PollAnswer) {
+        let voter_id = get_voter_id(&answer.voter);
+        let previous = self
+            .storage
+            .get_choice(&answer.poll_id, voter_id)
+            .unwrap_or_default();
+        let mut tally = self.storage.get_tally(&answer.poll_id).unwrap_or_default();
+        for (option_ids, delta) in [(&previous, -1), (&answer.option_ids, 1)] {
+            for option_id in option_ids {
+                let Ok(idx) = usize::try_from(*option_id) else {
+                    continue;
+                };
+                if idx >= tally.len() {
+                    tally.resize(idx + 1, 0);
+                }
+                tally[idx] = (tally[idx] + delta).max(0);
+            }
+        }
+        self.storage.set_tally(&answer.poll_id, tally);
+        self.storage
+            .set_choice(&answer.poll_id, voter_id, answer.option_ids.clone());
+    }
+
+    /// Returns numbers of votes for each option of a poll.
+    ///
+    /// # Arguments
+    ///
+    /// * `poll_id` - Unique identifier of the poll.
+    pub fn get_tally(&self, poll_id: &str) -> Option<Vec<Integer>> {
+        self.storage.get_tally(poll_id)
+    }
+
+    /// Returns options chosen by a voter.
+    ///
+    /// # Arguments
+    ///
+    /// * `poll_id` - Unique identifier of the poll.
+    /// * `voter_id` - Identifier of the user or the chat.
+    pub fn get_choice(&self, poll_id: &str, voter_id: Integer) -> Option<Vec<Integer>> {
+        self.storage.get_choice(poll_id, voter_id)
+    }
+}
+
+fn get_voter_id(voter: &PollAnswerVoter) -> Integer {
+    match voter {
+        PollAnswerVoter::Chat(x) => x.get_id().into(),
+        PollAnswerVoter::User(x) => x.id.into(),
+    }
+}
@@ -0,0 +1,117 @@
+use crate::types::{Bot, Chat};
+use std::fmt;
+
+const FILTERED_MESSAGE_KINDS: [FilteredMessageKind; 5] = [
+    FilteredMessageKind::Command,
+    FilteredMessageKind::GeneralCommand,
+    FilteredMessageKind::Reply,
+    FilteredMessageKind::Service,
+    FilteredMessageKind::ViaBot,
+];
+
+impl Bot {
+    /// Returns which messages the bot receives in a chat.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat` - The chat to check.
+    /// * `is_administrator` - Whether the bot is an administrator of the chat.
+    ///
+    /// Use it to explain why the bot does not see messages in a group:
+    /// in privacy mode, only messages meant for the bot are delivered.
+    /// Privacy mode is changed with @BotFather and applies to groups the bot joins afterwards.
+    pub fn message_delivery(&self, chat: &Chat, is_administrator: bool) -> MessageDelivery {
+        match chat {
+            Chat::Private(_) => MessageDelivery::All,
+            Chat::Channel(_) if is_administrator => MessageDelivery::All,
+            Chat::Channel(_) => MessageDelivery::Nothing,
+            Chat::Group(_) | Chat::Supergroup(_) => {
+                if self.can_read_all_group_messages || is_administrator {
+                    MessageDelivery::All
+                } else {
+                    MessageDelivery::Filtered
+                }
+            }
+        }
+    }
+}
+
+/// Describes which messages a bot receives in a chat.
+///
+/// See [`Bot::message_delivery`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MessageDelivery {
+    /// The bot receives all messages.
+    All,
+    /// The bot receives only messages meant for it, see [`Self::kinds`].
+    Filtered,
+    /// The bot receives no messages.
+    ///
+    /// A bot receives channel posts only when it is an administrator of the channel.
+    Nothing,
+}
+
+impl MessageDelivery {
+    /// Returns kinds of messages the bot receives in privacy mode.
+    ///
+    /// Returns [`None`] if all messages are received
+    /// and an empty list if no messages are received.
+    pub fn kinds(&self) -> Option<&'static [FilteredMessageKind]> {
+        match self {
+            Self::All => None,
+            Self::Filtered => Some(&FILTERED_MESSAGE_KINDS),
+            Self::Nothing => Some(&[]),
+        }
+    }
+}
+
+impl fmt::Display for MessageDelivery {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::All => write!(out, "all messages"),
+            Self::Filtered => {
+                write!(
+                    out,
+                    "privacy mode is enabled, only these messages are received: "
+                )?;
+                for (idx, kind) in FILTERED_MESSAGE_KINDS.iter().enumerate() {
+                    if idx > 0 {
+                        write!(out, "; ")?;
+                    }
+                    write!(out, "{}", kind)?;
+                }
+                Ok(())
+            }
+            Self::Nothing => write!(out, "no messages, the bot is not an administrator"),
+        }
+    }
+}
+
+/// Represents a kind of messages received by a bot in privacy mode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FilteredMessageKind {
+    /// Commands explicitly meant for the bot, e.g. `/command@this_bot`.
+    Command,
+    /// General commands, e.g. `/start`, if the bot was the last bot to send a message to the group.
+    GeneralCommand,
+    /// Replies to messages of the bot.
+    Reply,
+    /// Service messages.
+    Service,
+    /// Messages sent via the bot in inline mode.
+    ViaBot,
+}
+
+impl fmt::Display for FilteredMessageKind {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        out.write_str(match self {
+            Self::Command => "commands meant for the bot",
+            Self::GeneralCommand => {
+                "general commands if the bot was the last bot to send a message"
+            }
+            Self::Reply => "replies to messages of the bot",
+            Self::Service => "service messages",
+            Self::ViaBot => "messages sent via the bot",
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{Chat, ChatId, Integer, User},
 };
 
@@ -275,6 +275,6 @@ impl Method for SetMessageReaction {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setMessageReaction", self)
+        Payload::json(MethodName::SetMessageReaction, self)
     }
 }
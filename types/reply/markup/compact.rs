@@ -0,0 +1,158 @@
+use super::{
+    InlineKeyboardButton, InlineKeyboardButtonType, InlineKeyboardError, InlineKeyboardMarkup,
+};
+
+const BUTTON_SEPARATOR: char = '\t';
+const ROW_SEPARATOR: char = '\n';
+const VALUE_SEPARATOR: char = '|';
+
+const TAG_CALLBACK_DATA: char = 'c';
+const TAG_CALLBACK_GAME: char = 'g';
+const TAG_JSON: char = 'j';
+const TAG_PAY: char = 'p';
+const TAG_SWITCH_INLINE_QUERY: char = 'q';
+const TAG_SWITCH_INLINE_QUERY_CURRENT_CHAT: char = 'Q';
+const TAG_URL: char = 'u';
+
+impl InlineKeyboardMarkup {
+    /// Returns a compact string representation of the keyboard.
+    ///
+    /// Rows are separated by line feeds and buttons by tabs;
+    /// each button is a type tag followed by the text and the value.
+    /// Callback, URL, inline query, game and pay buttons take a few bytes
+    /// in addition to their text and value, other buttons are stored as JSON.
+    ///
+    /// Use [`Self::from_compact_string`] to restore the keyboard.
+    pub fn to_compact_string(&self) -> Result<String, InlineKeyboardError> {
+        let mut result = String::new();
+        for (row_idx, row) in self.inline_keyboard.iter().enumerate() {
+            if row_idx > 0 {
+                result.push(ROW_SEPARATOR);
+            }
+            for (button_idx, button) in row.iter().enumerate() {
+                if button_idx > 0 {
+                    result.push(BUTTON_SEPARATOR);
+                }
+                let (tag, value) = match button.button_type {
+                    InlineKeyboardButtonType::CallbackData(ref value) => {
+                        (TAG_CALLBACK_DATA, value.as_str())
+                    }
+                    InlineKeyboardButtonType::CallbackGame => (TAG_CALLBACK_GAME, ""),
+                    InlineKeyboardButtonType::Pay => (TAG_PAY, ""),
+                    InlineKeyboardButtonType::SwitchInlineQuery(ref value) => {
+                        (TAG_SWITCH_INLINE_QUERY, value.as_str())
+                    }
+                    InlineKeyboardButtonType::SwitchInlineQueryCurrentChat(ref value) => {
+                        (TAG_SWITCH_INLINE_QUERY_CURRENT_CHAT, value.as_str())
+                    }
+                    InlineKeyboardButtonType::Url(ref value) => (TAG_URL, value.as_str()),
+                    _ => {
+                        let value = serde_json::to_string(button)
+                            .map_err(InlineKeyboardError::SerializeMarkup)?;
+                        result.push(TAG_JSON);
+                        escape(&value, &mut result);
+                        continue;
+                    }
+                };
+                result.push(tag);
+                escape(&button.text, &mut result);
+                result.push(VALUE_SEPARATOR);
+                escape(value, &mut result);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Restores a keyboard from a string returned by [`Self::to_compact_string`].
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The compact string representation of the keyboard.
+    pub fn from_compact_string(value: &str) -> Result<Self, InlineKeyboardError> {
+        let mut result = Self::default();
+        if value.is_empty() {
+            return Ok(result);
+        }
+        for row in value.split(ROW_SEPARATOR) {
+            let mut buttons = Vec::new();
+            if !row.is_empty() {
+                for button in row.split(BUTTON_SEPARATOR) {
+                    buttons.push(parse_button(button)?);
+                }
+            }
+            result.inline_keyboard.push(buttons);
+        }
+        Ok(result)
+    }
+}
+
+fn parse_button(value: &str) -> Result<InlineKeyboardButton, InlineKeyboardError> {
+    let mut chars = value.chars();
+    let tag = chars
+        .next()
+        .ok_or(InlineKeyboardError::InvalidCompactString)?;
+    if tag == TAG_JSON {
+        let value = unescape(chars.as_str()).ok_or(InlineKeyboardError::InvalidCompactString)?;
+        return serde_json::from_str(&value).map_err(InlineKeyboardError::DeserializeMarkup);
+    }
+    let (text, value) =
+        split_value(chars.as_str()).ok_or(InlineKeyboardError::InvalidCompactString)?;
+    let button_type = match tag {
+        TAG_CALLBACK_DATA => InlineKeyboardButtonType::CallbackData(value),
+        TAG_CALLBACK_GAME => InlineKeyboardButtonType::CallbackGame,
+        TAG_PAY => InlineKeyboardButtonType::Pay,
+        TAG_SWITCH_INLINE_QUERY => InlineKeyboardButtonType::SwitchInlineQuery(value),
+        TAG_SWITCH_INLINE_QUERY_CURRENT_CHAT => {
+            InlineKeyboardButtonType::SwitchInlineQueryCurrentChat(value)
+        }
+        TAG_URL => InlineKeyboardButtonType::Url(value),
+        _ => return Err(InlineKeyboardError::InvalidCompactString),
+    };
+    Ok(InlineKeyboardButton { text, button_type })
+}
+
+fn escape(value: &str, out: &mut String) {
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            BUTTON_SEPARATOR => out.push_str("\\t"),
+            ROW_SEPARATOR => out.push_str("\\n"),
+            VALUE_SEPARATOR => out.push_str("\\|"),
+            c => out.push(c),
+        }
+    }
+}
+
+fn unescape(value: &str) -> Option<String> {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        result.push(match c {
+            '\\' => match chars.next()? {
+                '\\' => '\\',
+                't' => BUTTON_SEPARATOR,
+                'n' => ROW_SEPARATOR,
+                VALUE_SEPARATOR => VALUE_SEPARATOR,
+                _ => return None,
+            },
+            VALUE_SEPARATOR => return None,
+            c => c,
+        });
+    }
+    Some(result)
+}
+
+fn split_value(value: &str) -> Option<(String, String)> {
+    let mut escaped = false;
+    for (idx, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            VALUE_SEPARATOR => {
+                return Some((unescape(&value[..idx])?, unescape(&value[idx + 1..])?));
+            }
+            _ => {}
+        }
+    }
+    None
+}
@@ -0,0 +1,125 @@
+use super::{InlineKeyboardButton, InlineKeyboardButtonType, InlineKeyboardMarkup};
+
+/// Represents a change of a button in an inline keyboard.
+///
+/// Positions are zero-based indexes of a row and a button in the row.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InlineKeyboardChange {
+    /// A button was added.
+    Added {
+        /// Position of the button.
+        position: (usize, usize),
+        /// The new button.
+        button: InlineKeyboardButton,
+    },
+    /// A button was removed.
+    Removed {
+        /// Position of the button.
+        position: (usize, usize),
+        /// The removed button.
+        button: InlineKeyboardButton,
+    },
+    /// Text of a button was changed.
+    Relabeled {
+        /// Position of the button.
+        position: (usize, usize),
+        /// The new text.
+        text: String,
+    },
+    /// Action of a button was changed, e.g. callback data or URL.
+    Retargeted {
+        /// Position of the button.
+        position: (usize, usize),
+        /// The new action.
+        button_type: InlineKeyboardButtonType,
+    },
+}
+
+/// Represents differences between two inline keyboards.
+///
+/// Use it to skip [`crate::types::EditMessageReplyMarkup`] calls
+/// which don't change anything and would fail with “message is not modified”.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InlineKeyboardDiff {
+    changes: Vec<InlineKeyboardChange>,
+}
+
+impl InlineKeyboardDiff {
+    /// Creates a new `InlineKeyboardDiff`.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The current markup of a message.
+    /// * `new` - The intended markup.
+    ///
+    /// Buttons are compared by position.
+    pub fn new(old: &InlineKeyboardMarkup, new: &InlineKeyboardMarkup) -> Self {
+        let empty = Vec::new();
+        let rows = old.inline_keyboard.len().max(new.inline_keyboard.len());
+        let mut changes = Vec::new();
+        for row in 0..rows {
+            let old_row = old.inline_keyboard.get(row).unwrap_or(&empty);
+            let new_row = new.inline_keyboard.get(row).unwrap_or(&empty);
+            for column in 0..old_row.len().max(new_row.len()) {
+                let position = (row, column);
+                match (old_row.get(column), new_row.get(column)) {
+                    (Some(old), Some(new)) => {
+                        if old.text != new.text {
+                            changes.push(InlineKeyboardChange::Relabeled {
+                                position,
+                                text: new.text.clone(),
+                            });
+                        }
+                        if old.button_type != new.button_type {
+                            changes.push(InlineKeyboardChange::Retargeted {
+                                position,
+                                button_type: new.button_type.clone(),
+                            });
+                        }
+                    }
+                    (Some(old), None) => changes.push(InlineKeyboardChange::Removed {
+                        position,
+                        button: old.clone(),
+                    }),
+                    (None, Some(new)) => changes.push(InlineKeyboardChange::Added {
+                        position,
+                        button: new.clone(),
+                    }),
+                    (None, None) => {}
+                }
+            }
+        }
+        Self { changes }
+    }
+
+    /// Returns all changes.
+    pub fn changes(&self) -> &[InlineKeyboardChange] {
+        &self.changes
+    }
+
+    /// Returns `true` if the keyboards are equal and an edit is not needed.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns `true` if buttons were added or removed.
+    pub fn is_layout_changed(&self) -> bool {
+        self.changes.iter().any(|x| {
+            matches!(
+                x,
+                InlineKeyboardChange::Added { .. } | InlineKeyboardChange::Removed { .. }
+            )
+        })
+    }
+}
+
+impl InlineKeyboardMarkup {
+    /// Returns differences between the markup and the intended one.
+    ///
+    /// # Arguments
+    ///
+    /// * `new` - The intended markup.
+    pub fn diff(&self, new: &InlineKeyboardMarkup) -> InlineKeyboardDiff {
+        InlineKeyboardDiff::new(self, new)
+    }
+}
@@ -385,6 +385,10 @@ impl RawButtonFlag {
 /// Represents an error occurred with an inline keyboard.
 #[derive(Debug)]
 pub enum InlineKeyboardError {
+    /// Can not deserialize a button stored as JSON in a compact string.
+    DeserializeMarkup(JsonError),
+    /// A compact string is malformed.
+    InvalidCompactString,
     /// Can not serialize callback data.
     SerializeCallbackData(JsonError),
     /// Can not serialize markup.
@@ -395,6 +399,8 @@ impl Error for InlineKeyboardError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         use self::InlineKeyboardError::*;
         match self {
+            DeserializeMarkup(err) => Some(err),
+            InvalidCompactString => None,
             SerializeCallbackData(err) => Some(err),
             SerializeMarkup(err) => Some(err),
         }
@@ -405,6 +411,8 @@ impl fmt::Display for InlineKeyboardError {
     fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
         use self::InlineKeyboardError::*;
         match self {
+            DeserializeMarkup(err) => write!(out, "failed to deserialize markup: {}", err),
+            InvalidCompactString => write!(out, "invalid compact string of markup"),
             SerializeCallbackData(err) => write!(out, "failed to serialize callback data: {}", err),
             SerializeMarkup(err) => write!(out, "failed to serialize markup: {}", err),
         }
@@ -0,0 +1,194 @@
+use super::{InlineKeyboardButton, InlineKeyboardMarkup, KeyboardButton, ReplyKeyboardMarkup};
+
+const ELLIPSIS: char = '…';
+
+/// Recommended maximum length of a button label in characters.
+///
+/// Longer labels are usually truncated by mobile clients.
+pub const RECOMMENDED_LABEL_LENGTH: usize = 32;
+
+/// Recommended maximum total length of labels in a keyboard row in characters.
+pub const RECOMMENDED_ROW_WIDTH: usize = 40;
+
+/// Shortens a button label or another short text to the given number of characters.
+///
+/// The label is cut at the last whitespace if possible, and an ellipsis is appended.
+/// Labels within the limit are returned as is.
+///
+/// # Arguments
+///
+/// * `text` - The label.
+/// * `max` - Maximum number of characters, including the ellipsis.
+pub fn wrap_label(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return String::from(text);
+    }
+    if max == 0 {
+        return String::new();
+    }
+    let head: String = text.chars().take(max - 1).collect();
+    let head = match head.rfind(char::is_whitespace) {
+        Some(idx) if idx > 0 => &head[..idx],
+        _ => head.as_str(),
+    };
+    let mut result = String::from(head.trim_end());
+    result.push(ELLIPSIS);
+    result
+}
+
+/// Represents a problem found in a keyboard layout.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyboardWarning {
+    /// A button label is longer than the limit.
+    LongLabel {
+        /// Index of the row.
+        row: usize,
+        /// Index of the button in the row.
+        column: usize,
+        /// Length of the label in characters.
+        length: usize,
+    },
+    /// Total length of labels in a row is longer than the limit.
+    WideRow {
+        /// Index of the row.
+        row: usize,
+        /// Total length of labels in characters.
+        width: usize,
+    },
+}
+
+impl InlineKeyboardMarkup {
+    /// Returns problems which make the keyboard hard to read on mobile clients.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_label_length` - Maximum length of a label, see [`RECOMMENDED_LABEL_LENGTH`].
+    /// * `max_row_width` - Maximum total length of labels in a row,
+    ///   see [`RECOMMENDED_ROW_WIDTH`].
+    pub fn validate_layout(
+        &self,
+        max_label_length: usize,
+        max_row_width: usize,
+    ) -> Vec<KeyboardWarning> {
+        validate_rows(&self.inline_keyboard, max_label_length, max_row_width)
+    }
+
+    /// Splits rows which are wider than the limit into several rows.
+    ///
+    /// Order of buttons is kept; a button wider than the limit gets its own row.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_row_width` - Maximum total length of labels in a row,
+    ///   see [`RECOMMENDED_ROW_WIDTH`].
+    pub fn split_wide_rows(mut self, max_row_width: usize) -> Self {
+        self.inline_keyboard = split_rows(self.inline_keyboard, max_row_width);
+        self
+    }
+}
+
+impl ReplyKeyboardMarkup {
+    /// Returns problems which make the keyboard hard to read on mobile clients.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_label_length` - Maximum length of a label, see [`RECOMMENDED_LABEL_LENGTH`].
+    /// * `max_row_width` - Maximum total length of labels in a row,
+    ///   see [`RECOMMENDED_ROW_WIDTH`].
+    pub fn validate_layout(
+        &self,
+        max_label_length: usize,
+        max_row_width: usize,
+    ) -> Vec<KeyboardWarning> {
+        validate_rows(&self.keyboard, max_label_length, max_row_width)
+    }
+
+    /// Splits rows which are wider than the limit into several rows.
+    ///
+    /// Order of buttons is kept; a button wider than the limit gets its own row.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_row_width` - Maximum total length of labels in a row,
+    ///   see [`RECOMMENDED_ROW_WIDTH`].
+    pub fn split_wide_rows(mut self, max_row_width: usize) -> Self {
+        self.keyboard = split_rows(self.keyboard, max_row_width);
+        self
+    }
+}
+
+trait Labeled {
+    fn label(&self) -> &str;
+
+    fn label_length(&self) -> usize {
+        self.label().chars().count()
+    }
+}
+
+impl Labeled for InlineKeyboardButton {
+    fn label(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Labeled for KeyboardButton {
+    fn label(&self) -> &str {
+        &self.text
+    }
+}
+
+fn validate_rows<T>(
+    rows: &[Vec<T>],
+    max_label_length: usize,
+    max_row_width: usize,
+) -> Vec<KeyboardWarning>
+where
+    T: Labeled,
+{
+    let mut result = Vec::new();
+    for (row_idx, row) in rows.iter().enumerate() {
+        let mut width = 0;
+        for (column_idx, button) in row.iter().enumerate() {
+            let length = button.label_length();
+            if length > max_label_length {
+                result.push(KeyboardWarning::LongLabel {
+                    row: row_idx,
+                    column: column_idx,
+                    length,
+                });
+            }
+            width += length;
+        }
+        if row.len() > 1 && width > max_row_width {
+            result.push(KeyboardWarning::WideRow {
+                row: row_idx,
+                width,
+            });
+        }
+    }
+    result
+}
+
+fn split_rows<T>(rows: Vec<Vec<T>>, max_row_width: usize) -> Vec<Vec<T>>
+where
+    T: Labeled,
+{
+    let mut result = Vec::with_capacity(rows.len());
+    for row in rows {
+        let mut current = Vec::new();
+        let mut width = 0;
+        for button in row {
+            let length = button.label_length();
+            if !current.is_empty() && width + length > max_row_width {
+                result.push(std::mem::take(&mut current));
+                width = 0;
+            }
+            width += length;
+            current.push(button);
+        }
+        if !current.is_empty() {
+            result.push(current);
+        }
+    }
+    result
+}
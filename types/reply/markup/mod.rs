@@ -1,10 +1,13 @@
-pub use self::{force_reply::*, inline_keyboard::*, reply_keyboard::*};
+pub use self::{diff::*, force_reply::*, inline_keyboard::*, layout::*, reply_keyboard::*};
 use serde::{Deserialize, Serialize};
 use serde_json::Error as JsonError;
 use std::{error::Error, fmt};
 
+mod compact;
+mod diff;
 mod force_reply;
 mod inline_keyboard;
+mod layout;
 mod reply_keyboard;
 
 /// Represents a reply markup.
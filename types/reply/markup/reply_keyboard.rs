@@ -5,7 +5,7 @@ use crate::types::{ChatAdministratorRights, Integer, PollType, True, WebAppInfo}
 /// Represents a custom keyboard with reply options.
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
 pub struct ReplyKeyboardMarkup {
-    keyboard: Vec<Vec<KeyboardButton>>,
+    pub(super) keyboard: Vec<Vec<KeyboardButton>>,
     #[serde(default, skip_serializing_if = "Not::not")]
     one_time_keyboard: bool,
     #[serde(default, skip_serializing_if = "Not::not")]
@@ -132,7 +132,7 @@ where
 /// Represents a button of the reply keyboard.
 #[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize)]
 pub struct KeyboardButton {
-    text: String,
+    pub(super) text: String,
     #[serde(flatten)]
     #[serde(skip_serializing_if = "Option::is_none")]
     button_type: Option<KeyboardButtonType>,
@@ -79,6 +79,45 @@ impl ResponseError {
         self.retry_after.and_then(|x| x.try_into().ok())
     }
 
+    /// Returns a flag describing whether the request may succeed when repeated.
+    ///
+    /// Unlike [`Self::can_retry`], the flag is also set for server errors
+    /// and rate limit errors without a `retry_after` parameter.
+    pub fn is_retryable(&self) -> bool {
+        self.retry_after.is_some() || self.error_code.is_some_and(|x| x == 429 || x >= 500)
+    }
+
+    /// Returns a flag describing whether the user has blocked the bot.
+    pub fn is_blocked_by_user(&self) -> bool {
+        self.error_code == Some(403) && self.description.contains("bot was blocked by the user")
+    }
+
+    /// Returns a flag describing whether a group has been migrated to a supergroup.
+    ///
+    /// Use [`Self::migrate_to_chat_id`] to get the identifier of the supergroup.
+    pub fn is_chat_migrated(&self) -> bool {
+        self.migrate_to_chat_id.is_some()
+    }
+
+    /// Returns a flag describing whether a callback or inline query to answer has expired.
+    ///
+    /// Telegram rejects answers sent after the query timeout or to an unknown query.
+    pub fn is_query_expired(&self) -> bool {
+        self.description.contains("query is too old")
+            || self.description.contains("query ID is invalid")
+    }
+
+    /// Returns a flag describing whether an edited message has the same content as before.
+    pub fn is_message_not_modified(&self) -> bool {
+        self.description.contains("message is not modified")
+    }
+
+    /// Returns a flag describing whether a message to edit is not found or can't be edited.
+    pub fn is_message_not_editable(&self) -> bool {
+        self.description.contains("message to edit not found")
+            || self.description.contains("message can't be edited")
+    }
+
     /// Returns a new identifier of a group which has been migrated to a supergroup.
     pub fn migrate_to_chat_id(&self) -> Option<Integer> {
         self.migrate_to_chat_id
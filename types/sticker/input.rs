@@ -1,4 +1,4 @@
-use std::{fmt, fmt::Formatter};
+use std::{fmt, fmt::Formatter, path::Path};
 use serde::Serialize;
 use serde_json::Error as JsonError;
 use crate::{
@@ -6,6 +6,10 @@ use crate::{
     types::{InputFile, MaskPosition, StickerFormat},
 };
 
+const MAX_EMOJI_COUNT: usize = 20;
+const MAX_KEYWORDS_COUNT: usize = 20;
+const MAX_KEYWORDS_LENGTH: usize = 64;
+
 /// Represents a metadata of a sticker to be added to a sticker set.
 #[derive(Debug)]
 pub struct InputSticker {
@@ -65,12 +69,50 @@ impl InputSticker {
         self.mask_position = Some(value);
         self
     }
+
+    /// Checks whether the sticker satisfies the constraints of the Bot API.
+    ///
+    /// The file extension and MIME type are checked against the format
+    /// only when the sticker is uploaded and they are known.
+    pub fn validate(&self) -> Result<(), InputStickerError> {
+        let emoji_count = self.emoji_list.len();
+        if !(1..=MAX_EMOJI_COUNT).contains(&emoji_count) {
+            return Err(InputStickerError::InvalidEmojiCount(emoji_count));
+        }
+        if let Some(ref keywords) = self.keywords {
+            let length: usize = keywords.iter().map(|x| x.chars().count()).sum();
+            if keywords.len() > MAX_KEYWORDS_COUNT || length > MAX_KEYWORDS_LENGTH {
+                return Err(InputStickerError::InvalidKeywords);
+            }
+        }
+        if let InputFile::Reader(ref reader) = self.sticker {
+            let (extensions, mime_type) = match self.format {
+                StickerFormat::Static => (&["png", "webp"][..], None),
+                StickerFormat::Animated => (&["tgs"][..], Some("application/x-tgsticker")),
+                StickerFormat::Video => (&["webm"][..], Some("video/webm")),
+            };
+            let is_valid_extension = reader
+                .file_name()
+                .and_then(|x| Path::new(x).extension())
+                .and_then(|x| x.to_str())
+                .is_none_or(|x| extensions.iter().any(|y| x.eq_ignore_ascii_case(y)));
+            let is_valid_mime_type = reader.mime_type().is_none_or(|x| match mime_type {
+                Some(mime_type) => x.essence_str() == mime_type,
+                None => extensions.contains(&x.subtype().as_str()),
+            });
+            if !is_valid_extension || !is_valid_mime_type {
+                return Err(InputStickerError::UnsupportedFile(self.format));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl TryFrom<InputSticker> for Form {
     type Error = InputStickerError;
 
     fn try_from(value: InputSticker) -> Result<Self, Self::Error> {
+        value.validate()?;
         let InputSticker {
             sticker,
             emoji_list,
@@ -105,6 +147,7 @@ impl TryFrom<InputSticker> for Form {
 /// Represents a collection of stickers to be added to a sticker set.
 #[derive(Debug, Default)]
 pub struct InputStickers {
+    error: Option<InputStickerError>,
     form: Form,
     metadata: Vec<InputStickerMetadata>,
 }
@@ -115,7 +158,13 @@ impl InputStickers {
     /// # Arguments
     ///
     /// * `value` - The sticker to add.
+    ///
+    /// The sticker is validated with [`InputSticker::validate`];
+    /// the first error is returned when the collection is converted into a form.
     pub fn add_sticker(mut self, value: InputSticker) -> Self {
+        if let Err(err) = value.validate() {
+            self.error.get_or_insert(err);
+        }
         let InputSticker {
             sticker,
             emoji_list,
@@ -147,6 +196,9 @@ impl TryFrom<InputStickers> for Form {
     type Error = InputStickerError;
 
     fn try_from(mut value: InputStickers) -> Result<Self, Self::Error> {
+        if let Some(err) = value.error {
+            return Err(err);
+        }
         let stickers =
             serde_json::to_string(&value.metadata).map_err(InputStickerError::Serialize)?;
         value.form.insert_field("stickers", stickers);
@@ -157,16 +209,32 @@ impl TryFrom<InputStickers> for Form {
 /// Represents an error when converting input stickers into a multipart form.
 #[derive(Debug)]
 pub enum InputStickerError {
+    /// Number of emoji is not in the range 1-20.
+    InvalidEmojiCount(usize),
+    /// More than 20 keywords or their total length is more than 64 characters.
+    InvalidKeywords,
     /// Could not serialize a list of input stickers.
     Serialize(JsonError),
+    /// File extension or MIME type doesn't match the format of the sticker.
+    UnsupportedFile(StickerFormat),
 }
 
 impl fmt::Display for InputStickerError {
     fn fmt(&self, out: &mut Formatter<'_>) -> fmt::Result {
         match self {
+            Self::InvalidEmojiCount(count) => {
+                write!(out, "sticker must have 1-20 emoji, got {}", count)
+            }
+            Self::InvalidKeywords => write!(
+                out,
+                "sticker must have up to 20 keywords with total length of up to 64 characters"
+            ),
             Self::Serialize(err) => {
                 write!(out, "could not serialize a list of input stickers: {}", err)
             }
+            Self::UnsupportedFile(format) => {
+                write!(out, "file is not supported for {} stickers", format.as_ref())
+            }
         }
     }
 }
@@ -1,6 +1,6 @@
 pub use self::{input::*, mask::*, set::*};
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, Method, MethodName, Payload},
     types::{
         ChatId, File, InputFile, Integer, Message, PhotoSize, ReplyMarkup, ReplyMarkupError,
         ReplyParameters, ReplyParametersError,
@@ -213,6 +213,22 @@ impl Sticker {
         self.thumbnail = Some(value);
         self
     }
+
+    /// Returns `true` if the sticker is a custom emoji.
+    pub fn is_custom_emoji(&self) -> bool {
+        matches!(self.sticker_type, StickerType::CustomEmoji)
+    }
+
+    /// Returns `true` if the sticker belongs to a set created by the bot.
+    ///
+    /// # Arguments
+    ///
+    /// * `bot_username` - Username of the bot without leading `@`.
+    pub fn is_owned_by(&self, bot_username: &str) -> bool {
+        self.set_name
+            .as_deref()
+            .is_some_and(|x| set::is_bot_set_name(x, bot_username))
+    }
 }
 
 /// Represents a format of stickers in the set.
@@ -287,7 +303,7 @@ impl Method for GetCustomEmojiStickers {
     type Response = Vec<Sticker>;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getCustomEmojiStickers", self)
+        Payload::json(MethodName::GetCustomEmojiStickers, self)
     }
 }
 
@@ -423,7 +439,7 @@ impl Method for SendSticker {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::form("sendSticker", self.form)
+        Payload::form(MethodName::SendSticker, self.form)
     }
 }
 
@@ -458,7 +474,7 @@ impl Method for SetStickerEmojiList {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setStickerEmojiList", self)
+        Payload::json(MethodName::SetStickerEmojiList, self)
     }
 }
 
@@ -494,7 +510,7 @@ impl Method for SetStickerKeywords {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setStickerKeywords", self)
+        Payload::json(MethodName::SetStickerKeywords, self)
     }
 }
 
@@ -539,7 +555,7 @@ impl Method for SetStickerMaskPosition {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setStickerMaskPosition", self)
+        Payload::json(MethodName::SetStickerMaskPosition, self)
     }
 }
 
@@ -578,6 +594,6 @@ impl Method for UploadStickerFile {
     type Response = File;
 
     fn into_payload(self) -> Payload {
-        Payload::form("uploadStickerFile", self.form)
+        Payload::form(MethodName::UploadStickerFile, self.form)
     }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::{
-    api::{Form, Method, Payload},
+    api::{Form, Method, MethodName, Payload},
     types::{
         InputFile, InputSticker, InputStickerError, InputStickers, Integer, PhotoSize, Sticker,
         StickerFormat, StickerType,
@@ -56,6 +56,15 @@ impl StickerSet {
         self.thumbnail = Some(value);
         self
     }
+
+    /// Returns `true` if the set was created by the bot.
+    ///
+    /// # Arguments
+    ///
+    /// * `bot_username` - Username of the bot without leading `@`.
+    pub fn is_owned_by(&self, bot_username: &str) -> bool {
+        is_bot_set_name(&self.name, bot_username)
+    }
 }
 
 /// Adds a new sticker to a set created by the bot.
@@ -96,7 +105,7 @@ impl Method for AddStickerToSet {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::form("addStickerToSet", self.form)
+        Payload::form(MethodName::AddStickerToSet, self.form)
     }
 }
 
@@ -169,7 +178,7 @@ impl Method for CreateNewStickerSet {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::form("createNewStickerSet", self.form)
+        Payload::form(MethodName::CreateNewStickerSet, self.form)
     }
 }
 
@@ -199,7 +208,7 @@ impl Method for DeleteStickerFromSet {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("deleteStickerFromSet", self)
+        Payload::json(MethodName::DeleteStickerFromSet, self)
     }
 }
 
@@ -227,7 +236,7 @@ impl Method for DeleteStickerSet {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("deleteStickerSet", self)
+        Payload::json(MethodName::DeleteStickerSet, self)
     }
 }
 
@@ -255,7 +264,7 @@ impl Method for GetStickerSet {
     type Response = StickerSet;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getStickerSet", self)
+        Payload::json(MethodName::GetStickerSet, self)
     }
 }
 
@@ -301,7 +310,7 @@ impl Method for ReplaceStickerInSet {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::form("replaceStickerInSet", self.form)
+        Payload::form(MethodName::ReplaceStickerInSet, self.form)
     }
 }
 
@@ -349,7 +358,7 @@ impl Method for SetCustomEmojiStickerSetThumbnail {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setCustomEmojiStickerSetThumbnail", self)
+        Payload::json(MethodName::SetCustomEmojiStickerSetThumbnail, self)
     }
 }
 
@@ -382,7 +391,7 @@ impl Method for SetStickerPositionInSet {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setStickerPositionInSet", self)
+        Payload::json(MethodName::SetStickerPositionInSet, self)
     }
 }
 
@@ -416,7 +425,7 @@ impl Method for SetStickerSetTitle {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::json("setStickerSetTitle", self)
+        Payload::json(MethodName::SetStickerSetTitle, self)
     }
 }
 
@@ -475,6 +484,15 @@ impl Method for SetStickerSetThumbnail {
     type Response = bool;
 
     fn into_payload(self) -> Payload {
-        Payload::form("setStickerSetThumbnail", self.form)
+        Payload::form(MethodName::SetStickerSetThumbnail, self.form)
     }
 }
+
+pub(super) fn is_bot_set_name(name: &str, bot_username: &str) -> bool {
+    let suffix_len = bot_username.len() + 4;
+    name.len() > suffix_len
+        && name.is_char_boundary(name.len() - suffix_len)
+        && name[name.len() - suffix_len..]
+            .strip_prefix("_by_")
+            .is_some_and(|x| x.eq_ignore_ascii_case(bot_username))
+}
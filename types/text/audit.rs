@@ -0,0 +1,170 @@
+use std::fmt;
+use super::{Text, TextEntity};
+
+impl Text {
+    /// Checks positions of entities.
+    ///
+    /// Use it to validate texts loaded from a storage before sending them,
+    /// since Telegram rejects a message with invalid entities as a whole.
+    ///
+    /// Returns an empty list if the entities are valid.
+    pub fn audit_entities(&self) -> Vec<TextEntityIssue> {
+        let units: Vec<u16> = self.data.encode_utf16().collect();
+        let entities: Vec<&TextEntity> = self.entities.iter().flatten().collect();
+        let mut result = Vec::new();
+        for (index, entity) in entities.iter().enumerate() {
+            let (start, end) = get_range(entity);
+            let kind = if start == end {
+                TextEntityIssueKind::Empty
+            } else if end > units.len() {
+                TextEntityIssueKind::OutOfRange {
+                    text_length: units.len(),
+                }
+            } else if let Some(offset) = [start, end]
+                .into_iter()
+                .find(|&x| is_inside_surrogate_pair(&units, x))
+            {
+                TextEntityIssueKind::SplitsSurrogatePair { offset }
+            } else {
+                continue;
+            };
+            result.push(TextEntityIssue { index, kind });
+        }
+        for (index, entity) in entities.iter().enumerate() {
+            let (start, end) = get_range(entity);
+            for (other, other_entity) in entities.iter().enumerate().skip(index + 1) {
+                let (other_start, other_end) = get_range(other_entity);
+                if start >= other_end || other_start >= end {
+                    continue;
+                }
+                let kind = if (start < other_start && end < other_end)
+                    || (other_start < start && other_end < end)
+                {
+                    TextEntityIssueKind::Overlaps { other }
+                } else if (start <= other_start
+                    && other_end <= end
+                    && can_contain(entity, other_entity))
+                    || (other_start <= start
+                        && end <= other_end
+                        && can_contain(other_entity, entity))
+                {
+                    continue;
+                } else {
+                    TextEntityIssueKind::InvalidNesting { other }
+                };
+                result.push(TextEntityIssue { index, kind });
+            }
+        }
+        result
+    }
+}
+
+/// Represents an invalid entity found by [`Text::audit_entities`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TextEntityIssue {
+    /// Index of the entity in the list.
+    pub index: usize,
+    /// The problem with the entity.
+    pub kind: TextEntityIssueKind,
+}
+
+impl fmt::Display for TextEntityIssue {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        write!(out, "entity {} ", self.index)?;
+        match self.kind {
+            TextEntityIssueKind::Empty => write!(out, "is empty"),
+            TextEntityIssueKind::InvalidNesting { other } => {
+                write!(out, "can't be nested with entity {}", other)
+            }
+            TextEntityIssueKind::OutOfRange { text_length } => {
+                write!(out, "ends after the end of the text: {}", text_length)
+            }
+            TextEntityIssueKind::Overlaps { other } => {
+                write!(out, "partially overlaps entity {}", other)
+            }
+            TextEntityIssueKind::SplitsSurrogatePair { offset } => {
+                write!(out, "splits a surrogate pair at {}", offset)
+            }
+        }
+    }
+}
+
+/// Represents a problem with an entity.
+///
+/// Offsets and lengths are in UTF-16 code units.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextEntityIssueKind {
+    /// The entity has zero length.
+    Empty,
+    /// The entity contains or is contained by an entity which can't be nested.
+    ///
+    /// Bold, italic, underline, strikethrough and spoiler entities can be nested
+    /// with any entities except pre and code; block quotations can contain
+    /// any entities except other block quotations.
+    InvalidNesting {
+        /// Index of the other entity.
+        other: usize,
+    },
+    /// The entity ends after the end of the text.
+    OutOfRange {
+        /// Length of the text.
+        text_length: usize,
+    },
+    /// The entity partially overlaps another entity.
+    Overlaps {
+        /// Index of the other entity.
+        other: usize,
+    },
+    /// The entity starts or ends between two halves of a surrogate pair.
+    SplitsSurrogatePair {
+        /// Offset of the boundary.
+        offset: usize,
+    },
+}
+
+fn can_contain(outer: &TextEntity, inner: &TextEntity) -> bool {
+    if is_blockquote(outer) {
+        !is_blockquote(inner)
+    } else if is_formatting(outer) {
+        !is_code(inner)
+    } else if is_formatting(inner) {
+        !is_code(outer)
+    } else {
+        false
+    }
+}
+
+fn get_range(entity: &TextEntity) -> (usize, usize) {
+    let position = entity.position();
+    let offset = position.offset as usize;
+    (offset, offset + position.length as usize)
+}
+
+fn is_blockquote(entity: &TextEntity) -> bool {
+    matches!(
+        entity,
+        TextEntity::Blockquote(_) | TextEntity::ExpandableBlockquote(_)
+    )
+}
+
+fn is_code(entity: &TextEntity) -> bool {
+    matches!(entity, TextEntity::Code(_) | TextEntity::Pre { .. })
+}
+
+fn is_formatting(entity: &TextEntity) -> bool {
+    matches!(
+        entity,
+        TextEntity::Bold(_)
+            | TextEntity::Italic(_)
+            | TextEntity::Spoiler(_)
+            | TextEntity::Strikethrough(_)
+            | TextEntity::Underline(_)
+    )
+}
+
+fn is_inside_surrogate_pair(units: &[u16], offset: usize) -> bool {
+    offset > 0
+        && offset < units.len()
+        && (0xDC00..0xE000).contains(&units[offset])
+        && (0xD800..0xDC00).contains(&units[offset - 1])
+}
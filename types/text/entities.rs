@@ -254,6 +254,66 @@ impl TextEntity {
             user,
         }
     }
+
+    pub(super) fn position(&self) -> TextEntityPosition {
+        match self {
+            TextEntity::Blockquote(position)
+            | TextEntity::Bold(position)
+            | TextEntity::BotCommand(position)
+            | TextEntity::Cashtag(position)
+            | TextEntity::Code(position)
+            | TextEntity::Email(position)
+            | TextEntity::ExpandableBlockquote(position)
+            | TextEntity::Hashtag(position)
+            | TextEntity::Italic(position)
+            | TextEntity::Mention(position)
+            | TextEntity::PhoneNumber(position)
+            | TextEntity::Spoiler(position)
+            | TextEntity::Strikethrough(position)
+            | TextEntity::Underline(position)
+            | TextEntity::Url(position)
+            | TextEntity::CustomEmoji { position, .. }
+            | TextEntity::Pre { position, .. }
+            | TextEntity::TextLink { position, .. }
+            | TextEntity::TextMention { position, .. } => *position,
+        }
+    }
+
+    pub(super) fn position_mut(&mut self) -> &mut TextEntityPosition {
+        match self {
+            TextEntity::Blockquote(position)
+            | TextEntity::Bold(position)
+            | TextEntity::BotCommand(position)
+            | TextEntity::Cashtag(position)
+            | TextEntity::Code(position)
+            | TextEntity::Email(position)
+            | TextEntity::ExpandableBlockquote(position)
+            | TextEntity::Hashtag(position)
+            | TextEntity::Italic(position)
+            | TextEntity::Mention(position)
+            | TextEntity::PhoneNumber(position)
+            | TextEntity::Spoiler(position)
+            | TextEntity::Strikethrough(position)
+            | TextEntity::Underline(position)
+            | TextEntity::Url(position)
+            | TextEntity::CustomEmoji { position, .. }
+            | TextEntity::Pre { position, .. }
+            | TextEntity::TextLink { position, .. }
+            | TextEntity::TextMention { position, .. } => position,
+        }
+    }
+
+    /// Returns an identifier of the custom emoji.
+    ///
+    /// Use [`crate::types::GetCustomEmojiStickers`] to get full information about the sticker.
+    pub fn get_custom_emoji_id(&self) -> Option<&str> {
+        match self {
+            TextEntity::CustomEmoji {
+                custom_emoji_id, ..
+            } => Some(custom_emoji_id),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
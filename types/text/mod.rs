@@ -1,8 +1,13 @@
 use std::str::EncodeUtf16;
 use serde::{Deserialize, Serialize};
-pub use self::entities::*;
+pub use self::{audit::*, entities::*, similarity::*, split::*};
 
+mod audit;
 mod entities;
+mod replace;
+mod similarity;
+mod slice;
+mod split;
 
 /// Represents a text with entities.
 #[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize)]
@@ -53,6 +58,25 @@ impl Text {
             })
             .filter(|entities| !entities.is_empty())
     }
+
+    /// Returns unique identifiers of custom emoji found in text.
+    ///
+    /// Pass them to [`crate::types::GetCustomEmojiStickers`] to get the stickers;
+    /// the method accepts at most 200 identifiers.
+    pub fn get_custom_emoji_ids(&self) -> Vec<&str> {
+        let mut result: Vec<&str> = Vec::new();
+        for id in self
+            .entities
+            .iter()
+            .flatten()
+            .filter_map(TextEntity::get_custom_emoji_id)
+        {
+            if !result.contains(&id) {
+                result.push(id);
+            }
+        }
+        result
+    }
 }
 
 impl PartialEq<str> for Text {
@@ -0,0 +1,90 @@
+use std::ops::Range;
+use super::{Text, TextEntity};
+
+impl Text {
+    /// Returns a copy of the text where all occurrences of a pattern are replaced.
+    ///
+    /// Entities are moved to keep pointing at the same content;
+    /// see [`Self::replace_range`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The pattern to replace.
+    /// * `to` - The replacement.
+    pub fn replace(&self, from: &str, to: &str) -> Text {
+        let mut result = self.clone();
+        if from.is_empty() {
+            return result;
+        }
+        let ranges: Vec<Range<usize>> = self
+            .data
+            .match_indices(from)
+            .map(|(idx, _)| {
+                let start = self.data[..idx].encode_utf16().count();
+                start..start + from.encode_utf16().count()
+            })
+            .collect();
+        for range in ranges.into_iter().rev() {
+            result.replace_range(range, to);
+        }
+        result
+    }
+
+    /// Replaces a part of the text.
+    ///
+    /// Entities after the part are shifted and entities around the part are resized;
+    /// entities which become empty are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The part to replace in UTF-16 code units.
+    /// * `value` - The replacement.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or splits a character.
+    pub fn replace_range(&mut self, range: Range<usize>, value: &str) {
+        let units: Vec<u16> = self.data.encode_utf16().collect();
+        let (start, end) = (range.start, range.end);
+        assert!(start <= end && end <= units.len(), "range is out of bounds");
+        let byte_start = String::from_utf16(&units[..start])
+            .expect("range splits a character")
+            .len();
+        let byte_end = String::from_utf16(&units[..end])
+            .expect("range splits a character")
+            .len();
+        self.data.replace_range(byte_start..byte_end, value);
+
+        let new_end = start + value.encode_utf16().count();
+        let map_start = |idx: usize| match idx {
+            idx if idx <= start => idx,
+            idx if idx >= end => idx - end + new_end,
+            _ => start,
+        };
+        let map_end = |idx: usize| match idx {
+            idx if idx <= start => idx,
+            idx if idx >= end => idx - end + new_end,
+            _ => new_end,
+        };
+        if let Some(entities) = self.entities.take() {
+            let entities: Vec<TextEntity> = entities
+                .into_iter()
+                .filter_map(|mut entity| {
+                    let position = entity.position_mut();
+                    let offset = position.offset as usize;
+                    let entity_start = map_start(offset);
+                    let entity_end = map_end(offset + position.length as usize);
+                    if entity_start >= entity_end {
+                        return None;
+                    }
+                    position.offset = entity_start as u32;
+                    position.length = (entity_end - entity_start) as u32;
+                    Some(entity)
+                })
+                .collect();
+            if !entities.is_empty() {
+                self.entities = Some(entities.into_iter().collect());
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+use super::{Text, TextEntity};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+const SHINGLE_SIZE: usize = 3;
+
+impl Text {
+    /// Returns a normalized form of the text for duplicate detection.
+    ///
+    /// Content of URLs, mentions, hashtags, cashtags, bot commands,
+    /// emails, phone numbers and custom emoji is removed,
+    /// the rest is lowercased and whitespace is collapsed to single spaces.
+    pub fn normalize(&self) -> String {
+        let mut units: Vec<u16> = self.data.encode_utf16().collect();
+        let mut removed = vec![false; units.len()];
+        for entity in self.entities.iter().flatten() {
+            let position = match entity {
+                TextEntity::BotCommand(position)
+                | TextEntity::Cashtag(position)
+                | TextEntity::Email(position)
+                | TextEntity::Hashtag(position)
+                | TextEntity::Mention(position)
+                | TextEntity::PhoneNumber(position)
+                | TextEntity::Url(position)
+                | TextEntity::CustomEmoji { position, .. } => position,
+                _ => continue,
+            };
+            let start = (position.offset as usize).min(removed.len());
+            let end = (start + position.length as usize).min(removed.len());
+            removed[start..end].iter_mut().for_each(|x| *x = true);
+        }
+        let mut idx = 0;
+        units.retain(|_| {
+            idx += 1;
+            !removed[idx - 1]
+        });
+        String::from_utf16_lossy(&units)
+            .to_lowercase()
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    /// Returns a stable hash of the normalized text.
+    ///
+    /// Equal hashes indicate exact duplicates up to normalization,
+    /// see [`Self::normalize`].
+    pub fn normalized_hash(&self) -> u64 {
+        fnv1a(self.normalize().as_bytes())
+    }
+
+    /// Returns a SimHash fingerprint of the normalized text.
+    ///
+    /// Fingerprints of near-duplicate texts differ in a few bits only,
+    /// see [`TextFingerprint::distance`].
+    pub fn fingerprint(&self) -> TextFingerprint {
+        let normalized = self.normalize();
+        let mut weights = [0i64; 64];
+        for shingle in shingles(&normalized) {
+            let hash = fnv1a(shingle.as_bytes());
+            for (bit, weight) in weights.iter_mut().enumerate() {
+                if hash & (1 << bit) != 0 {
+                    *weight += 1;
+                } else {
+                    *weight -= 1;
+                }
+            }
+        }
+        TextFingerprint(
+            weights
+                .iter()
+                .enumerate()
+                .filter(|(_, weight)| **weight > 0)
+                .fold(0, |acc, (bit, _)| acc | (1 << bit)),
+        )
+    }
+
+    /// Returns the Jaccard similarity of word shingles of two normalized texts.
+    ///
+    /// The result is in range from `0.0` (nothing in common) to `1.0` (equal texts).
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A text to compare with.
+    pub fn similarity(&self, other: &Text) -> f64 {
+        let (a, b) = (self.normalize(), other.normalize());
+        let a: HashSet<String> = shingles(&a).collect();
+        let b: HashSet<String> = shingles(&b).collect();
+        if a.is_empty() && b.is_empty() {
+            return 1.0;
+        }
+        a.intersection(&b).count() as f64 / a.union(&b).count() as f64
+    }
+}
+
+/// Represents a SimHash fingerprint of a text.
+///
+/// Use [`Text::fingerprint`] to get a fingerprint.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TextFingerprint(u64);
+
+impl TextFingerprint {
+    /// Returns the number of differing bits between two fingerprints.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A fingerprint to compare with.
+    pub fn distance(&self, other: &TextFingerprint) -> u32 {
+        (self.0 ^ other.0).count_ones()
+    }
+
+    /// Returns `true` if two fingerprints belong to near-duplicate texts.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A fingerprint to compare with.
+    /// * `max_distance` - Maximum number of differing bits; 3 is a common choice.
+    pub fn is_similar(&self, other: &TextFingerprint, max_distance: u32) -> bool {
+        self.distance(other) <= max_distance
+    }
+}
+
+impl From<u64> for TextFingerprint {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<TextFingerprint> for u64 {
+    fn from(value: TextFingerprint) -> Self {
+        value.0
+    }
+}
+
+fn shingles(value: &str) -> impl Iterator<Item = String> + '_ {
+    let words: Vec<&str> = value.split(' ').filter(|x| !x.is_empty()).collect();
+    let size = SHINGLE_SIZE.min(words.len()).max(1);
+    let count = (words.len() + 1).saturating_sub(size);
+    (0..count).map(move |idx| words[idx..idx + size].join(" "))
+}
+
+fn fnv1a(value: &[u8]) -> u64 {
+    value.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+    })
+}
@@ -0,0 +1,69 @@
+use std::ops::Range;
+use super::{Text, TextEntity};
+
+impl Text {
+    /// Returns a part of the text.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The part to return in UTF-16 code units, as in entity positions.
+    ///
+    /// Returns [`None`] if the range is out of bounds or splits a character.
+    pub fn slice_utf16(&self, range: Range<usize>) -> Option<&str> {
+        if range.start > range.end {
+            return None;
+        }
+        let (mut start, mut end) = (None, None);
+        let mut idx = 0;
+        for (byte_idx, c) in self.data.char_indices() {
+            if idx == range.start {
+                start = Some(byte_idx);
+            }
+            if idx == range.end {
+                end = Some(byte_idx);
+                break;
+            }
+            idx += c.len_utf16();
+        }
+        if idx == range.start && start.is_none() {
+            start = Some(self.data.len());
+        }
+        if idx == range.end && end.is_none() {
+            end = Some(self.data.len());
+        }
+        Some(&self.data[start?..end?])
+    }
+
+    /// Returns entities which intersect with a part of the text.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The part of the text in UTF-16 code units.
+    pub fn entities_in_range(&self, range: Range<usize>) -> Vec<&TextEntity> {
+        self.entities
+            .iter()
+            .flatten()
+            .filter(|entity| {
+                let position = entity.position();
+                let start = position.offset as usize;
+                let end = start + position.length as usize;
+                start < range.end && range.start < end
+            })
+            .collect()
+    }
+}
+
+impl TextEntity {
+    /// Returns the content of the entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text containing the entity.
+    ///
+    /// Returns [`None`] if the entity does not match the text.
+    pub fn extract<'a>(&self, text: &'a Text) -> Option<&'a str> {
+        let position = self.position();
+        let offset = position.offset as usize;
+        text.slice_utf16(offset..offset + position.length as usize)
+    }
+}
@@ -0,0 +1,109 @@
+use super::{Text, TextEntity};
+
+/// Maximum length of a message text in UTF-16 code units.
+pub const MAX_MESSAGE_TEXT_LENGTH: usize = 4096;
+
+const LINE_FEED: u16 = b'\n' as u16;
+
+impl Text {
+    /// Returns the length of the text in UTF-16 code units.
+    ///
+    /// Telegram limits and entity positions use the same units.
+    pub fn utf16_len(&self) -> usize {
+        self.data.encode_utf16().count()
+    }
+
+    /// Splits the text into parts which fit into a message.
+    ///
+    /// The text is cut at line breaks if possible, then at whitespace,
+    /// and cuts inside entities are avoided;
+    /// an entity is divided between parts only when a part is filled by the entity completely.
+    /// Entities are rebased to the start of each part.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_length` - Maximum length of a part in UTF-16 code units,
+    ///   see [`MAX_MESSAGE_TEXT_LENGTH`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_length` is less than 2.
+    pub fn split(&self, max_length: usize) -> Vec<Text> {
+        assert!(max_length > 1, "max length must be greater than 1");
+        let units: Vec<u16> = self.data.encode_utf16().collect();
+        let entities: Vec<&TextEntity> = self.entities.iter().flatten().collect();
+        let ranges: Vec<(usize, usize)> = entities
+            .iter()
+            .map(|x| {
+                let position = x.position();
+                let offset = position.offset as usize;
+                (offset, offset + position.length as usize)
+            })
+            .collect();
+        let is_inside_entity = |idx: usize| ranges.iter().any(|&(x, y)| x < idx && idx < y);
+        let is_covered_by_entity = |idx: usize| ranges.iter().any(|&(x, y)| x <= idx && idx < y);
+        let is_char_boundary = |idx: usize| idx == units.len() || !is_low_surrogate(units[idx]);
+
+        let mut result = Vec::new();
+        let mut start = 0;
+        while start < units.len() {
+            let mut end = units.len();
+            if end - start > max_length {
+                let limit = start + max_length;
+                let candidates = (start + 1..=limit).rev();
+                end = candidates
+                    .clone()
+                    .find(|&idx| units[idx - 1] == LINE_FEED && !is_inside_entity(idx))
+                    .or_else(|| {
+                        candidates
+                            .clone()
+                            .find(|&idx| is_whitespace(units[idx - 1]) && !is_inside_entity(idx))
+                    })
+                    .or_else(|| {
+                        candidates
+                            .clone()
+                            .find(|&idx| is_char_boundary(idx) && !is_inside_entity(idx))
+                    })
+                    .or_else(|| candidates.clone().find(|&idx| is_char_boundary(idx)))
+                    .unwrap_or(limit);
+            }
+            let part_entities: Vec<TextEntity> = entities
+                .iter()
+                .zip(&ranges)
+                .filter_map(|(entity, &(x, y))| {
+                    let (entity_start, entity_end) = (x.max(start), y.min(end));
+                    if entity_start >= entity_end {
+                        return None;
+                    }
+                    let mut entity = (*entity).clone();
+                    let position = entity.position_mut();
+                    position.offset = (entity_start - start) as u32;
+                    position.length = (entity_end - entity_start) as u32;
+                    Some(entity)
+                })
+                .collect();
+            result.push(Text {
+                data: String::from_utf16_lossy(&units[start..end]),
+                entities: if part_entities.is_empty() {
+                    None
+                } else {
+                    Some(part_entities.into_iter().collect())
+                },
+            });
+            start = end;
+            while start < units.len() && is_whitespace(units[start]) && !is_covered_by_entity(start)
+            {
+                start += 1;
+            }
+        }
+        result
+    }
+}
+
+fn is_low_surrogate(value: u16) -> bool {
+    (0xDC00..=0xDFFF).contains(&value)
+}
+
+fn is_whitespace(value: u16) -> bool {
+    char::from_u32(value.into()).is_some_and(char::is_whitespace)
+}
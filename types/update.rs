@@ -1,16 +1,20 @@
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{
         BusinessConnection, BusinessMessagesDeleted, CallbackQuery, Chat, ChatBoostRemoved,
         ChatBoostUpdated, ChatJoinRequest, ChatMemberUpdated, ChatPeerId, ChatUsername,
-        ChosenInlineResult, InlineQuery, Integer, MaybeInaccessibleMessage, Message,
+        ChosenInlineResult, InlineQuery, Integer, MaybeInaccessibleMessage, Message, MessageData,
         MessageReactionCountUpdated, MessageReactionUpdated, PaidMediaPurchased, Poll, PollAnswer,
-        PollAnswerVoter, PreCheckoutQuery, ShippingQuery, User, UserPeerId, UserUsername,
+        PollAnswerVoter, PreCheckoutQuery, RefundedPayment, ShippingQuery, User, UserPeerId,
+        UserUsername,
     },
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
-use std::{collections::HashSet, time::Duration};
+use std::{
+    collections::HashSet,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 /// Represents an incoming update.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -41,6 +45,44 @@ impl Update {
         Self { id, update_type }
     }
 
+    /// Returns the date of the event which caused the update in Unix time.
+    ///
+    /// For edited messages the edit date is returned.
+    pub fn get_date(&self) -> Option<Integer> {
+        Some(match self.update_type {
+            UpdateType::BotStatus(ref x) | UpdateType::UserStatus(ref x) => x.date,
+            UpdateType::BusinessConnection(ref x) => x.date,
+            UpdateType::ChatBoostRemoved(ref x) => x.remove_date,
+            UpdateType::ChatBoostUpdated(ref x) => x.boost.add_date,
+            UpdateType::ChatJoinRequest(ref x) => x.date,
+            UpdateType::Message(ref x)
+            | UpdateType::BusinessMessage(ref x)
+            | UpdateType::EditedBusinessMessage(ref x)
+            | UpdateType::EditedMessage(ref x)
+            | UpdateType::ChannelPost(ref x)
+            | UpdateType::EditedChannelPost(ref x) => x.edit_date.unwrap_or(x.date),
+            UpdateType::MessageReaction(ref x) => x.date,
+            UpdateType::MessageReactionCount(ref x) => x.date,
+            _ => return None,
+        })
+    }
+
+    /// Returns time passed between the event and receipt of the update.
+    ///
+    /// Growing values indicate a backlog of undelivered updates.
+    ///
+    /// # Arguments
+    ///
+    /// * `received_at` - Time when the update was received,
+    ///   e.g. [`crate::api::UpdateBatch::received_at`]
+    ///   or [`crate::api::Clock::now`] when a webhook request arrived.
+    ///
+    /// Returns [`None`] if the update has no date, see [`Self::get_date`].
+    pub fn lag(&self, received_at: SystemTime) -> Option<Duration> {
+        let date = UNIX_EPOCH + Duration::from_secs(u64::try_from(self.get_date()?).ok()?);
+        Some(received_at.duration_since(date).unwrap_or_default())
+    }
+
     /// Returns the chat.
     pub fn get_chat(&self) -> Option<&Chat> {
         self.get_message()
@@ -68,6 +110,9 @@ impl Update {
     }
 
     /// Returns the user.
+    ///
+    /// Chat boost updates are not attributed to a user;
+    /// use [`crate::types::ChatBoostSource::get_user`] to get the booster.
     pub fn get_user(&self) -> Option<&User> {
         Some(match self.update_type {
             UpdateType::BotStatus(ref x) | UpdateType::UserStatus(ref x) => &x.from,
@@ -109,6 +154,32 @@ impl Update {
         self.get_user().and_then(|user| user.username.as_ref())
     }
 
+    /// Returns a stable shard index of the update.
+    ///
+    /// Updates of the same chat always get the same index,
+    /// so consumers partitioned by the index process a chat sequentially.
+    /// Updates without a chat are partitioned by the user;
+    /// since the ID of a private chat equals the ID of the user,
+    /// such updates go to the same shard as the private chat.
+    /// Updates without both are partitioned by the update ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of shards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn shard_key(&self, n: u64) -> u64 {
+        assert!(n > 0, "number of shards must be greater than zero");
+        let key: Integer = match (self.get_chat_id(), self.get_user_id()) {
+            (Some(chat_id), _) => chat_id.into(),
+            (None, Some(user_id)) => user_id.into(),
+            (None, None) => self.id,
+        };
+        key.unsigned_abs() % n
+    }
+
     /// Returns the message.
     pub fn get_message(&self) -> Option<&Message> {
         match self.update_type {
@@ -125,6 +196,19 @@ impl Update {
             _ => None,
         }
     }
+
+    /// Returns the refunded payment if the update is a refund service message.
+    ///
+    /// Use it as a filter to revoke access paid with Telegram Stars.
+    pub fn get_refunded_payment(&self) -> Option<&RefundedPayment> {
+        match self.update_type {
+            UpdateType::Message(Message {
+                data: MessageData::RefundedPayment(ref payment),
+                ..
+            }) => Some(payment),
+            _ => None,
+        }
+    }
 }
 
 /// Represents a type of an update.
@@ -289,6 +373,30 @@ impl TryFrom<Update> for CallbackQuery {
     }
 }
 
+impl TryFrom<Update> for ChatBoostRemoved {
+    type Error = UnexpectedUpdate;
+
+    fn try_from(value: Update) -> Result<Self, Self::Error> {
+        use self::UpdateType::*;
+        match value.update_type {
+            ChatBoostRemoved(x) => Ok(x),
+            _ => Err(UnexpectedUpdate(value)),
+        }
+    }
+}
+
+impl TryFrom<Update> for ChatBoostUpdated {
+    type Error = UnexpectedUpdate;
+
+    fn try_from(value: Update) -> Result<Self, Self::Error> {
+        use self::UpdateType::*;
+        match value.update_type {
+            ChatBoostUpdated(x) => Ok(x),
+            _ => Err(UnexpectedUpdate(value)),
+        }
+    }
+}
+
 impl TryFrom<Update> for ChatJoinRequest {
     type Error = UnexpectedUpdate;
 
@@ -475,7 +583,12 @@ impl Method for GetUpdates {
     type Response = Vec<Update>;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getUpdates", self)
+        let timeout = self.timeout;
+        let payload = Payload::json(MethodName::GetUpdates, self);
+        match timeout {
+            Some(value) => payload.with_polling_timeout(Duration::from_secs(value.max(0) as u64)),
+            None => payload,
+        }
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{Integer, ParseMode, PhotoSize},
 };
 use serde::{Deserialize, Serialize};
@@ -506,6 +506,6 @@ impl Method for GetUserProfilePhotos {
     type Response = UserProfilePhotos;
 
     fn into_payload(self) -> Payload {
-        Payload::json("getUserProfilePhotos", self)
+        Payload::json(MethodName::GetUserProfilePhotos, self)
     }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    api::{Method, Payload},
+    api::{Method, MethodName, Payload},
     types::{ChatId, Float, Integer, Location, Message, ReplyMarkup, ReplyParameters},
 };
 use serde::{Deserialize, Serialize};
@@ -326,6 +326,6 @@ impl Method for SendVenue {
     type Response = Message;
 
     fn into_payload(self) -> Payload {
-        Payload::json("sendVenue", self)
+        Payload::json(MethodName::SendVenue, self)
     }
 }
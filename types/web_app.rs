@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+/// Represents information about a Web App.
 #[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize)]
 pub struct WebAppInfo {
     /// An HTTPS URL of a Web App to be opened
@@ -1,10 +1,17 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use serde_json::{Error as JsonError, Map as JsonMap, Value as JsonValue};
+use std::{collections::HashSet, error::Error, fmt, io::Cursor};
+#[cfg(feature = "rcgen")]
+use rcgen::{CertificateParams, DistinguishedName, DnType, Error as RcgenError, KeyPair};
 use crate::{
-    api::{Method, Payload},
-    types::{AllowedUpdate, Integer},
+    api::{Form, Method, MethodName, Payload},
+    types::{AllowedUpdate, InputFile, InputFileReader, Integer, Update},
 };
 
+const CERTIFICATE_FILE_NAME: &str = "certificate.pem";
+const DEFAULT_MAX_BODY_DEPTH: usize = 64;
+const DEFAULT_MAX_BODY_SIZE: usize = 1024 * 1024;
+
 
 #[derive(Clone, Debug, Deserialize, PartialEq, PartialOrd, Serialize)]
 pub struct WebhookInfo {
@@ -158,9 +165,9 @@ impl Method for DeleteWebhook {
 
     fn into_payload(self) -> Payload {
         if self.drop_pending_updates.is_some() {
-            Payload::json("deleteWebhook", self)
+            Payload::json(MethodName::DeleteWebhook, self)
         } else {
-            Payload::empty("deleteWebhook")
+            Payload::empty(MethodName::DeleteWebhook)
         }
     }
 }
@@ -173,7 +180,7 @@ impl Method for GetWebhookInfo {
     type Response = WebhookInfo;
 
     fn into_payload(self) -> Payload {
-        Payload::empty("getWebhookInfo")
+        Payload::empty(MethodName::GetWebhookInfo)
     }
 }
 
@@ -191,7 +198,7 @@ pub struct SetWebhook {
     url: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_updates: Option<HashSet<AllowedUpdate>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip)]
     pub certificate: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub drop_pending_updates: Option<bool>,
@@ -267,7 +274,11 @@ impl SetWebhook {
     ///
     /// # Arguments
     ///
-    /// * `value` - Public key certificate; so that the root certificate in use can be checked.
+    /// * `value` - Public key certificate in PEM format;
+    ///   so that the root certificate in use can be checked.
+    ///
+    /// Required when the webhook uses a self-signed certificate,
+    /// the certificate is uploaded as a file.
     pub fn with_certificate<T>(mut self, value: T) -> Self
     where
         T: Into<String>,
@@ -336,7 +347,324 @@ impl SetWebhook {
 impl Method for SetWebhook {
     type Response = bool;
 
-    fn into_payload(self) -> Payload {
-        Payload::json("setWebhook", self)
+    fn into_payload(mut self) -> Payload {
+        let certificate = match self.certificate.take() {
+            Some(certificate) => certificate,
+            None => return Payload::json(MethodName::SetWebhook, self),
+        };
+        let mut form = Form::from([
+            ("url", self.url.into()),
+            (
+                "certificate",
+                InputFile::from(
+                    InputFileReader::new(Cursor::new(certificate.into_bytes()))
+                        .with_file_name(CERTIFICATE_FILE_NAME),
+                )
+                .into(),
+            ),
+        ]);
+        if let Some(value) = self.allowed_updates {
+            match serde_json::to_string(&value) {
+                Ok(value) => form.insert_field("allowed_updates", value),
+                Err(err) => return Payload::json_error(MethodName::SetWebhook, err),
+            }
+        }
+        if let Some(value) = self.drop_pending_updates {
+            form.insert_field("drop_pending_updates", value);
+        }
+        if let Some(value) = self.ip_address {
+            form.insert_field("ip_address", value);
+        }
+        if let Some(value) = self.max_connections {
+            form.insert_field("max_connections", value);
+        }
+        if let Some(value) = self.secret_token {
+            form.insert_field("secret_token", value);
+        }
+        Payload::form(MethodName::SetWebhook, form)
+    }
+}
+
+/// Parses bodies of webhook requests into updates.
+///
+/// The size and the nesting depth of a body are checked before parsing,
+/// so malformed or oversized requests are rejected without allocating the whole update.
+/// Use the error to log the rejection and respond with a client error status.
+#[derive(Clone, Copy, Debug)]
+pub struct WebhookBodyGuard {
+    max_depth: usize,
+    max_size: usize,
+}
+
+impl WebhookBodyGuard {
+    /// Creates a new `WebhookBodyGuard`.
+    pub fn new() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_BODY_DEPTH,
+            max_size: DEFAULT_MAX_BODY_SIZE,
+        }
+    }
+
+    /// Sets a new maximum nesting depth of JSON arrays and objects.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Maximum depth; default - 64.
+    pub fn with_max_depth(mut self, value: usize) -> Self {
+        self.max_depth = value;
+        self
+    }
+
+    /// Sets a new maximum size of a body.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Maximum size in bytes; default - 1 MiB.
+    ///
+    /// Compare the value with the `Content-Length` header to reject a request
+    /// before reading the body.
+    pub fn with_max_size(mut self, value: usize) -> Self {
+        self.max_size = value;
+        self
+    }
+
+    /// Returns the maximum size of a body in bytes.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Parses a body into an update.
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - Body of a webhook request.
+    pub fn parse(&self, body: &[u8]) -> Result<Update, WebhookBodyError> {
+        if body.len() > self.max_size {
+            return Err(WebhookBodyError::TooLarge(body.len()));
+        }
+        let (mut depth, mut is_string, mut is_escaped) = (0usize, false, false);
+        for &byte in body {
+            if is_string {
+                match byte {
+                    _ if is_escaped => is_escaped = false,
+                    b'\\' => is_escaped = true,
+                    b'"' => is_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match byte {
+                b'"' => is_string = true,
+                b'[' | b'{' => {
+                    depth += 1;
+                    if depth > self.max_depth {
+                        return Err(WebhookBodyError::TooDeep(depth));
+                    }
+                }
+                b']' | b'}' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+        serde_json::from_slice(body).map_err(WebhookBodyError::InvalidJson)
+    }
+}
+
+impl Default for WebhookBodyGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Represents a rejected body of a webhook request.
+#[derive(Debug)]
+pub enum WebhookBodyError {
+    /// The body is not a valid update.
+    InvalidJson(JsonError),
+    /// The body exceeds the maximum depth; contains the depth reached.
+    TooDeep(usize),
+    /// The body exceeds the maximum size; contains the size in bytes.
+    TooLarge(usize),
+}
+
+impl Error for WebhookBodyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidJson(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for WebhookBodyError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidJson(err) => write!(out, "invalid webhook body: {}", err),
+            Self::TooDeep(depth) => write!(out, "webhook body is too deep: {}", depth),
+            Self::TooLarge(size) => write!(out, "webhook body is too large: {} bytes", size),
+        }
+    }
+}
+
+/// Represents a method sent in the response to a webhook request.
+///
+/// Telegram executes the method without an additional request from the bot,
+/// which saves a round trip, e.g. when answering a message with [`crate::types::SendMessage`].
+///
+/// The result of the method and errors are not reported back,
+/// so use [`crate::api::Client`] for methods which results are needed.
+/// Methods with files to upload can't be sent this way.
+///
+/// Serialize the reply as the JSON body of the response
+/// with the `Content-Type: application/json` header.
+#[derive(Clone, Debug, Serialize)]
+#[serde(transparent)]
+pub struct WebhookReply {
+    body: JsonMap<String, JsonValue>,
+}
+
+impl WebhookReply {
+    /// Creates a new `WebhookReply`.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method to execute.
+    pub fn new<M>(method: M) -> Result<Self, WebhookReplyError>
+    where
+        M: Method,
+    {
+        let payload = method.into_payload();
+        let name = payload.method();
+        let data = match payload.into_json_body() {
+            Some(data) => data?,
+            None => return Err(WebhookReplyError::HasFiles(name)),
+        };
+        let mut body: JsonMap<String, JsonValue> = serde_json::from_str(&data)?;
+        body.insert(String::from("method"), JsonValue::from(name.as_str()));
+        Ok(Self { body })
+    }
+
+    /// Returns the name of the method.
+    pub fn method(&self) -> &str {
+        self.body
+            .get("method")
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default()
+    }
+}
+
+/// Represents an error occurred when creating a webhook reply.
+#[derive(Debug, derive_more::From)]
+pub enum WebhookReplyError {
+    /// The method contains files to upload; contains the name of the method.
+    #[from(ignore)]
+    HasFiles(MethodName),
+    /// Failed to serialize the method.
+    Json(JsonError),
+}
+
+impl Error for WebhookReplyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for WebhookReplyError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::HasFiles(method) => {
+                write!(
+                    out,
+                    "method {} with files can't be sent in a webhook reply",
+                    method
+                )
+            }
+            Self::Json(err) => write!(out, "failed to serialize webhook reply: {}", err),
+        }
+    }
+}
+
+/// Represents a self-signed certificate for a webhook.
+///
+/// Pass [`Self::certificate_pem`] to [`SetWebhook::with_certificate`]
+/// and configure the HTTPS server of the webhook with both PEMs.
+///
+/// Works for bare IP deployments as well: an IP address is added
+/// to the subject alternative names as an IP entry.
+#[cfg(feature = "rcgen")]
+#[derive(Clone, Debug)]
+pub struct WebhookCertificate {
+    certificate_pem: String,
+    private_key_pem: String,
+}
+
+#[cfg(feature = "rcgen")]
+impl WebhookCertificate {
+    /// Generates a new self-signed certificate.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - Domain name or IP address of the webhook;
+    ///   used as the common name and the subject alternative name.
+    pub fn generate<T>(host: T) -> Result<Self, WebhookCertificateError>
+    where
+        T: Into<String>,
+    {
+        let host = host.into();
+        let mut params = CertificateParams::new(vec![host.clone()])?;
+        let mut distinguished_name = DistinguishedName::new();
+        distinguished_name.push(DnType::CommonName, host);
+        params.distinguished_name = distinguished_name;
+        let key_pair = KeyPair::generate()?;
+        let certificate = params.self_signed(&key_pair)?;
+        Ok(Self {
+            certificate_pem: certificate.pem(),
+            private_key_pem: key_pair.serialize_pem(),
+        })
+    }
+
+    /// Returns the certificate in PEM format.
+    pub fn certificate_pem(&self) -> &str {
+        &self.certificate_pem
+    }
+
+    /// Returns the private key in PEM format.
+    pub fn private_key_pem(&self) -> &str {
+        &self.private_key_pem
+    }
+}
+
+/// Represents an error occurred when generating a webhook certificate.
+#[cfg(feature = "rcgen")]
+#[derive(Debug)]
+pub enum WebhookCertificateError {
+    /// Failed to generate a certificate.
+    Generate(RcgenError),
+}
+
+#[cfg(feature = "rcgen")]
+impl From<RcgenError> for WebhookCertificateError {
+    fn from(err: RcgenError) -> Self {
+        Self::Generate(err)
+    }
+}
+
+#[cfg(feature = "rcgen")]
+impl Error for WebhookCertificateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Generate(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "rcgen")]
+impl fmt::Display for WebhookCertificateError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Generate(err) => write!(out, "failed to generate webhook certificate: {}", err),
+        }
     }
 }